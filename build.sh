@@ -36,6 +36,20 @@ cargo test --doc -- --show-output
 cargo doc --verbose --no-deps
 
 
+step "TESTING NO_STD"
+cargo test --no-default-features -p beaumont-numbers
+cargo test --no-default-features --features rust_decimal,bigdecimal -p beaumont-numbers
+cargo test --no-default-features -p beaumont-linear-algebra
+
+
+step "BUILDING NO_STD"
+rustup target add thumbv7em-none-eabihf
+cargo build --no-default-features --target thumbv7em-none-eabihf \
+  -p beaumont-numbers \
+  -p beaumont-linear-algebra \
+  -p beaumont
+
+
 step "BUILDING RELEASE"
 cargo build --release
 
@@ -0,0 +1,78 @@
+use beaumont_linear_algebra::Vector;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const LEN: usize = 100_000;
+
+// Weights and returns of very different magnitudes
+fn portfolio() -> (Vector<f64>, Vector<f64>) {
+    let weights = (0..LEN).map(|i| 1.0 / (1 + i % 100) as f64).collect();
+    let returns = (0..LEN).map(|i| ((i * 7919) % 2000) as f64 / 1000.0 - 1.0).collect();
+    (weights, returns)
+}
+
+fn dot(c: &mut Criterion) {
+    let (weights, returns) = portfolio();
+    let mut group = c.benchmark_group("dot f64");
+    group.bench_function("generic", |b| b.iter(|| {
+        black_box(&weights).dot(black_box(&returns))
+    }));
+    group.bench_function("simd_dot", |b| b.iter(|| {
+        black_box(&weights).simd_dot(black_box(&returns))
+    }));
+    group.finish();
+
+    let weights: Vector<f32> = weights.map(|x| x as f32);
+    let returns: Vector<f32> = returns.map(|x| x as f32);
+    let mut group = c.benchmark_group("dot f32");
+    group.bench_function("generic", |b| b.iter(|| {
+        black_box(&weights).dot(black_box(&returns))
+    }));
+    group.bench_function("simd_dot", |b| b.iter(|| {
+        black_box(&weights).simd_dot(black_box(&returns))
+    }));
+    group.finish();
+}
+
+fn add(c: &mut Criterion) {
+    let (weights, returns) = portfolio();
+    let mut group = c.benchmark_group("add f64");
+    group.bench_function("generic", |b| b.iter(|| {
+        black_box(&weights).add(black_box(&returns))
+    }));
+    group.bench_function("simd_add", |b| b.iter(|| {
+        black_box(&weights).simd_add(black_box(&returns))
+    }));
+    group.finish();
+}
+
+fn scale(c: &mut Criterion) {
+    let (weights, _) = portfolio();
+    let mut group = c.benchmark_group("scale f64");
+    group.bench_function("generic", |b| b.iter(|| {
+        black_box(&weights).scale_by(black_box(1.5))
+    }));
+    group.bench_function("simd_scale_by", |b| b.iter(|| {
+        black_box(&weights).simd_scale_by(black_box(1.5))
+    }));
+    group.finish();
+}
+
+fn axpy(c: &mut Criterion) {
+    let (weights, returns) = portfolio();
+    let mut group = c.benchmark_group("axpy f64");
+    group.bench_function("generic", |b| b.iter_batched_ref(
+        || weights.scale_by(1.0),
+        |y| y.add_in_place(&returns.scale_by(black_box(0.5))),
+        criterion::BatchSize::LargeInput,
+    ));
+    group.bench_function("simd_axpy_in_place", |b| b.iter_batched_ref(
+        || weights.scale_by(1.0),
+        |y| y.simd_axpy_in_place(black_box(0.5), &returns),
+        criterion::BatchSize::LargeInput,
+    ));
+    group.finish();
+}
+
+criterion_group!(benches, dot, add, scale, axpy);
+criterion_main!(benches);
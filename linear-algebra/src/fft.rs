@@ -0,0 +1,173 @@
+use crate::{Dimension, Vector};
+use alloc::vec::Vec;
+use beaumont_numbers::Complex;
+
+impl Vector<Complex<f64>> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the discrete Fourier transform of this vector, by a Fast Fourier Transform.
+    ///
+    /// Given a vector $\vec{x}$ of dimension $n$, its discrete Fourier transform is the vector
+    /// $\vec{X}$ of the same dimension, such that:
+    ///
+    /// $$
+    /// X_{k} \quad \equiv \quad \sum_{j=0}^{n-1} x_{j} e^{-2 \pi i j k / n}
+    /// $$
+    ///
+    /// where the components are indexed starting from 0 (as the frequencies they represent, with
+    /// $X_{0}$ being the sum of the components). It's not normalized, as the inverse transform
+    /// is (see [`Vector::ifft`]).
+    ///
+    /// When the dimension is a power of 2, it takes $O(n \log n)$ operations by the radix-2
+    /// algorithm. Otherwise, the Bluestein's algorithm turns the transform into a convolution of
+    /// a power of 2 dimension, which still takes $O(n \log n)$ operations.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = Vector::from([1.0, 2.0, 3.0, 4.0].map(Complex::from));
+    /// let spectrum = v.fft();
+    /// assert!((spectrum[1] - Complex::new(10.0, 0.0)).abs() < 1e-12);
+    /// assert!((spectrum[2] - Complex::new(-2.0, 2.0)).abs() < 1e-12);
+    /// assert!((spectrum[3] - Complex::new(-2.0, 0.0)).abs() < 1e-12);
+    /// assert!((spectrum[4] - Complex::new(-2.0, -2.0)).abs() < 1e-12);
+    /// ```
+    pub fn fft(&self) -> Self {
+        transform(self.as_slice(), Direction::Forward).into_iter().collect()
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the inverse discrete Fourier transform of this vector (see [`Vector::fft`]), by a
+    /// Fast Fourier Transform:
+    ///
+    /// $$
+    /// x_{j} \quad \equiv \quad \frac{1}{n} \sum_{k=0}^{n-1} X_{k} e^{2 \pi i j k / n}
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = Vector::from([Complex::new(1.0, -1.0), Complex::new(0.5, 2.0), Complex::new(3.0, 0.0)]);
+    /// let w = v.fft().ifft();
+    /// assert!(v.iter().zip(w.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn ifft(&self) -> Self {
+        let scale = 1.0 / self.dim() as f64;
+        transform(self.as_slice(), Direction::Inverse).into_iter().map(|z| scaled(z, scale)).collect()
+    }
+}
+
+
+// The direction of a transform, as the sign of the exponent of its twiddle factors
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    Forward,
+    Inverse,
+}
+
+impl Direction {
+    fn sign(self) -> f64 {
+        match self {
+            Direction::Forward => -1.0,
+            Direction::Inverse => 1.0,
+        }
+    }
+}
+
+
+// Compute the (not normalized) transform of the given values in the given direction
+fn transform(values: &[Complex<f64>], direction: Direction) -> Vec<Complex<f64>> {
+    if values.len().is_power_of_two() {
+        let mut values = values.to_vec();
+        radix2::transform(&mut values, direction);
+        values
+    } else if values.is_empty() {
+        Vec::new()
+    } else {
+        bluestein::transform(values, direction)
+    }
+}
+
+// Multiply the given complex number by a real scalar
+fn scaled(z: Complex<f64>, scalar: f64) -> Complex<f64> {
+    Complex::new(z.re() * scalar, z.im() * scalar)
+}
+
+
+mod radix2;
+mod bluestein;
+mod real;
+mod convolution;
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::PI;
+
+    // The discrete Fourier transform computed by its definition, in O(n^2) operations
+    pub(super) fn naive_dft(values: &[Complex<f64>], direction: Direction) -> Vec<Complex<f64>> {
+        let n = values.len();
+        (0..n).map(|k| {
+            values.iter().enumerate()
+                .map(|(j, x)| {
+                    // Reducing j * k modulo n keeps the angle small (and accurate)
+                    let angle = direction.sign() * 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                    *x * Complex::from_polar(1.0, angle)
+                })
+                .sum()
+        }).collect()
+    }
+
+    // Pseudo-random values (of different magnitudes and signs)
+    pub(super) fn values(n: usize, seed: u64) -> Vec<Complex<f64>> {
+        let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        (0..n).map(|_| Complex::new(next() * 10.0, next())).collect()
+    }
+
+    // The largest distance between the correspondent components, relative to the largest one
+    pub(super) fn relative_error(actual: &[Complex<f64>], expected: &[Complex<f64>]) -> f64 {
+        assert_eq!(actual.len(), expected.len());
+        let largest = expected.iter().map(|z| z.abs()).fold(1.0, f64::max);
+        actual.iter().zip(expected).map(|(x, y)| (*x - *y).abs()).fold(0.0, f64::max) / largest
+    }
+
+    #[test]
+    fn fft_against_naive_dft() {
+        for n in (1..=64).chain([100, 127, 128, 360, 1000, 1024]) {
+            let x = values(n, n as u64);
+            let v: Vector<Complex<f64>> = x.iter().copied().collect();
+            let error = relative_error(v.fft().as_slice(), &naive_dft(&x, Direction::Forward));
+            assert!(error < 1e-12, "relative error {error} for dimension {n}");
+        }
+    }
+
+    #[test]
+    fn ifft_against_naive_dft() {
+        for n in [1, 2, 3, 8, 12, 17, 256, 243] {
+            let x = values(n, 7);
+            let v: Vector<Complex<f64>> = x.iter().copied().collect();
+            let expected: Vec<Complex<f64>> = naive_dft(&x, Direction::Inverse).into_iter()
+                .map(|z| scaled(z, 1.0 / n as f64))
+                .collect();
+            let error = relative_error(v.ifft().as_slice(), &expected);
+            assert!(error < 1e-12, "relative error {error} for dimension {n}");
+            let error = relative_error(v.fft().ifft().as_slice(), &x);
+            assert!(error < 1e-12, "relative error {error} for dimension {n}");
+        }
+    }
+
+    #[test]
+    fn fft_empty() {
+        let v: Vector<Complex<f64>> = Vector::from([]);
+        assert_eq!(v.fft().dim(), 0);
+        assert_eq!(v.ifft().dim(), 0);
+    }
+}
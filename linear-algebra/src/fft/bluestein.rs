@@ -0,0 +1,64 @@
+use super::{radix2, scaled, Direction};
+use alloc::vec;
+use alloc::vec::Vec;
+use beaumont_numbers::Complex;
+use core::f64::consts::PI;
+
+// Transform the given values (not normalized) by the Bluestein's algorithm, for any number of
+// them: as `jk = (j² + k² - (k - j)²) / 2`, the transform is the convolution of the values
+// (multiplied by the chirp `c_j = e^{±πi j² / n}`) with the conjugate chirp, multiplied by the
+// chirp again. The convolution is computed by radix-2 transforms of a power of 2 length.
+pub(super) fn transform(values: &[Complex<f64>], direction: Direction) -> Vec<Complex<f64>> {
+    let n = values.len();
+    let m = (2 * n - 1).next_power_of_two();
+
+    // Reducing j² modulo 2n keeps the angles small (and accurate) for large lengths
+    let chirp: Vec<Complex<f64>> = (0..n)
+        .map(|j| {
+            let square = (j as u128 * j as u128 % (2 * n as u128)) as f64;
+            Complex::from_polar(1.0, direction.sign() * PI * square / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex::from(0.0); m];
+    for (a, (x, c)) in a.iter_mut().zip(values.iter().zip(&chirp)) {
+        *a = *x * *c;
+    }
+
+    // The conjugate chirp for both the positive and the negative (wrapped around) offsets
+    let mut b = vec![Complex::from(0.0); m];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[m - j] = chirp[j].conj();
+    }
+
+    radix2::transform(&mut a, Direction::Forward);
+    radix2::transform(&mut b, Direction::Forward);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = *a * *b;
+    }
+    radix2::transform(&mut a, Direction::Inverse);
+
+    let scale = 1.0 / m as f64;
+    a.iter().zip(&chirp).map(|(a, c)| scaled(*a * *c, scale)).collect()
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fft::test::{naive_dft, relative_error, values};
+
+    #[test]
+    fn any_length() {
+        for n in [1, 2, 3, 5, 6, 7, 31, 33, 97, 500] {
+            let x = values(n, 3);
+            for direction in [Direction::Forward, Direction::Inverse] {
+                let error = relative_error(&transform(&x, direction), &naive_dft(&x, direction));
+                assert!(error < 1e-12, "relative error {error} for length {n}");
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use super::{radix2, scaled, Direction};
+use crate::{Dimension, Vector};
+use alloc::vec;
+use alloc::vec::Vec;
+use beaumont_numbers::Complex;
+
+impl Vector<Complex<f64>> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the (linear) convolution of this vector with the other one, by Fast Fourier
+    /// Transforms. Given vectors $\vec{x}$ and $\vec{y}$ of dimensions $n$ and $m$, their
+    /// convolution is the vector of dimension $n + m - 1$ (or empty, if any of them is):
+    ///
+    /// $$
+    /// (\vec{x} * \vec{y})_{k} \quad \equiv \quad \sum_{j} x_{j} y_{k-j}
+    /// $$
+    ///
+    /// It takes $O((n + m) \log (n + m))$ operations, instead of the $O(n m)$ ones of the
+    /// definition.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = Vector::from([Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// let w = Vector::from([Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)]);
+    /// let expected = [Complex::new(-1.0, 1.0), Complex::new(3.0, 5.0), Complex::new(6.0, 0.0)];
+    /// assert!(v.convolve(&w).iter().zip(expected).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn convolve(&self, other: &Self) -> Self {
+        convolve(self.as_slice(), other.as_slice()).into_iter().collect()
+    }
+}
+
+
+impl Vector<f64> {
+
+    /// Compute the (linear) convolution of this real vector with the other one, by Fast Fourier
+    /// Transforms (see [`Vector::convolve`](Vector#method.convolve-1) for complex vectors).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // The coefficients of the product of the polynomials (1 + 2x) and (3 + x + x²)
+    /// let product = vector![1.0, 2.0].convolve(&vector![3.0, 1.0, 1.0]);
+    /// let expected = [3.0, 7.0, 3.0, 2.0];
+    /// assert!(product.iter().zip(expected).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn convolve(&self, other: &Self) -> Self {
+        // Both the vectors are packed into a single complex one (as its real and imaginary
+        // parts), which needs a single forward transform instead of two
+        if self.dim() == 0 || other.dim() == 0 {
+            return Vector::from([]);
+        }
+        let len = (self.dim() + other.dim() - 1) as usize;
+        let m = len.next_power_of_two();
+        let mut z = vec![Complex::from(0.0); m];
+        for (z, x) in z.iter_mut().zip(self.iter()) {
+            *z = Complex::from(x);
+        }
+        for (z, y) in z.iter_mut().zip(other.iter()) {
+            *z = Complex::new(z.re(), y);
+        }
+        radix2::transform(&mut z, Direction::Forward);
+
+        // As Z = X + iY for real x and y, the product XY is (Z_k² - conj(Z_{-k})²) / 4i
+        let mut product: Vec<Complex<f64>> = (0..m)
+            .map(|k| {
+                let zk = z[k];
+                let zr = z[(m - k) % m].conj();
+                let d = zk * zk - zr * zr;
+                Complex::new(d.im() * 0.25, -d.re() * 0.25)
+            })
+            .collect();
+        radix2::transform(&mut product, Direction::Inverse);
+        let scale = 1.0 / m as f64;
+        product.into_iter().take(len).map(|z| z.re() * scale).collect()
+    }
+}
+
+
+// The linear convolution of the given values, by radix-2 transforms of a power of 2 length
+fn convolve(x: &[Complex<f64>], y: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    if x.is_empty() || y.is_empty() {
+        return Vec::new();
+    }
+    let len = x.len() + y.len() - 1;
+    let m = len.next_power_of_two();
+    let mut a = vec![Complex::from(0.0); m];
+    let mut b = vec![Complex::from(0.0); m];
+    a[..x.len()].copy_from_slice(x);
+    b[..y.len()].copy_from_slice(y);
+
+    radix2::transform(&mut a, Direction::Forward);
+    radix2::transform(&mut b, Direction::Forward);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = *a * *b;
+    }
+    radix2::transform(&mut a, Direction::Inverse);
+
+    let scale = 1.0 / m as f64;
+    a.into_iter().take(len).map(|z| scaled(z, scale)).collect()
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fft::test::{relative_error, values};
+
+    // The convolution computed by its definition, in O(nm) operations
+    fn naive_convolution(x: &[Complex<f64>], y: &[Complex<f64>]) -> Vec<Complex<f64>> {
+        if x.is_empty() || y.is_empty() {
+            return Vec::new();
+        }
+        let mut result = vec![Complex::from(0.0); x.len() + y.len() - 1];
+        for (i, x) in x.iter().enumerate() {
+            for (j, y) in y.iter().enumerate() {
+                result[i + j] = result[i + j] + *x * *y;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn convolve_complex() {
+        for (n, m) in [(1, 1), (1, 5), (3, 4), (16, 17), (100, 7), (129, 300)] {
+            let x: Vector<Complex<f64>> = values(n, 1).into_iter().collect();
+            let y: Vector<Complex<f64>> = values(m, 2).into_iter().collect();
+            let expected = naive_convolution(x.as_slice(), y.as_slice());
+            let error = relative_error(x.convolve(&y).as_slice(), &expected);
+            assert!(error < 1e-12, "relative error {error} for dimensions {n} and {m}");
+        }
+    }
+
+    #[test]
+    fn convolve_real() {
+        for (n, m) in [(1, 1), (2, 1), (3, 4), (16, 17), (100, 7), (129, 300)] {
+            let x: Vector<f64> = values(n, 3).iter().map(|z| z.re()).collect();
+            let y: Vector<f64> = values(m, 4).iter().map(|z| z.im()).collect();
+            let complex = |v: &Vector<f64>| -> Vec<Complex<f64>> { v.iter().map(Complex::from).collect() };
+            let expected = naive_convolution(&complex(&x), &complex(&y));
+            let actual = complex(&x.convolve(&y));
+            let error = relative_error(&actual, &expected);
+            assert!(error < 1e-12, "relative error {error} for dimensions {n} and {m}");
+        }
+    }
+
+    #[test]
+    fn convolve_empty() {
+        let empty: Vector<f64> = Vector::from([]);
+        assert_eq!(empty.convolve(&Vector::from([1.0, 2.0])).dim(), 0);
+        let empty: Vector<Complex<f64>> = Vector::from([]);
+        assert_eq!(Vector::from([Complex::from(1.0)]).convolve(&empty).dim(), 0);
+    }
+}
@@ -0,0 +1,71 @@
+use super::Direction;
+use alloc::vec::Vec;
+use beaumont_numbers::Complex;
+use core::f64::consts::PI;
+
+// Transform the given values in place (not normalized) by the iterative radix-2 algorithm,
+// whose number must be a power of 2: the values are permuted in bit-reversed order, and then
+// combined by butterflies of doubling sizes.
+pub(super) fn transform(values: &mut [Complex<f64>], direction: Direction) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    debug_assert!(n.is_power_of_two());
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // The twiddle factors are computed once (each one by its own angle, rather than by repeated
+    // multiplications which would accumulate rounding errors), and the butterflies of size
+    // `len` take them with stride `n / len`
+    let twiddles: Vec<Complex<f64>> = (0..n / 2)
+        .map(|k| Complex::from_polar(1.0, direction.sign() * 2.0 * PI * k as f64 / n as f64))
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for block in values.chunks_exact_mut(len) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (k, (a, b)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                let t = *b * twiddles[k * stride];
+                *b = *a - t;
+                *a = *a + t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fft::test::{naive_dft, relative_error, values};
+
+    #[test]
+    fn powers_of_two() {
+        for bits in 0..=10 {
+            let x = values(1 << bits, bits);
+            let mut y = x.clone();
+            transform(&mut y, Direction::Forward);
+            assert!(relative_error(&y, &naive_dft(&x, Direction::Forward)) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn impulse() {
+        let mut x = [Complex::from(0.0); 8];
+        x[0] = Complex::from(1.0);
+        transform(&mut x, Direction::Inverse);
+        assert!(x.iter().all(|z| *z == Complex::from(1.0)));
+    }
+}
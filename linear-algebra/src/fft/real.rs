@@ -0,0 +1,132 @@
+use super::{scaled, transform, Direction};
+use crate::{Dimension, Vector};
+use alloc::vec::Vec;
+use beaumont_numbers::Complex;
+use core::f64::consts::PI;
+
+impl Vector<f64> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the discrete Fourier transform of this real vector (see [`Vector::fft`]).
+    ///
+    /// As the transform $\vec{X}$ of a real vector of dimension $n$ is Hermitian (that is,
+    /// $X_{n-k} = \overline{X_{k}}$), only its first $\lfloor n/2 \rfloor + 1$ components are
+    /// returned (none for an empty vector), which are the non-negative frequencies. When $n$ is
+    /// even, they're computed by a complex transform of dimension $n/2$ (which takes about half
+    /// of the operations).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_numbers::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 2.0, 3.0, 4.0];
+    /// let spectrum = v.rfft();
+    /// assert_eq!(spectrum.dim(), 3);
+    /// assert!((spectrum[1] - Complex::new(10.0, 0.0)).abs() < 1e-12);
+    /// assert!((spectrum[2] - Complex::new(-2.0, 2.0)).abs() < 1e-12);
+    /// assert!((spectrum[3] - Complex::new(-2.0, 0.0)).abs() < 1e-12);
+    /// ```
+    pub fn rfft(&self) -> Vector<Complex<f64>> {
+        let n = self.dim() as usize;
+        let x = self.as_slice();
+        if n % 2 == 1 {
+            let values: Vec<Complex<f64>> = x.iter().map(|x| Complex::from(*x)).collect();
+            return transform(&values, Direction::Forward).into_iter().take(n / 2 + 1).collect();
+        }
+        if n == 0 {
+            return Vector::from([]);
+        }
+
+        // The even and odd components are packed as the real and imaginary parts of a complex
+        // vector of half the dimension, whose transform is then split into the two transforms
+        let half = n / 2;
+        let packed: Vec<Complex<f64>> = x.chunks_exact(2).map(|pair| Complex::new(pair[0], pair[1])).collect();
+        let z = transform(&packed, Direction::Forward);
+        (0..=half)
+            .map(|k| {
+                let zk = z[k % half];
+                let zr = z[(half - k) % half].conj();
+                let even = scaled(zk + zr, 0.5);
+                let odd = zk - zr;
+                // The odd transform is the difference divided by 2i
+                let odd = Complex::new(odd.im() * 0.5, -odd.re() * 0.5);
+                even + Complex::from_polar(1.0, -2.0 * PI * k as f64 / n as f64) * odd
+            })
+            .collect()
+    }
+}
+
+
+impl Vector<Complex<f64>> {
+
+    /// Compute the inverse discrete Fourier transform of the non-negative frequencies of a real
+    /// vector of the given dimension, as returned by the [`Vector::rfft`] method.
+    ///
+    /// The negative frequencies are restored by symmetry, and the imaginary parts of the
+    /// components of the DC (and, for an even dimension, of the Nyquist) frequency are ignored.
+    ///
+    /// It panics if this vector hasn't `len / 2 + 1` components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![0.5, -1.0, 2.0, 0.25, 3.0];
+    /// let w = v.rfft().irfft(5);
+    /// assert!(v.iter().zip(w.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn irfft(&self, len: usize) -> Vector<f64> {
+        assert_eq!(self.dim() as usize, len / 2 + 1, "must have len / 2 + 1 components");
+        let half = self.as_slice();
+        let spectrum: Vec<Complex<f64>> = (0..len)
+            .map(|k| match k {
+                0 => Complex::from(half[0].re()),
+                k if 2 * k == len => Complex::from(half[k].re()),
+                k if k < half.len() => half[k],
+                k => half[len - k].conj(),
+            })
+            .collect();
+        let scale = 1.0 / len as f64;
+        transform(&spectrum, Direction::Inverse).into_iter().map(|z| z.re() * scale).collect()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fft::test::{relative_error, values};
+
+    #[test]
+    fn rfft_same_as_fft() {
+        for n in (1..=33).chain([100, 128, 243, 1000]) {
+            let x: Vector<f64> = values(n, 11).iter().map(|z| z.re()).collect();
+            let complex: Vector<Complex<f64>> = x.iter().map(Complex::from).collect();
+            let expected = complex.fft();
+            let error = relative_error(x.rfft().as_slice(), &expected.as_slice()[..n / 2 + 1]);
+            assert!(error < 1e-12, "relative error {error} for dimension {n}");
+        }
+    }
+
+    #[test]
+    fn irfft_round_trip() {
+        for n in (1..=33).chain([100, 128, 243, 1000]) {
+            let x: Vector<f64> = values(n, 5).iter().map(|z| z.im()).collect();
+            let y = x.rfft().irfft(n);
+            assert!(x.iter().zip(y.iter()).all(|(a, b)| (a - b).abs() < 1e-12), "dimension {n}");
+        }
+    }
+
+    #[test]
+    fn rfft_empty() {
+        assert_eq!(Vector::<f64>::from([]).rfft().dim(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have len / 2 + 1 components")]
+    fn irfft_wrong_len() {
+        let _ = Vector::from([Complex::from(1.0); 3]).irfft(6);
+    }
+}
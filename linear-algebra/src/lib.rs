@@ -1,12 +1,32 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! A basic implementation of linear algebra common operations.
 //!
 //! <div class="warning">This project is in an early stage of development, and
 //! <strong>not</strong> production ready yet. Use with caution!</div>
 //!
+//! # Features
+//! - `std` (enabled by default)<br>
+//!   Disable it to build for `no_std` targets (still requiring the `alloc` crate)
+//! - `rayon`<br>
+//!   Enable it for the parallel operations on large vectors and matrices (such as `par_dot`),
+//!   built upon the [rayon](https://docs.rs/rayon) crate (and requiring `std`)
+//! - `simd`<br>
+//!   Enable it for the SIMD-accelerated operations on vectors of `f32` and `f64` (such as
+//!   `simd_dot`), with the instruction set detected at runtime (and requiring `std`)
 //!
+//! # Operators
+//! The arithmetic operators (such as `+`, `-` and `*`) of vectors, sparse vectors and matrices
+//! are implemented for both owned and borrowed operands. Borrowed operands are never modified
+//! (and the result is newly allocated), while an owned left-hand side operand (such as in
+//! `v + &w`, `-v` or `m * 2`) has its components updated in place, and it's returned without
+//! allocating.
+//!
+
+extern crate alloc;
 
+use core::fmt;
 
 /// A trait for types that have a dimension (such as vectors and matrices)
 pub trait Dimension {
@@ -20,9 +40,122 @@ pub trait Dimension {
     }
 }
 
+
+/// Error type for linear algebra operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearAlgebraError {
+    /// Error due to the operands having different dimensions
+    DimensionMismatch {
+        /// The dimension required by the operation (as the one of the left-hand side operand)
+        expected: u64,
+        /// The dimension actually found (as the one of the right-hand side operand)
+        found: u64
+    },
+    /// Error due to the operands having incompatible shapes (such as matrices to be added, or
+    /// to be multiplied)
+    ShapeMismatch {
+        /// The shape (as the number of rows and columns) of the left-hand side operand, which the
+        /// other one is required to be compatible with
+        expected: (u32, u32),
+        /// The shape actually found (as the one of the right-hand side operand, where vectors are
+        /// shaped as columns)
+        found: (u32, u32)
+    },
+    /// Error due to an index exceeding the dimension (such as of a sparse vector)
+    IndexOutOfBounds {
+        /// The index (starting from 1)
+        index: u64,
+        /// The dimension that the index was required not to exceed
+        dim: u64
+    }
+}
+
+impl fmt::Display for LinearAlgebraError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearAlgebraError::DimensionMismatch { expected, found } =>
+                write!(f, "Dimension mismatch (expected {expected}, found {found})"),
+            LinearAlgebraError::ShapeMismatch { expected: (rows, cols), found: (found_rows, found_cols) } =>
+                write!(f, "Shape mismatch (expected {rows}x{cols}, found {found_rows}x{found_cols})"),
+            LinearAlgebraError::IndexOutOfBounds { index, dim } =>
+                write!(f, "Index {index} out of bounds (dimension {dim})")
+        }
+    }
+}
+
+impl core::error::Error for LinearAlgebraError {}
+
+
+// Coherence rules don't allow a generic implementation of the scalar multiplication with the
+// scalar on the left-hand side (such as `2 * v`), therefore it's implemented for each of the
+// scalar types, by the containers of scalars (such as vectors and matrices) invoking this macro:
+//
+//     impl_scalar_mul!(Vector<_>, &Vector<_>);
+//     impl_scalar_mul!(SVector<_, const N: usize>);
+//
+// where `_` stands for the scalar type, and `&` requires the borrowed container to be scaled too.
+macro_rules! impl_scalar_mul {
+    ($container:ident<_ $(, const $n:ident: $nt:ty)*> $(, $($rest:tt)*)?) => {
+        impl_scalar_mul!(@scalars [[] $container [$(const $n: $nt,)*] [$($n),*]]);
+        $(impl_scalar_mul!($($rest)*);)?
+    };
+    (&$container:ident<_ $(, const $n:ident: $nt:ty)*> $(, $($rest:tt)*)?) => {
+        impl_scalar_mul!(@scalars [[&] $container [$(const $n: $nt,)*] [$($n),*]]);
+        $(impl_scalar_mul!($($rest)*);)?
+    };
+    (@scalars $container:tt) => {
+        impl_scalar_mul!(@scalar $container [] i8);
+        impl_scalar_mul!(@scalar $container [] i16);
+        impl_scalar_mul!(@scalar $container [] i32);
+        impl_scalar_mul!(@scalar $container [] i64);
+        impl_scalar_mul!(@scalar $container [] i128);
+        impl_scalar_mul!(@scalar $container [] isize);
+        impl_scalar_mul!(@scalar $container [] u8);
+        impl_scalar_mul!(@scalar $container [] u16);
+        impl_scalar_mul!(@scalar $container [] u32);
+        impl_scalar_mul!(@scalar $container [] u64);
+        impl_scalar_mul!(@scalar $container [] u128);
+        impl_scalar_mul!(@scalar $container [] usize);
+        impl_scalar_mul!(@scalar $container [] f32);
+        impl_scalar_mul!(@scalar $container [] f64);
+        impl_scalar_mul!(@scalar $container [] beaumont_numbers::Decimal);
+        impl_scalar_mul!(@scalar $container [T,] beaumont_numbers::Complex<T>);
+        impl_scalar_mul!(@scalar $container [T, const D: usize,] beaumont_numbers::Dual<T, D>);
+    };
+    (@scalar [[$($r:tt)?] $container:ident [$($g:tt)*] [$($n:ident),*]] [$($sg:tt)*] $t:ty) => {
+        impl<$($sg)* $($g)*> core::ops::Mul<$($r)? $container<$t $(, $n)*>> for $t
+        where $t: Copy + PartialEq + core::ops::Mul<Output = $t> {
+            type Output = $container<$t $(, $n)*>;
+
+            fn mul(self, rhs: $($r)? $container<$t $(, $n)*>) -> Self::Output {
+                rhs * self
+            }
+        }
+    };
+}
+
+
 mod vectors;
+mod svectors;
+mod sparse_vectors;
+mod vector_views;
 mod matrices;
+mod statistics;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "std")]
+mod fft;
 
 // Re-export the public contents of our private modules to the crate root
 pub use vectors::*;
-pub use matrices::*;
\ No newline at end of file
+pub use svectors::*;
+pub use sparse_vectors::*;
+pub use vector_views::*;
+pub use matrices::*;
+pub use statistics::*;
+#[cfg(feature = "rayon")]
+pub use parallel::PARALLEL_THRESHOLD;
+#[cfg(feature = "simd")]
+pub use simd::SimdFloat;
\ No newline at end of file
@@ -1,4 +1,5 @@
-use crate::Dimension;
+use crate::{Dimension, LinearAlgebraError};
+use alloc::boxed::Box;
 
 #[cfg_attr(doc, beaumont_macros::allow_katex)]
 /// Representation of a mathematical matrix.
@@ -32,6 +33,10 @@ use crate::Dimension;
 ///
 /// // Pass the components and the number of rows and columns
 /// let m4 = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+///
+/// // Print it with aligned columns (or render it in LaTeX)
+/// assert_eq!(m4.to_string(), "[1  2  3]\n[4  5  6]");
+/// assert_eq!(m4.to_latex(), "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6\n\\end{pmatrix}");
 /// ```
 ///
 #[derive(Debug)]
@@ -56,6 +61,19 @@ impl<T> Dimension for Matrix<T> where T: Copy + PartialEq {
 }
 
 
+impl<T> Matrix<T> where T: Copy + PartialEq {
+
+    // Check this matrix to have the same shape as the other (as required by the component-wise
+    // operations)
+    pub(crate) fn check_shape<U>(&self, other: &Matrix<U>) -> Result<(), LinearAlgebraError> where U: Copy + PartialEq {
+        if (self.rows, self.cols) != (other.rows, other.cols) {
+            return Err(LinearAlgebraError::ShapeMismatch { expected: (self.rows, self.cols), found: (other.rows, other.cols) });
+        }
+        Ok(())
+    }
+}
+
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -71,9 +89,24 @@ mod test {
         assert_eq!(m.rows, 2);
         assert_eq!(m.dim(), 6);
     }
+
+    #[test]
+    fn check_shape() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let n = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        assert_eq!(m.check_shape(&m), Ok(()));
+        assert_eq!(m.check_shape(&n), Err(LinearAlgebraError::ShapeMismatch { expected: (2, 3), found: (3, 2) }));
+    }
 }
 
 
 mod from;
 mod ops;
-// TODO mod iter;
+mod display;
+mod iter;
+mod transposed;
+#[cfg(feature = "rayon")]
+mod par;
+
+pub use iter::*;
+pub use transposed::*;
@@ -0,0 +1,154 @@
+use super::Matrix;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
+
+impl<T> fmt::Display for Matrix<T> where T: Copy + PartialEq + fmt::Display {
+
+    // Matrices are written as their bracketed rows, one per line, with the components of each
+    // column aligned to the right (unless another alignment is given). Each column is as wide as
+    // its widest component, or as the width flag (if wider), while the other formatting flags
+    // (such as the precision and the sign) are applied to each component.
+    //
+    //     [ 1.50  -2.00]
+    //     [10.00   0.25]
+    //
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = self.components.iter().map(|x| cell(x, f)).collect();
+        let cols = self.cols as usize;
+        let widths: Vec<usize> = (0..cols)
+            .map(|col| {
+                let widest = cells.iter().skip(col).step_by(cols).map(|c| c.chars().count()).max();
+                widest.unwrap_or(0).max(f.width().unwrap_or(0))
+            })
+            .collect();
+
+        for row in 0..self.rows as usize {
+            if row > 0 {
+                f.write_char('\n')?;
+            }
+            f.write_char('[')?;
+            for (col, width) in widths.iter().enumerate() {
+                if col > 0 {
+                    f.write_str("  ")?;
+                }
+                pad(f, &cells[row * cols + col], *width)?;
+            }
+            f.write_char(']')?;
+        }
+        Ok(())
+    }
+}
+
+// Format the given component with the precision and sign flags of the given formatter
+fn cell<T>(x: &T, f: &fmt::Formatter<'_>) -> String where T: fmt::Display {
+    match (f.precision(), f.sign_plus()) {
+        (Some(precision), true) => format!("{x:+.precision$}"),
+        (Some(precision), false) => format!("{x:.precision$}"),
+        (None, true) => format!("{x:+}"),
+        (None, false) => format!("{x}"),
+    }
+}
+
+// Write the given cell padded to the given width, according to the alignment and fill flags
+fn pad(f: &mut fmt::Formatter<'_>, cell: &str, width: usize) -> fmt::Result {
+    let padding = width.saturating_sub(cell.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(cell)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+
+impl<T> Matrix<T> where T: Copy + PartialEq + fmt::Display {
+
+    /// Render this matrix as a LaTeX `pmatrix` environment, which can be rendered by KaTeX as
+    /// well (such as in the documentation, see [`beaumont_macros::allow_katex`]).
+    ///
+    /// Note that the backslashes need to be escaped in Markdown (such as doc comments), where
+    /// the line breaks are written as `\\\\`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// assert_eq!(m.to_latex(), "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6\n\\end{pmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::from("\\begin{pmatrix}\n");
+        let cols = self.cols as usize;
+        for row in 0..self.rows as usize {
+            if row > 0 {
+                latex.push_str(" \\\\\n");
+            }
+            for col in 0..cols {
+                if col > 0 {
+                    latex.push_str(" & ");
+                }
+                // Writing into a string never fails
+                let _ = write!(latex, "{}", self.components[row * cols + col]);
+            }
+        }
+        if self.rows > 0 {
+            latex.push('\n');
+        }
+        latex.push_str("\\end{pmatrix}");
+        latex
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn display() {
+        let m = Matrix::from(([1, -20, 3, 4, 5, 600], 2, 3));
+        assert_eq!(m.to_string(), "[1  -20    3]\n[4    5  600]");
+
+        let empty: Matrix<i32> = Matrix::from(([], 0, 3));
+        assert_eq!(empty.to_string(), "");
+        let no_cols: Matrix<i32> = Matrix::from(([], 2, 0));
+        assert_eq!(no_cols.to_string(), "[]\n[]");
+    }
+
+    #[test]
+    fn display_with_flags() {
+        let m = Matrix::from(([1.5, -2.0, 10.0, 0.25], 2, 2));
+        assert_eq!(format!("{m:.2}"), "[ 1.50  -2.00]\n[10.00   0.25]");
+        assert_eq!(format!("{m:6.1}"), "[   1.5    -2.0]\n[  10.0     0.2]");
+        assert_eq!(format!("{m:<+}"), "[+1.5  -2   ]\n[+10   +0.25]");
+        assert_eq!(format!("{m:*^5}"), "[*1.5*  *-2**]\n[*10**  0.25*]");
+
+        let d = Matrix::from(([Decimal::new(15, 1), Decimal::new(-2, 0), Decimal::new(10, 0), Decimal::new(25, 2)], 2, 2));
+        assert_eq!(format!("{d:.2}"), "[ 1.50  -2.00]\n[10.00   0.25]");
+        assert_eq!(format!("{d:6.1}"), "[   1.5    -2.0]\n[  10.0     0.3]");
+        assert_eq!(format!("{d:<+}"), "[+1.5  -2   ]\n[+10   +0.25]");
+    }
+
+    #[test]
+    fn to_latex() {
+        let m = Matrix::from(([1.5, -2.0], 1, 2));
+        assert_eq!(m.to_latex(), "\\begin{pmatrix}\n1.5 & -2\n\\end{pmatrix}");
+        let empty: Matrix<i32> = Matrix::from(([], 0, 0));
+        assert_eq!(empty.to_latex(), "\\begin{pmatrix}\n\\end{pmatrix}");
+    }
+}
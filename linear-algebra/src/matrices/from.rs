@@ -1,4 +1,5 @@
 use super::Matrix;
+use alloc::boxed::Box;
 
 
 /// Create a new mathematical vector from a boxed slice, with the given number of rows and columns.
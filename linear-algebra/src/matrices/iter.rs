@@ -0,0 +1,265 @@
+use super::Matrix;
+use crate::vector_views::Layout;
+use crate::{VectorView, VectorViewIterator};
+use core::iter::{Enumerate, FusedIterator};
+use core::ops::Range;
+
+/// Iterator over the rows or the columns of a matrix, as views of their components (such as the
+/// ones returned by [`Matrix::rows`] and [`Matrix::cols`]).
+#[derive(Clone)]
+pub struct MatrixLinesIterator<'a, T> where T: Copy + PartialEq {
+    // The components of the matrix (in row-major order)
+    components: &'a [T],
+    // The positions (from 0) of the remaining lines
+    lines: Range<usize>,
+    // The distance between the first components of two consecutive lines
+    offset: usize,
+    // The distance between two consecutive components of a line
+    stride: usize,
+    // The number of the components of each line
+    len: usize,
+}
+
+impl<'a, T> MatrixLinesIterator<'a, T> where T: Copy + PartialEq {
+
+    // The iterator over the rows of the given matrix
+    pub(crate) fn rows(matrix: &'a Matrix<T>) -> Self {
+        let cols = matrix.cols as usize;
+        MatrixLinesIterator { components: &matrix.components, lines: 0..matrix.rows as usize, offset: cols, stride: 1, len: cols }
+    }
+
+    // The iterator over the columns of the given matrix
+    pub(crate) fn cols(matrix: &'a Matrix<T>) -> Self {
+        let (rows, cols) = (matrix.rows as usize, matrix.cols as usize);
+        MatrixLinesIterator { components: &matrix.components, lines: 0..cols, offset: 1, stride: cols, len: rows }
+    }
+
+    // The view of the line at the given position (from 0)
+    fn line(&self, line: usize) -> VectorView<'a, T> {
+        if self.len == 0 {
+            return VectorView { components: &[], layout: Layout::CONTIGUOUS };
+        }
+        let first = line * self.offset;
+        let span = first..first + (self.len - 1) * self.stride + 1;
+        VectorView { components: &self.components[span], layout: Layout::strided(self.stride) }
+    }
+}
+
+impl<'a, T> Iterator for MatrixLinesIterator<'a, T> where T: Copy + PartialEq {
+    type Item = VectorView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next().map(|line| self.line(line))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.lines.nth(n).map(|line| self.line(line))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MatrixLinesIterator<'a, T> where T: Copy + PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.lines.next_back().map(|line| self.line(line))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixLinesIterator<'a, T> where T: Copy + PartialEq {}
+
+impl<'a, T> FusedIterator for MatrixLinesIterator<'a, T> where T: Copy + PartialEq {}
+
+
+/// Iterator over the components of a matrix (in row-major order) together with their
+/// `(row, col)` indices, starting from 1 (as returned by [`Matrix::enumerate_indexed`]).
+pub struct MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {
+    // The enumerated components of the matrix, which shall outlive this field
+    components: Enumerate<core::slice::Iter<'a, T>>,
+    // The number of the columns of the matrix
+    cols: usize,
+}
+
+impl<'a, T> MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {
+
+    // The indices (starting from 1) of the component at the given position (from 0)
+    fn indexed(&self, (i, x): (usize, &T)) -> ((u32, u32), T) {
+        (((i / self.cols + 1) as u32, (i % self.cols + 1) as u32), *x)
+    }
+}
+
+impl<'a, T> Iterator for MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {
+    type Item = ((u32, u32), T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.components.next().map(|next| self.indexed(next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.components.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.components.next_back().map(|next| self.indexed(next))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {}
+
+impl<'a, T> FusedIterator for MatrixIndexedIterator<'a, T> where T: Copy + PartialEq {}
+
+
+impl<T> Matrix<T> where T: Copy + PartialEq {
+
+    /// The iterator over the rows of this matrix, as views of their components (without copying
+    /// them).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // The daily returns of 2 assets (one per row), over 3 days
+    /// let returns = matrix![ 1, 2, 3 |
+    ///                      | 4, 5, 6 ];
+    /// let totals: Vec<i32> = returns.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(totals, [6, 15]);
+    /// assert_eq!(returns.rows().last().unwrap(), vector![4, 5, 6]);
+    /// ```
+    pub fn rows(&self) -> MatrixLinesIterator<'_, T> {
+        MatrixLinesIterator::rows(self)
+    }
+
+    /// The iterator over the columns of this matrix, as (strided) views of their components
+    /// (without copying them).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let totals: Vec<i32> = m.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(totals, [5, 7, 9]);
+    /// assert_eq!(m.cols().nth(1).unwrap(), vector![2, 5]);
+    /// ```
+    pub fn cols(&self) -> MatrixLinesIterator<'_, T> {
+        MatrixLinesIterator::cols(self)
+    }
+
+    /// The iterator over the components of this matrix, in row-major order (that is, all the
+    /// components of the first row, then the ones of the second row, and so on).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2 |
+    ///                | 3, 4 ];
+    /// assert_eq!(m.iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// assert_eq!(m.iter().max(), Some(4));
+    /// ```
+    pub fn iter(&self) -> VectorViewIterator<'_, T> {
+        VectorViewIterator::new(&self.components, Layout::CONTIGUOUS)
+    }
+
+    /// The iterator over mutable references to the components of this matrix (in row-major
+    /// order), which allows modifying them in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut m = matrix![ 1, 2 |
+    ///                    | 3, 4 ];
+    /// for x in m.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(m.iter().collect::<Vec<_>>(), [10, 20, 30, 40]);
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.components.iter_mut()
+    }
+
+    /// The iterator over the components of this matrix (in row-major order) together with their
+    /// `(row, col)` indices, starting from 1 (as the ones of the indexing operator).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2 |
+    ///                | 3, 4 ];
+    /// let trace: i32 = m.enumerate_indexed().filter(|((i, j), _)| i == j).map(|(_, x)| x).sum();
+    /// assert_eq!(trace, 5);
+    /// assert_eq!(m.enumerate_indexed().nth(2), Some(((2, 1), 3)));
+    /// ```
+    pub fn enumerate_indexed(&self) -> MatrixIndexedIterator<'_, T> {
+        MatrixIndexedIterator { components: self.components.iter().enumerate(), cols: self.cols as usize }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Vector;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn rows() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        let rows: Vec<Vector<i32>> = m.rows().map(|row| row.to_vector()).collect();
+        assert_eq!(rows, [Vector::from([1, 2]), Vector::from([3, 4]), Vector::from([5, 6])]);
+        assert_eq!(m.rows().len(), 3);
+        assert_eq!(m.rows().next_back().unwrap(), Vector::from([5, 6]));
+        assert_eq!(m.rows().nth(1).unwrap(), Vector::from([3, 4]));
+    }
+
+    #[test]
+    fn cols() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        let cols: Vec<Vector<i32>> = m.cols().map(|col| col.to_vector()).collect();
+        assert_eq!(cols, [Vector::from([1, 3, 5]), Vector::from([2, 4, 6])]);
+        assert_eq!(m.cols().len(), 2);
+
+        // The columns are views, which can be narrowed down as well
+        let col = m.cols().next_back().unwrap();
+        assert_eq!(col[3], 6);
+        assert_eq!(col.reversed(), Vector::from([6, 4, 2]));
+        assert_eq!(col.slice(2..), Vector::from([4, 6]));
+    }
+
+    #[test]
+    fn rows_and_cols_of_empty_matrices() {
+        let no_cols: Matrix<i32> = Matrix::from(([], 2, 0));
+        assert_eq!(no_cols.rows().len(), 2);
+        assert!(no_cols.rows().all(|row| row.iter().len() == 0));
+        assert_eq!(no_cols.cols().len(), 0);
+
+        let no_rows: Matrix<i32> = Matrix::from(([], 0, 3));
+        assert_eq!(no_rows.rows().len(), 0);
+        assert_eq!(no_rows.cols().len(), 3);
+        assert!(no_rows.cols().all(|col| col.iter().len() == 0));
+    }
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut m = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        assert_eq!(m.iter().rev().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1]);
+        m.iter_mut().for_each(|x| *x -= 1);
+        assert_eq!(m, Matrix::from(([0, 1, 2, 3, 4, 5], 2, 3)));
+    }
+
+    #[test]
+    fn enumerate_indexed() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let indexed: Vec<((u32, u32), i32)> = m.enumerate_indexed().collect();
+        assert_eq!(indexed, [((1, 1), 1), ((1, 2), 2), ((1, 3), 3), ((2, 1), 4), ((2, 2), 5), ((2, 3), 6)]);
+        assert!(m.enumerate_indexed().all(|(index, x)| m[index] == x));
+        assert_eq!(m.enumerate_indexed().next_back(), Some(((2, 3), 6)));
+    }
+}
@@ -1,2 +1,7 @@
-
-mod idx;
\ No newline at end of file
+mod eq;
+mod idx;
+mod neg;
+mod scale;
+mod add;
+mod sub;
+mod mul;
@@ -0,0 +1,161 @@
+use crate::{LinearAlgebraError, Matrix};
+use core::ops::{Add, AddAssign};
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Add this matrix to the other matrix.
+    ///
+    /// Given two matrices $\textbf{A}$ and $\textbf{B}$ of the same dimension $m \times n$, their
+    /// addition is defined by the sum of the correspondent components.
+    ///
+    /// $$
+    /// \textbf{A} + \textbf{B} \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// a_{11} + b_{11} & \dots & a_{1n} + b_{1n} \\\\
+    /// \dots           & \dots & \dots           \\\\
+    /// a_{m1} + b_{m1} & \dots & a_{mn} + b_{mn}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// It panics if the matrices have different shapes (as the `+` operator applied to matrix
+    /// references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let n = matrix![ 10, 20, 30 |
+    ///                | 40, 50, 60 ];
+    /// let expected = matrix![ 11, 22, 33 |
+    ///                       | 44, 55, 66 ];
+    /// assert_eq!(Matrix::add(&m, &n), expected);
+    /// assert_eq!(&m + &n, expected);
+    /// ```
+    pub fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    /// Same as the [`Matrix::add`] method, but it returns an error (instead of panicking) if the
+    /// matrices have different shapes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let n = matrix![ 1, 2 |
+    ///                | 3, 4 |
+    ///                | 5, 6 ];
+    /// let err = m.try_add(&n).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::ShapeMismatch { expected: (2, 3), found: (3, 2) });
+    /// ```
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> {
+        self.check_shape(rhs)?;
+        Ok(self.add(rhs))
+    }
+}
+
+
+impl<T> Add<&Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Matrix<T>;
+
+    /// Add this matrix to the other matrix (see [`Matrix::add`]), and it panics if they have
+    /// different shapes.
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "must have the same shape");
+        Matrix {
+            components: self.components.iter().zip(&rhs.components).map(|(x, y)| *x + *y).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Add<Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<T> Add<&Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> Add<Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Self::Output {
+        self + &rhs
+    }
+}
+
+
+impl<T> AddAssign<&Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+
+    /// Add the other matrix to this matrix (in place), and it panics if they have different
+    /// shapes.
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "must have the same shape");
+        for (x, y) in self.components.iter_mut().zip(&rhs.components) {
+            *x = *x + *y;
+        }
+    }
+}
+
+impl<T> AddAssign<Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> {
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_operator_owned_and_borrowed() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        let n = Matrix::from(([6, 5, 4, 3, 2, 1], 3, 2));
+        let expected = Matrix::from(([7; 6], 3, 2));
+        assert_eq!(&m + &n, expected);
+        assert_eq!(&m + Matrix::from(([6, 5, 4, 3, 2, 1], 3, 2)), expected);
+        assert_eq!(Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2)) + &n, expected);
+        assert_eq!(m + n, expected);
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut m = Matrix::from(([1.5, 2.5], 1, 2));
+        m += &Matrix::from(([1.0, -1.0], 1, 2));
+        m += Matrix::from(([0.5, 0.5], 1, 2));
+        assert_eq!(m, Matrix::from(([3.0, 2.0], 1, 2)));
+    }
+
+    #[test]
+    fn try_add() {
+        let m = Matrix::from(([1, 2, 3], 1, 3));
+        assert_eq!(m.try_add(&m), Ok(Matrix::from(([2, 4, 6], 1, 3))));
+        // The same number of components isn't enough
+        let n = Matrix::from(([1, 2, 3], 3, 1));
+        assert_eq!(m.try_add(&n), Err(LinearAlgebraError::ShapeMismatch { expected: (1, 3), found: (3, 1) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same shape")]
+    fn add_matrices_of_different_shapes() {
+        let _ = Matrix::from(([1, 2, 3, 4], 2, 2)) + Matrix::from(([1, 2, 3, 4], 1, 4));
+    }
+}
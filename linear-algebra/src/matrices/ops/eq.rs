@@ -0,0 +1,46 @@
+use crate::Matrix;
+
+impl<T> PartialEq for Matrix<T> where T: Copy + PartialEq {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Tests for `self` and `other` values to be equal, and is used by `==`.
+    ///
+    /// Note that matrices, such as $\textbf{A}$ and $\textbf{B}$ of dimension $m \times n$, are
+    /// considered to be equal if and only if all of their correspondent scalar components are
+    /// equal:
+    ///
+    /// $$
+    /// \begin{aligned}
+    /// &\textbf{A} = \textbf{B} \iff a_{ij} = b_{ij} \quad \forall i = 1, 2, \dots, m \quad \forall j = 1, 2, \dots, n
+    /// \end{aligned}
+    /// $$
+    ///
+    /// Therefore, matrices of different shapes are never equal (even if they have the same
+    /// components, such as a row and a column).
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows &&
+        self.cols == other.cols &&
+        self.components.iter()
+            .zip(&other.components)
+            .all(|(x, y)| x.eq(y))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn eq_matrices() {
+        let m = Matrix::from(([1, 2, 3, 4], 2, 2));
+        assert_eq!(m, Matrix::from(([1, 2, 3, 4], 2, 2)));
+        assert_ne!(m, Matrix::from(([1, 2, 3, 5], 2, 2)));
+    }
+
+    #[test]
+    fn eq_matrices_of_different_shapes() {
+        assert_ne!(Matrix::from(([1, 2, 3], 1, 3)), Matrix::from(([1, 2, 3], 3, 1)));
+        assert_ne!(Matrix::<i32>::from(([], 0, 2)), Matrix::from(([], 2, 0)));
+    }
+}
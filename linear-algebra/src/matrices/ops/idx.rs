@@ -1,4 +1,4 @@
-use std::ops::Index;
+use core::ops::Index;
 use crate::Matrix;
 
 impl<T> Index<(u32, u32)> for Matrix<T> where T: Copy + PartialEq {
@@ -27,6 +27,7 @@ impl<T> Index<(u32, u32)> for Matrix<T> where T: Copy + PartialEq {
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     fn idx() {
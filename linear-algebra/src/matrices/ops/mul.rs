@@ -0,0 +1,301 @@
+use crate::{Dimension, LinearAlgebraError, Matrix, Vector};
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Multiply this matrix by the other matrix.
+    ///
+    /// Given a matrix $\textbf{A}$ of dimension $m \times n$ and a matrix $\textbf{B}$ of
+    /// dimension $n \times p$, their product is the matrix $\textbf{C}$ of dimension
+    /// $m \times p$ whose components are the dot products of the rows of $\textbf{A}$ and the
+    /// columns of $\textbf{B}$:
+    ///
+    /// $$
+    /// c_{ij} \quad \equiv \quad \sum_{k=1}^{n} a_{ik} b_{kj}
+    /// $$
+    ///
+    /// It panics if the number of columns of this matrix is different from the number of rows
+    /// of the other one (as the `*` operator applied to matrix references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let a = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let b = matrix![ 1, 0 |
+    ///                | 0, 1 |
+    ///                | 2, 2 ];
+    /// let expected = matrix![  7,  8 |
+    ///                       | 16, 17 ];
+    /// assert_eq!(Matrix::mul(&a, &b), expected);
+    /// assert_eq!(&a * &b, expected);
+    /// ```
+    pub fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    /// Same as the [`Matrix::mul`] method, but it returns an error (instead of panicking) if the
+    /// number of columns of this matrix is different from the number of rows of the other one
+    /// (reported along with the shapes of both).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let a = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let err = a.try_mul(&a).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::ShapeMismatch { expected: (2, 3), found: (2, 3) });
+    /// ```
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> {
+        if self.cols != rhs.rows {
+            return Err(LinearAlgebraError::ShapeMismatch { expected: (self.rows, self.cols), found: (rhs.rows, rhs.cols) });
+        }
+        Ok(self.mul(rhs))
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Multiply this matrix by the given vector.
+    ///
+    /// Given a matrix $\textbf{A}$ of dimension $m \times n$ and a vector $\vec{v}$ in the
+    /// n-dimensional space, their product is the vector in the m-dimensional space whose
+    /// components are the dot products of the rows of $\textbf{A}$ and $\vec{v}$:
+    ///
+    /// $$
+    /// (\textbf{A} \vec{v})_{i} \quad \equiv \quad \sum_{k=1}^{n} a_{ik} v_{k}
+    /// $$
+    ///
+    /// It panics if the number of columns of this matrix is different from the dimension of the
+    /// vector (as the `*` operator applied to matrix and vector references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // The exposures of 2 portfolios (one per row) to 3 assets, and the returns of the assets
+    /// let exposures = matrix![ 0.5 , 0.5, 0.0 |
+    ///                        | 0.25, 0.0, 1.0 ];
+    /// let returns = vector![0.25, 0.75, 0.5];
+    /// assert_eq!(exposures.mul_vector(&returns), vector![0.5, 0.5625]);
+    /// assert_eq!(&exposures * &returns, vector![0.5, 0.5625]);
+    /// ```
+    pub fn mul_vector(&self, v: &Vector<T>) -> Vector<T> {
+        self * v
+    }
+
+    /// Same as the [`Matrix::mul_vector`] method, but it returns an error (instead of panicking)
+    /// if the number of columns of this matrix is different from the dimension of the vector
+    /// (reported along with the shapes of both, where the vector is shaped as a column).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let a = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let err = a.try_mul_vector(&vector![1, 2]).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::ShapeMismatch { expected: (2, 3), found: (2, 1) });
+    /// ```
+    pub fn try_mul_vector(&self, v: &Vector<T>) -> Result<Vector<T>, LinearAlgebraError> {
+        if self.cols as u64 != v.dim() {
+            let rows = u32::try_from(v.dim()).unwrap_or(u32::MAX);
+            return Err(LinearAlgebraError::ShapeMismatch { expected: (self.rows, self.cols), found: (rows, 1) });
+        }
+        Ok(self.mul_vector(v))
+    }
+}
+
+
+impl<T> Mul<&Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Matrix<T>;
+
+    /// Multiply this matrix by the other matrix (see [`Matrix::mul`]), and it panics if the
+    /// number of columns of this matrix is different from the number of rows of the other one.
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows, "must have as many columns as the rows of the other matrix");
+        let (n, p) = (self.cols as usize, rhs.cols as usize);
+
+        // The columns of the other matrix are copied beforehand, so that both the operands of
+        // each dot product are contiguous in memory
+        let columns: Vec<T> = (0..p)
+            .flat_map(|j| rhs.components.iter().skip(j).step_by(p).copied())
+            .collect();
+
+        let columns = &columns;
+        let components = (0..self.rows as usize)
+            .flat_map(|i| {
+                let row = &self.components[i * n..(i + 1) * n];
+                (0..p).map(move |j| {
+                    let column = &columns[j * n..(j + 1) * n];
+                    row.iter().zip(column).map(|(a, b)| *a * *b).sum::<T>()
+                })
+            })
+            .collect();
+
+        Matrix { components, rows: self.rows, cols: rhs.cols }
+    }
+}
+
+impl<T> Mul<Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<T> Mul<Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+
+impl<T> Mul<&Vector<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Vector<T>;
+
+    /// Multiply this matrix by the vector (see [`Matrix::mul_vector`]), and it panics if the
+    /// number of columns of this matrix is different from the dimension of the vector.
+    fn mul(self, v: &Vector<T>) -> Self::Output {
+        assert_eq!(self.cols as u64, v.dim(), "must have the same dimension");
+        let n = self.cols as usize;
+        let v = v.as_slice();
+        (0..self.rows as usize)
+            .map(|i| {
+                let row = &self.components[i * n..(i + 1) * n];
+                row.iter().zip(v).map(|(a, b)| *a * *b).sum::<T>()
+            })
+            .collect()
+    }
+}
+
+impl<T> Mul<Vector<T>> for &Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Self::Output {
+        self * &v
+    }
+}
+
+impl<T> Mul<&Vector<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Self::Output {
+        &self * v
+    }
+}
+
+impl<T> Mul<Vector<T>> for Matrix<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Self::Output {
+        &self * &v
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The product computed by its definition, with the components indexed starting from 1
+    fn naive_mul(a: &Matrix<i64>, b: &Matrix<i64>) -> Matrix<i64> {
+        let mut components = Vec::new();
+        for i in 1..=a.rows {
+            for j in 1..=b.cols {
+                components.push((1..=a.cols).map(|k| a[(i, k)] * b[(k, j)]).sum());
+            }
+        }
+        Matrix::from((components.into_boxed_slice(), a.rows, b.cols))
+    }
+
+    fn matrix(rows: u32, cols: u32, seed: i64) -> Matrix<i64> {
+        let components: Vec<i64> = (0..(rows * cols) as i64).map(|i| (i * 7 + seed) % 11 - 5).collect();
+        Matrix::from((components.into_boxed_slice(), rows, cols))
+    }
+
+    #[test]
+    fn mul_matrices() {
+        for (m, n, p) in [(1, 1, 1), (2, 3, 2), (3, 1, 4), (4, 4, 4), (5, 7, 3), (1, 6, 1)] {
+            let a = matrix(m, n, 1);
+            let b = matrix(n, p, 2);
+            assert_eq!(&a * &b, naive_mul(&a, &b), "shapes {m}x{n} and {n}x{p}");
+        }
+    }
+
+    #[test]
+    fn mul_matrices_owned_and_borrowed() {
+        let a = Matrix::from(([1.0, 2.0, 3.0, 4.0], 2, 2));
+        let identity = Matrix::from(([1.0, 0.0, 0.0, 1.0], 2, 2));
+        let expected = Matrix::from(([1.0, 2.0, 3.0, 4.0], 2, 2));
+        assert_eq!(&a * &identity, expected);
+        assert_eq!(&identity * Matrix::from(([1.0, 2.0, 3.0, 4.0], 2, 2)), expected);
+        assert_eq!(Matrix::from(([1.0, 2.0, 3.0, 4.0], 2, 2)) * &identity, expected);
+        assert_eq!(a * identity, expected);
+    }
+
+    #[test]
+    fn mul_empty_matrices() {
+        // The inner dimension is 0, therefore all the components are empty sums
+        let a: Matrix<i32> = Matrix::from(([], 2, 0));
+        let b: Matrix<i32> = Matrix::from(([], 0, 3));
+        assert_eq!(&a * &b, Matrix::from(([0; 6], 2, 3)));
+        assert_eq!(&b * &Matrix::from(([1, 2, 3], 3, 1)), Matrix::from(([], 0, 1)));
+        assert_eq!(&a * &Vector::from([]), Vector::from([0, 0]));
+    }
+
+    #[test]
+    fn try_mul() {
+        let a = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let b = Matrix::from(([1, 2, 3], 3, 1));
+        assert_eq!(a.try_mul(&b), Ok(Matrix::from(([14, 32], 2, 1))));
+        assert_eq!(b.try_mul(&a), Err(LinearAlgebraError::ShapeMismatch { expected: (3, 1), found: (2, 3) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have as many columns as the rows of the other matrix")]
+    fn mul_matrices_of_incompatible_shapes() {
+        let a = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn mul_vector() {
+        let a = matrix(4, 3, 3);
+        let v = Vector::from([2, -1, 3]);
+        let column = Matrix::from(([2, -1, 3], 3, 1));
+        let expected: Vector<i64> = naive_mul(&a, &column).components.iter().copied().collect();
+        assert_eq!(&a * &v, expected);
+        assert_eq!(&a * Vector::from([2, -1, 3]), expected);
+        assert_eq!(matrix(4, 3, 3) * &v, expected);
+        assert_eq!(a * v, expected);
+    }
+
+    #[test]
+    fn try_mul_vector() {
+        let a = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        assert_eq!(a.try_mul_vector(&Vector::from([1, 1, 1])), Ok(Vector::from([6, 15])));
+        assert_eq!(a.try_mul_vector(&Vector::from([1, 1])), Err(LinearAlgebraError::ShapeMismatch { expected: (2, 3), found: (2, 1) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn mul_vector_of_different_dimension() {
+        let _ = Matrix::from(([1, 2, 3, 4], 2, 2)).mul_vector(&Vector::from([1, 2, 3]));
+    }
+}
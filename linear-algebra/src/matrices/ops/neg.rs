@@ -0,0 +1,73 @@
+use crate::Matrix;
+use core::ops::Neg;
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Neg<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Negate this matrix, as the matrix of the negated components:
+    ///
+    /// $$
+    /// -\textbf{A} \quad \equiv \quad
+    /// -1 \cdot \textbf{A} \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// -a_{11} & \dots & -a_{1n} \\\\
+    /// \dots   & \dots & \dots   \\\\
+    /// -a_{m1} & \dots & -a_{mn}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// This method is a thin wrapper around the `-` (unary) operator applied to a matrix reference.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2 |
+    ///                | 3, 4 ];
+    /// assert_eq!(Matrix::neg(&m), Matrix::from(([-1, -2, -3, -4], 2, 2)));
+    /// assert_eq!(-&m, Matrix::from(([-1, -2, -3, -4], 2, 2)));
+    /// ```
+    pub fn neg(&self) -> Matrix<T> {
+        -self
+    }
+}
+
+
+impl<T> Neg for &Matrix<T> where T: Copy + PartialEq + Neg<Output = T> {
+    type Output = Matrix<T>;
+
+    /// Negate this matrix (see [`Matrix::neg`])
+    fn neg(self) -> Self::Output {
+        Matrix {
+            components: self.components.iter().map(|x| -*x).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Neg for Matrix<T> where T: Copy + PartialEq + Neg<Output = T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Self::Output {
+        for x in self.components.iter_mut() {
+            *x = -*x;
+        }
+        self
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neg_operator_owned_and_borrowed() {
+        let m = Matrix::from(([1.5, -2.0, 0.0], 1, 3));
+        let expected = Matrix::from(([-1.5, 2.0, -0.0], 1, 3));
+        assert_eq!(-&m, expected);
+        assert_eq!(-m, expected);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::Matrix;
+use core::ops::{Mul, MulAssign};
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Mul<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Multiply this matrix by a scalar, as the matrix of the multiplied components:
+    ///
+    /// $$
+    /// c \cdot \textbf{A} \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// c a_{11} & \dots & c a_{1n} \\\\
+    /// \dots    & \dots & \dots    \\\\
+    /// c a_{m1} & \dots & c a_{mn}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// This method is a thin wrapper around the `*` operator applied to a matrix reference and a
+    /// scalar (which can be written on either side, for the built-in numerical types and decimals).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2 |
+    ///                | 3, 4 ];
+    /// let expected = matrix![ 2, 4 |
+    ///                       | 6, 8 ];
+    /// assert_eq!(m.scale_by(2), expected);
+    /// assert_eq!(&m * 2, expected);
+    /// assert_eq!(2 * &m, expected);
+    /// ```
+    pub fn scale_by(&self, scalar: T) -> Self {
+        self * scalar
+    }
+}
+
+
+impl<T> Mul<T> for &Matrix<T> where T: Copy + PartialEq + Mul<Output = T> {
+    type Output = Matrix<T>;
+
+    /// Multiply this matrix by a scalar (see [`Matrix::scale_by`])
+    fn mul(self, scalar: T) -> Self::Output {
+        Matrix {
+            components: self.components.iter().map(|x| *x * scalar).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Mul<T> for Matrix<T> where T: Copy + PartialEq + Mul<Output = T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Multiply this matrix by a scalar (in place)
+    fn mul_assign(&mut self, scalar: T) {
+        for x in self.components.iter_mut() {
+            *x = *x * scalar;
+        }
+    }
+}
+
+
+impl_scalar_mul!(Matrix<_>, &Matrix<_>);
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn mul_operator_owned_and_borrowed() {
+        let m = Matrix::from(([1.5, -2.0, 0.5, 4.0], 2, 2));
+        let expected = Matrix::from(([3.0, -4.0, 1.0, 8.0], 2, 2));
+        assert_eq!(&m * 2.0, expected);
+        assert_eq!(2.0 * &m, expected);
+        assert_eq!(2.0 * Matrix::from(([1.5, -2.0, 0.5, 4.0], 2, 2)), expected);
+        assert_eq!(m * 2.0, expected);
+    }
+
+    #[test]
+    fn mul_assign() {
+        let mut m = Matrix::from(([1, -2, 3], 3, 1));
+        m *= -3;
+        assert_eq!(m, Matrix::from(([-3, 6, -9], 3, 1)));
+    }
+
+    #[test]
+    fn mul_decimal_on_the_left() {
+        let m = Matrix::from(([Decimal::new(150, 2), Decimal::new(-2, 0)], 1, 2));
+        let scaled = Decimal::new(2, 0) * m;
+        assert_eq!(scaled, Matrix::from(([Decimal::new(3, 0), Decimal::new(-4, 0)], 1, 2)));
+    }
+
+    #[test]
+    fn mul_complex_on_the_left() {
+        use beaumont_numbers::Complex;
+        let m = Matrix::from(([Complex::new(1, 0), Complex::new(1, 1)], 2, 1));
+        assert_eq!(Complex::new(0, 1) * &m, Matrix::from(([Complex::new(0, 1), Complex::new(-1, 1)], 2, 1)));
+    }
+}
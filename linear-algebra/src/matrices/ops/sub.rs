@@ -0,0 +1,148 @@
+use crate::{LinearAlgebraError, Matrix};
+use core::ops::{Sub, SubAssign};
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Subtract the other matrix from this matrix.
+    ///
+    /// Given two matrices $\textbf{A}$ and $\textbf{B}$ of the same dimension $m \times n$, their
+    /// subtraction is defined by the difference of the correspondent components.
+    ///
+    /// $$
+    /// \textbf{A} - \textbf{B} \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// a_{11} - b_{11} & \dots & a_{1n} - b_{1n} \\\\
+    /// \dots           & \dots & \dots           \\\\
+    /// a_{m1} - b_{m1} & \dots & a_{mn} - b_{mn}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// It panics if the matrices have different shapes (as the `-` operator applied to matrix
+    /// references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 11, 22, 33 |
+    ///                | 44, 55, 66 ];
+    /// let n = matrix![ 10, 20, 30 |
+    ///                | 40, 50, 60 ];
+    /// let expected = matrix![ 1, 2, 3 |
+    ///                       | 4, 5, 6 ];
+    /// assert_eq!(Matrix::sub(&m, &n), expected);
+    /// assert_eq!(&m - &n, expected);
+    /// ```
+    pub fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    /// Same as the [`Matrix::sub`] method, but it returns an error (instead of panicking) if the
+    /// matrices have different shapes.
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> {
+        self.check_shape(rhs)?;
+        Ok(self.sub(rhs))
+    }
+}
+
+
+impl<T> Sub<&Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+    type Output = Matrix<T>;
+
+    /// Subtract the other matrix from this matrix (see [`Matrix::sub`]), and it panics if they
+    /// have different shapes.
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "must have the same shape");
+        Matrix {
+            components: self.components.iter().zip(&rhs.components).map(|(x, y)| *x - *y).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Sub<Matrix<T>> for &Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Matrix<T>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<T> Sub<&Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> Sub<Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Matrix<T>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+
+impl<T> SubAssign<&Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+
+    /// Subtract the other matrix from this matrix (in place), and it panics if they have
+    /// different shapes.
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "must have the same shape");
+        for (x, y) in self.components.iter_mut().zip(&rhs.components) {
+            *x = *x - *y;
+        }
+    }
+}
+
+impl<T> SubAssign<Matrix<T>> for Matrix<T> where T: Copy + PartialEq + Sub<Output = T> {
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sub_operator_owned_and_borrowed() {
+        let m = Matrix::from(([7; 6], 2, 3));
+        let n = Matrix::from(([6, 5, 4, 3, 2, 1], 2, 3));
+        let expected = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        assert_eq!(&m - &n, expected);
+        assert_eq!(&m - Matrix::from(([6, 5, 4, 3, 2, 1], 2, 3)), expected);
+        assert_eq!(Matrix::from(([7; 6], 2, 3)) - &n, expected);
+        assert_eq!(m - n, expected);
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut m = Matrix::from(([1.5, 2.5], 2, 1));
+        m -= &Matrix::from(([1.0, -1.0], 2, 1));
+        m -= Matrix::from(([0.5, 0.5], 2, 1));
+        assert_eq!(m, Matrix::from(([0.0, 3.0], 2, 1)));
+    }
+
+    #[test]
+    fn try_sub() {
+        let m = Matrix::from(([1, 2, 3, 4], 2, 2));
+        assert_eq!(m.try_sub(&m), Ok(Matrix::from(([0; 4], 2, 2))));
+        let n = Matrix::from(([1, 2], 1, 2));
+        assert_eq!(m.try_sub(&n), Err(LinearAlgebraError::ShapeMismatch { expected: (2, 2), found: (1, 2) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same shape")]
+    fn sub_assign_matrices_of_different_shapes() {
+        let mut m = Matrix::from(([1, 2, 3, 4], 2, 2));
+        m -= Matrix::from(([1, 2, 3, 4], 4, 1));
+    }
+}
@@ -0,0 +1,153 @@
+use super::Matrix;
+use crate::parallel::PARALLEL_THRESHOLD;
+use crate::vector_views::Layout;
+use crate::{Dimension, Vector, VectorView, VectorViewMut};
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+use rayon::prelude::*;
+
+impl<T> Matrix<T> where T: Copy + PartialEq + Send + Sync {
+
+    // Whether the components of this matrix are too few to be worth processing in parallel
+    fn is_small(&self) -> bool {
+        self.dim() < PARALLEL_THRESHOLD as u64
+    }
+
+    // The view of the given row, indexed starting from 0
+    fn row_view(&self, row: usize) -> VectorView<'_, T> {
+        let cols = self.cols as usize;
+        VectorView { components: &self.components[row * cols..(row + 1) * cols], layout: Layout::CONTIGUOUS }
+    }
+
+    /// Create a new vector by applying the given function to (a view of) each row of this matrix,
+    /// with the rows processed in parallel (unless this matrix has less components than the
+    /// [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// As each row is processed by a single thread, the results are always the same (however
+    /// many threads).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // The daily prices of 3 assets (one per row), over 4 days
+    /// let prices = matrix![ 10.5, 11.0, 12.0, 10.5 |
+    ///                     | 20.0, 20.0, 21.0, 22.0 |
+    ///                     |  5.0,  3.0,  4.0,  3.5 ];
+    /// let highs = prices.par_map_rows(|row| row.iter().fold(f64::MIN, f64::max));
+    /// assert_eq!(highs, vector![12.0, 22.0, 5.0]);
+    /// ```
+    pub fn par_map_rows<U>(&self, f: impl Fn(VectorView<'_, T>) -> U + Sync + Send) -> Vector<U>
+    where U: Copy + PartialEq + Send {
+        let rows = self.rows as usize;
+        if self.is_small() {
+            return (0..rows).map(|row| f(self.row_view(row))).collect();
+        }
+        (0..rows).into_par_iter().map(|row| f(self.row_view(row))).collect()
+    }
+
+    /// Compute the dot product of each row of this matrix and the given vector (such as the
+    /// scores of many instruments, given the weights of their factors), with the rows processed
+    /// in parallel (unless this matrix has less components than the
+    /// [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// The results are the same as the ones of the [`VectorView::dot`] method applied to each
+    /// row, and it panics if the number of columns is different from the dimension of the vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let factors = matrix![ 1, 0, 2 |
+    ///                      | 3, 1, 0 ];
+    /// let weights = vector![10, 100, 1];
+    /// assert_eq!(factors.par_dot_rows(&weights), vector![12, 130]);
+    /// ```
+    pub fn par_dot_rows(&self, v: &Vector<T>) -> Vector<T>
+    where T: Add<Output = T> + Mul<Output = T> + Sum<T> {
+        assert_eq!(self.cols as u64, v.dim(), "must have the same dimension");
+        self.par_map_rows(|row| row.dot(v))
+    }
+
+    /// Apply the given function to (a mutable view of) each row of this matrix, which allows
+    /// modifying them in place, with the rows processed in parallel (unless this matrix has less
+    /// components than the [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // Normalize each row, so that its components sum up to 1
+    /// let mut weights = matrix![ 1.0, 3.0 |
+    ///                          | 2.0, 2.0 ];
+    /// weights.par_for_each_row_mut(|mut row| {
+    ///     let total: f64 = row.iter().sum();
+    ///     row.map_in_place(|x| x / total);
+    /// });
+    /// assert_eq!(weights[(1, 2)], 0.75);
+    /// assert_eq!(weights[(2, 1)], 0.5);
+    /// ```
+    pub fn par_for_each_row_mut(&mut self, f: impl Fn(VectorViewMut<'_, T>) + Sync + Send) {
+        let cols = self.cols as usize;
+        if cols == 0 {
+            // There are no components to be split in rows (but each row is still processed)
+            (0..self.rows).for_each(|_| f(VectorViewMut { components: &mut [], layout: Layout::CONTIGUOUS }));
+            return;
+        }
+        let view = |components| VectorViewMut { components, layout: Layout::CONTIGUOUS };
+        if self.is_small() {
+            self.components.chunks_mut(cols).for_each(|row| f(view(row)));
+        } else {
+            self.components.par_chunks_mut(cols).for_each(|row| f(view(row)));
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+
+    fn large(rows: u32, cols: u32) -> Matrix<f64> {
+        let components: Box<[f64]> = (0..rows * cols).map(|i| ((i * 7919) % 1000) as f64 / 7.0).collect();
+        Matrix::from((components, rows, cols))
+    }
+
+    #[test]
+    fn par_dot_rows_large() {
+        let m = large(PARALLEL_THRESHOLD as u32 / 50, 100);
+        let v: Vector<f64> = (0..100).map(|i| 1.0 / (i + 1) as f64).collect();
+        let dots = m.par_dot_rows(&v);
+        assert_eq!(dots.dim(), m.rows as u64);
+        for row in [0, 1, m.rows as usize / 2, m.rows as usize - 1] {
+            assert_eq!(dots.as_slice()[row].to_bits(), m.row_view(row).dot(&v).to_bits());
+        }
+    }
+
+    #[test]
+    fn par_map_rows_small() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        assert_eq!(m.par_map_rows(|row| row.iter().sum::<i32>()), Vector::from([3, 7, 11]));
+
+        let empty: Matrix<i32> = Matrix::from(([], 2, 0));
+        assert_eq!(empty.par_map_rows(|row| row.dim()), Vector::from([0, 0]));
+    }
+
+    #[test]
+    fn par_for_each_row_mut_large() {
+        let mut m = large(PARALLEL_THRESHOLD as u32 / 10, 20);
+        let expected: Vector<f64> = m.par_map_rows(|row| row.iter().fold(0.0, f64::max));
+        m.par_for_each_row_mut(|mut row| row.fill(row.iter().fold(0.0, f64::max)));
+        assert_eq!(m.par_map_rows(|row| row[20]), expected);
+        assert_eq!(m.par_map_rows(|row| row[1]), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn par_dot_rows_different_dimensions() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 3, 2));
+        let _ = m.par_dot_rows(&Vector::from([1, 2, 3]));
+    }
+}
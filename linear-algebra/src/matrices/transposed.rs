@@ -0,0 +1,228 @@
+use super::{Matrix, MatrixLinesIterator};
+use crate::Dimension;
+use core::fmt;
+use core::iter::Flatten;
+use core::ops::Index;
+
+/// Representation of a (read-only) view of the transpose of a matrix, borrowing its components
+/// rather than copying them (see [`Matrix::transposed`]).
+///
+/// The rows of the view are the columns of the matrix (and vice versa), and its components are
+/// indexed by `(row, col)`, starting from 1, as for matrices.
+///
+/// # Memory
+/// Views are borrowing the components of a matrix (which therefore cannot be modified while
+/// viewed), and they are provided with copy semantics (as shared references are). To get an
+/// owned copy of the transpose, see [`TransposedView::to_matrix`] (or [`Matrix::transpose`]).
+#[derive(Clone, Copy)]
+pub struct TransposedView<'a, T> where T: Copy + PartialEq {
+    // The transposed matrix
+    matrix: &'a Matrix<T>,
+}
+
+impl<'a, T> Dimension for TransposedView<'a, T> where T: Copy + PartialEq {
+
+    /// Returns the number of the components of this view
+    fn dim(&self) -> u64 {
+        self.matrix.dim()
+    }
+}
+
+
+impl<'a, T> TransposedView<'a, T> where T: Copy + PartialEq {
+
+    /// The shape of this view, as its number of rows and columns (which are the number of
+    /// columns and rows of the transposed matrix).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// assert_eq!(m.transposed().shape(), (3, 2));
+    /// ```
+    pub fn shape(&self) -> (u32, u32) {
+        (self.matrix.cols, self.matrix.rows)
+    }
+
+    /// The iterator over the rows of this view (that is, the columns of the transposed matrix).
+    pub fn rows(&self) -> MatrixLinesIterator<'a, T> {
+        MatrixLinesIterator::cols(self.matrix)
+    }
+
+    /// The iterator over the columns of this view (that is, the rows of the transposed matrix).
+    pub fn cols(&self) -> MatrixLinesIterator<'a, T> {
+        MatrixLinesIterator::rows(self.matrix)
+    }
+
+    /// The iterator over the components of this view, in row-major order (that is, the
+    /// components of the transposed matrix in column-major order).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// assert_eq!(m.transposed().iter().collect::<Vec<_>>(), [1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn iter(&self) -> Flatten<MatrixLinesIterator<'a, T>> {
+        self.rows().flatten()
+    }
+
+    /// Copy the components of this view into a new (owned) matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let (rows, cols) = self.shape();
+        Matrix { components: self.iter().collect(), rows, cols }
+    }
+}
+
+
+impl<'a, T> Index<(u32, u32)> for TransposedView<'a, T> where T: Copy + PartialEq {
+    type Output = T;
+
+    /// Read the component at the given `(row, col)` index of this view (that is, the one at the
+    /// `(col, row)` index of the transposed matrix), with them starting from 1 (not 0).
+    fn index(&self, index: (u32, u32)) -> &Self::Output {
+        let (row, col) = index;
+        &self.matrix[(col, row)]
+    }
+}
+
+impl<'a, T> fmt::Debug for TransposedView<'a, T> where T: Copy + PartialEq + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, cols) = self.shape();
+        f.debug_struct("TransposedView")
+            .field("rows", &rows)
+            .field("cols", &cols)
+            .field("components", &self.iter().collect::<alloc::vec::Vec<_>>())
+            .finish()
+    }
+}
+
+impl<'a, 'b, T> PartialEq<TransposedView<'b, T>> for TransposedView<'a, T> where T: Copy + PartialEq {
+    fn eq(&self, other: &TransposedView<'b, T>) -> bool {
+        self.matrix == other.matrix
+    }
+}
+
+impl<'a, T> PartialEq<Matrix<T>> for TransposedView<'a, T> where T: Copy + PartialEq {
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        self.shape() == (other.rows, other.cols) && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> PartialEq<TransposedView<'a, T>> for Matrix<T> where T: Copy + PartialEq {
+    fn eq(&self, other: &TransposedView<'a, T>) -> bool {
+        other == self
+    }
+}
+
+impl<'a, T> From<TransposedView<'a, T>> for Matrix<T> where T: Copy + PartialEq {
+    fn from(view: TransposedView<'a, T>) -> Self {
+        view.to_matrix()
+    }
+}
+
+
+impl<T> Matrix<T> where T: Copy + PartialEq {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Create the transpose of this matrix, as a new (owned) matrix.
+    ///
+    /// Given a matrix $\textbf{A}$ of dimension $m \times n$, its transpose is the matrix
+    /// $\textbf{A}^{T}$ of dimension $n \times m$ whose rows are the columns of $\textbf{A}$:
+    ///
+    /// $$
+    /// (\textbf{A}^{T})_{ij} \quad \equiv \quad a_{ji}
+    /// $$
+    ///
+    /// To avoid copying the components, see [`Matrix::transposed`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let m = matrix![ 1, 2, 3 |
+    ///                | 4, 5, 6 ];
+    /// let expected = matrix![ 1, 4 |
+    ///                       | 2, 5 |
+    ///                       | 3, 6 ];
+    /// assert_eq!(m.transpose(), expected);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        self.transposed().to_matrix()
+    }
+
+    /// Create a (read-only) view of the transpose of this matrix (see [`Matrix::transpose`]),
+    /// borrowing its components rather than copying them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// // The daily returns of 3 assets (one per row), over 2 days
+    /// let returns = matrix![ 1, 2 |
+    ///                      | 3, 4 |
+    ///                      | 5, 6 ];
+    /// let by_day = returns.transposed();
+    /// assert_eq!(by_day[(2, 3)], 6);
+    /// assert_eq!(by_day.rows().next().unwrap(), vector![1, 3, 5]);
+    /// assert_eq!(by_day, returns.transpose());
+    /// ```
+    pub fn transposed(&self) -> TransposedView<'_, T> {
+        TransposedView { matrix: self }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Vector;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn transpose() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let t = m.transpose();
+        assert_eq!((t.rows, t.cols), (3, 2));
+        assert_eq!(t, Matrix::from(([1, 4, 2, 5, 3, 6], 3, 2)));
+        assert_eq!(t.transpose(), m);
+
+        let empty: Matrix<i32> = Matrix::from(([], 0, 4));
+        assert_eq!(empty.transpose(), Matrix::from(([], 4, 0)));
+    }
+
+    #[test]
+    fn transposed_view() {
+        let m = Matrix::from(([1, 2, 3, 4, 5, 6], 2, 3));
+        let view = m.transposed();
+        assert_eq!(view.shape(), (3, 2));
+        assert_eq!(view.dim(), 6);
+        for row in 1..=3 {
+            for col in 1..=2 {
+                assert_eq!(view[(row, col)], m[(col, row)]);
+            }
+        }
+        let cols: Vec<Vector<i32>> = view.cols().map(|col| col.to_vector()).collect();
+        assert_eq!(cols, [Vector::from([1, 2, 3]), Vector::from([4, 5, 6])]);
+        assert_eq!(view.iter().rev().collect::<Vec<_>>(), [6, 3, 5, 2, 4, 1]);
+    }
+
+    #[test]
+    fn transposed_view_eq() {
+        let m = Matrix::from(([1, 2, 3, 4], 2, 2));
+        assert_eq!(m.transposed(), Matrix::from(([1, 3, 2, 4], 2, 2)));
+        assert_ne!(m.transposed(), m);
+        assert_eq!(m.transposed(), m.transposed());
+        assert_eq!(Matrix::from(m.transposed()), m.transpose());
+
+        // A row and a column have the same components, but different shapes
+        let row = Matrix::from(([1, 2, 3], 1, 3));
+        assert_ne!(row.transposed(), row);
+        assert_eq!(row.transposed(), Matrix::from(([1, 2, 3], 3, 1)));
+    }
+}
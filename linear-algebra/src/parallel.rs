@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+use rayon::prelude::*;
+
+/// The number of components below which the parallel operations (such as
+/// [`Vector::par_dot`](crate::Vector::par_dot)) fall back to serial code, as the overhead of
+/// distributing the work across threads would exceed its benefits.
+pub const PARALLEL_THRESHOLD: usize = 32_768;
+
+// The number of components reduced serially into each partial result. It's fixed (rather than
+// depending on the number of threads), so that the partial results are always the same.
+pub(crate) const CHUNK_SIZE: usize = 4_096;
+
+
+// Apply the given function to the consecutive chunks of the given number of components (in
+// parallel, if above the threshold), returning the partial results in the order of the chunks.
+//
+// As the chunks don't depend on how the work gets scheduled, combining the partial results
+// serially makes the reductions deterministic (they match bit for bit, however many threads).
+pub(crate) fn chunked<U>(len: usize, f: impl Fn(Range<usize>) -> U + Sync + Send) -> Vec<U>
+where U: Send {
+    let chunk = |i: usize| f(i * CHUNK_SIZE..((i + 1) * CHUNK_SIZE).min(len));
+    let count = len.div_ceil(CHUNK_SIZE);
+    if len < PARALLEL_THRESHOLD {
+        (0..count).map(chunk).collect()
+    } else {
+        (0..count).into_par_iter().map(chunk).collect()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chunked_ranges() {
+        let len = PARALLEL_THRESHOLD + 10;
+        let ranges = chunked(len, |range| range);
+        assert_eq!(ranges.len(), PARALLEL_THRESHOLD / CHUNK_SIZE + 1);
+        assert_eq!(ranges[0], 0..CHUNK_SIZE);
+        assert_eq!(ranges.last().unwrap().clone(), PARALLEL_THRESHOLD..len);
+        assert!(ranges.windows(2).all(|w| w[0].end == w[1].start));
+    }
+
+    #[test]
+    fn chunked_empty() {
+        assert!(chunked(0, |range| range).is_empty());
+    }
+}
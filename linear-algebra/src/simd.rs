@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::mem::MaybeUninit;
+use core::ops::{Add, Mul};
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// A trait for the floating point types (`f32` and `f64`) whose operations on slices (and
+/// vectors) are accelerated by SIMD instructions.
+///
+/// The instruction set (AVX-512, AVX2 or SSE2 on x86-64 CPUs) is detected at runtime, so that
+/// the same binary runs on any CPU, taking advantage of the widest registers available. On
+/// the other architectures, the operations rely on the compiler to vectorize them.
+///
+/// # Accuracy
+/// The generic dot product (see [`Vector::dot`](crate::Vector::dot)) sums the products
+/// sequentially, and its rounding error grows as $O(\varepsilon n)$ with the number $n$ of the
+/// components. Instead, the SIMD dot product sums blocks of products in the lanes of several
+/// registers, and then sums the blocks pairwise, so that its rounding error grows as
+/// $O(\varepsilon \log n)$, as for pairwise summation.
+///
+/// As the order of the summation depends on the instruction set, the results may differ in the
+/// last digits between CPUs (but they always match for repeated computations on the same CPU).
+/// The other operations are computed component by component, therefore their results are
+/// exactly the same as the ones of the generic operations.
+///
+/// ```rust
+/// use beaumont_linear_algebra::*;
+///
+/// // The sequential sum of a million of 0.1 in single precision is off by almost 1%
+/// let x = vec![0.1f32; 1_000_000];
+/// let ones = vec![1.0f32; 1_000_000];
+/// let sequential: f32 = x.iter().zip(&ones).map(|(a, b)| a * b).sum();
+/// assert!((sequential - 100_000.0).abs() > 500.0);
+/// assert!((f32::simd_dot(&x, &ones) - 100_000.0).abs() < 0.1);
+/// ```
+pub trait SimdFloat: Copy + PartialEq + Default + Add<Output = Self> + Mul<Output = Self> + Sum<Self> + sealed::Sealed {
+
+    /// Compute the dot product of the given slices, and it panics if they have different
+    /// lengths.
+    fn simd_dot(lhs: &[Self], rhs: &[Self]) -> Self;
+
+    /// Add the correspondent components of the given slices, and it panics if they have
+    /// different lengths.
+    fn simd_add(lhs: &[Self], rhs: &[Self]) -> Vec<Self>;
+
+    /// Multiply the given values by the scalar.
+    fn simd_scale(values: &[Self], scalar: Self) -> Vec<Self>;
+
+    /// Add the products of the given scalar and the components of `x` to the components of `y`
+    /// (in place), as the well-known `axpy` routine of the BLAS libraries, and it panics if
+    /// they have different lengths.
+    fn simd_axpy(alpha: Self, x: &[Self], y: &mut [Self]);
+}
+
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+
+// Invoke the given kernel for the widest instruction set supported by the CPU
+#[cfg(target_arch = "x86_64")]
+macro_rules! dispatch {
+    ($sse2:ident, $avx2:ident, $avx512:ident, $kernel:ident($($arg:expr),*)) => {
+        if std::is_x86_feature_detected!("avx512f") {
+            // SAFETY: the instruction set is supported by the CPU
+            unsafe { kernels::$avx512::$kernel($($arg),*) }
+        } else if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: the instruction set is supported by the CPU
+            unsafe { kernels::$avx2::$kernel($($arg),*) }
+        } else {
+            // SAFETY: the instruction set is supported by all the x86-64 CPUs
+            unsafe { kernels::$sse2::$kernel($($arg),*) }
+        }
+    };
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+macro_rules! dispatch {
+    ($sse2:ident, $avx2:ident, $avx512:ident, $kernel:ident($($arg:expr),*)) => {
+        kernels::portable::$kernel($($arg),*)
+    };
+}
+
+macro_rules! simd_float {
+    ($float:ty, $sse2:ident, $avx2:ident, $avx512:ident) => {
+        impl SimdFloat for $float {
+            fn simd_dot(lhs: &[Self], rhs: &[Self]) -> Self {
+                assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+                dispatch!($sse2, $avx2, $avx512, dot(lhs, rhs))
+            }
+
+            fn simd_add(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+                assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+                let add = |out: &mut [MaybeUninit<Self>]| dispatch!($sse2, $avx2, $avx512, add(lhs, rhs, out));
+                // SAFETY: the kernel writes as many components as the ones of the slices
+                unsafe { written(lhs.len(), add) }
+            }
+
+            fn simd_scale(values: &[Self], scalar: Self) -> Vec<Self> {
+                let scale = |out: &mut [MaybeUninit<Self>]| dispatch!($sse2, $avx2, $avx512, scale(values, scalar, out));
+                // SAFETY: the kernel writes as many components as the ones of the values
+                unsafe { written(values.len(), scale) }
+            }
+
+            fn simd_axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+                assert_eq!(x.len(), y.len(), "must have the same length");
+                dispatch!($sse2, $avx2, $avx512, axpy(alpha, x, y))
+            }
+        }
+    };
+}
+
+simd_float!(f32, sse2_f32, avx2_f32, avx512_f32);
+simd_float!(f64, sse2_f64, avx2_f64, avx512_f64);
+
+
+// Collect the components written by the given function into a new vector of the given length,
+// without initializing them beforehand (as zeroing them would take as long as writing them).
+//
+// SAFETY: the function must write all the components
+pub(crate) unsafe fn written<T>(len: usize, write: impl FnOnce(&mut [MaybeUninit<T>])) -> Vec<T> {
+    let mut values = Vec::with_capacity(len);
+    write(&mut values.spare_capacity_mut()[..len]);
+    // SAFETY: the first `len` components have been written (as required to the caller)
+    unsafe { values.set_len(len) };
+    values
+}
+
+
+mod kernels;
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn simd_dot_f64() {
+        let x: Vec<f64> = (0..1_000).map(|i| i as f64).collect();
+        assert_eq!(f64::simd_dot(&x, &x), 332_833_500.0);
+        assert_eq!(f64::simd_dot(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn simd_axpy_f32() {
+        let x = vec![1.5f32; 37];
+        let mut y = vec![1.0f32; 37];
+        f32::simd_axpy(2.0, &x, &mut y);
+        assert!(y.iter().all(|v| *v == 4.0));
+    }
+
+    #[test]
+    fn simd_add_and_scale() {
+        assert_eq!(f64::simd_add(&[1.0, 2.0, 3.0], &[0.5, 0.25, 0.125]), [1.5, 2.25, 3.125]);
+        assert_eq!(f32::simd_scale(&[1.0, 2.0, 3.0], -2.0), [-2.0, -4.0, -6.0]);
+        assert!(f32::simd_scale(&[], 2.0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn simd_add_different_lengths() {
+        let _ = f64::simd_add(&[1.0, 2.0, 3.0], &[1.0, 2.0]);
+    }
+}
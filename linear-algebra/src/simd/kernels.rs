@@ -0,0 +1,317 @@
+// The kernels of the floating point operations, for each of the instruction sets.
+//
+// The dot product is computed by pairwise summation of blocks of products, with each block
+// accumulated in several SIMD registers at once (as their lanes are independent sums).
+
+// The maximum number of products accumulated sequentially (in each lane) before being summed
+// pairwise with the other blocks
+const BLOCK: usize = 256;
+
+
+// Generate the kernels for the given instruction set (as the `target_feature` to be enabled),
+// floating point type, SIMD register type (with its number of lanes) and intrinsics.
+#[cfg(target_arch = "x86_64")]
+macro_rules! x86_64_kernels {
+    ($module:ident, $feature:literal, $float:ty, $register:ty, $lanes:literal,
+     $setzero:ident, $set1:ident, $loadu:ident, $storeu:ident, $add:ident, $mul:ident) => {
+
+        pub(crate) mod $module {
+            use super::BLOCK;
+            use core::arch::x86_64::*;
+            use core::mem::MaybeUninit;
+
+            const LANES: usize = $lanes;
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            fn load(chunk: &[$float; LANES]) -> $register {
+                // SAFETY: the chunk has exactly as many components as the lanes of the register
+                unsafe { $loadu(chunk.as_ptr()) }
+            }
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            fn store(chunk: &mut [$float; LANES], register: $register) {
+                // SAFETY: the chunk has exactly as many components as the lanes of the register
+                unsafe { $storeu(chunk.as_mut_ptr(), register) }
+            }
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            fn write(chunk: &mut [MaybeUninit<$float>; LANES], register: $register) {
+                // SAFETY: the chunk has exactly as many components as the lanes of the register
+                unsafe { $storeu(chunk.as_mut_ptr().cast(), register) }
+            }
+
+            #[target_feature(enable = $feature)]
+            fn block_dot(lhs: &[$float], rhs: &[$float]) -> $float {
+                let (lhs_chunks, lhs_tail) = lhs.as_chunks::<LANES>();
+                let (rhs_chunks, rhs_tail) = rhs.as_chunks::<LANES>();
+
+                // Using 4 registers, so that the additions don't wait for each other
+                let mut acc = [$setzero(); 4];
+                let (lhs_quads, lhs_rest) = lhs_chunks.as_chunks::<4>();
+                let (rhs_quads, rhs_rest) = rhs_chunks.as_chunks::<4>();
+                for (x, y) in lhs_quads.iter().zip(rhs_quads) {
+                    for k in 0..4 {
+                        acc[k] = $add(acc[k], $mul(load(&x[k]), load(&y[k])));
+                    }
+                }
+                for (x, y) in lhs_rest.iter().zip(rhs_rest) {
+                    acc[0] = $add(acc[0], $mul(load(x), load(y)));
+                }
+
+                let mut lanes = [0.0; LANES];
+                store(&mut lanes, $add($add(acc[0], acc[1]), $add(acc[2], acc[3])));
+                let mut sum: $float = lanes.iter().sum();
+                for (x, y) in lhs_tail.iter().zip(rhs_tail) {
+                    sum += x * y;
+                }
+                sum
+            }
+
+            // It requires the slices to have the same length
+            #[target_feature(enable = $feature)]
+            pub(crate) fn dot(lhs: &[$float], rhs: &[$float]) -> $float {
+                if lhs.len() <= BLOCK {
+                    return block_dot(lhs, rhs);
+                }
+                let (lhs_first, lhs_second) = lhs.split_at(lhs.len() / 2);
+                let (rhs_first, rhs_second) = rhs.split_at(rhs.len() / 2);
+                dot(lhs_first, rhs_first) + dot(lhs_second, rhs_second)
+            }
+
+            // It requires the slices to have the same length
+            #[target_feature(enable = $feature)]
+            pub(crate) fn add(lhs: &[$float], rhs: &[$float], out: &mut [MaybeUninit<$float>]) {
+                let (lhs_chunks, lhs_tail) = lhs.as_chunks::<LANES>();
+                let (rhs_chunks, rhs_tail) = rhs.as_chunks::<LANES>();
+                let (out_chunks, out_tail) = out.as_chunks_mut::<LANES>();
+                for ((x, y), z) in lhs_chunks.iter().zip(rhs_chunks).zip(out_chunks) {
+                    write(z, $add(load(x), load(y)));
+                }
+                for ((x, y), z) in lhs_tail.iter().zip(rhs_tail).zip(out_tail) {
+                    z.write(x + y);
+                }
+            }
+
+            // It requires the slices to have the same length
+            #[target_feature(enable = $feature)]
+            pub(crate) fn scale(values: &[$float], scalar: $float, out: &mut [MaybeUninit<$float>]) {
+                let (chunks, tail) = values.as_chunks::<LANES>();
+                let (out_chunks, out_tail) = out.as_chunks_mut::<LANES>();
+                let factor = $set1(scalar);
+                for (x, z) in chunks.iter().zip(out_chunks) {
+                    write(z, $mul(load(x), factor));
+                }
+                for (x, z) in tail.iter().zip(out_tail) {
+                    z.write(x * scalar);
+                }
+            }
+
+            // It requires the slices to have the same length
+            #[target_feature(enable = $feature)]
+            pub(crate) fn axpy(alpha: $float, x: &[$float], y: &mut [$float]) {
+                let (x_chunks, x_tail) = x.as_chunks::<LANES>();
+                let (y_chunks, y_tail) = y.as_chunks_mut::<LANES>();
+                let factor = $set1(alpha);
+                for (u, v) in x_chunks.iter().zip(y_chunks) {
+                    let sum = $add($mul(factor, load(u)), load(v));
+                    store(v, sum);
+                }
+                for (u, v) in x_tail.iter().zip(y_tail) {
+                    *v += alpha * u;
+                }
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(sse2_f32, "sse2", f32, __m128, 4,
+    _mm_setzero_ps, _mm_set1_ps, _mm_loadu_ps, _mm_storeu_ps, _mm_add_ps, _mm_mul_ps);
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(sse2_f64, "sse2", f64, __m128d, 2,
+    _mm_setzero_pd, _mm_set1_pd, _mm_loadu_pd, _mm_storeu_pd, _mm_add_pd, _mm_mul_pd);
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(avx2_f32, "avx2", f32, __m256, 8,
+    _mm256_setzero_ps, _mm256_set1_ps, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_add_ps, _mm256_mul_ps);
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(avx2_f64, "avx2", f64, __m256d, 4,
+    _mm256_setzero_pd, _mm256_set1_pd, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_add_pd, _mm256_mul_pd);
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(avx512_f32, "avx512f", f32, __m512, 16,
+    _mm512_setzero_ps, _mm512_set1_ps, _mm512_loadu_ps, _mm512_storeu_ps, _mm512_add_ps, _mm512_mul_ps);
+#[cfg(target_arch = "x86_64")]
+x86_64_kernels!(avx512_f64, "avx512f", f64, __m512d, 8,
+    _mm512_setzero_pd, _mm512_set1_pd, _mm512_loadu_pd, _mm512_storeu_pd, _mm512_add_pd, _mm512_mul_pd);
+
+
+// The kernels for the other architectures, which rely on the compiler to vectorize the
+// independent accumulators (as it does with the instruction sets enabled at compile time)
+#[cfg(any(test, not(target_arch = "x86_64")))]
+pub(crate) mod portable {
+    use super::BLOCK;
+    use crate::SimdFloat;
+    use core::mem::MaybeUninit;
+
+    const LANES: usize = 8;
+
+    fn block_dot<T>(lhs: &[T], rhs: &[T]) -> T where T: SimdFloat {
+        let (lhs_chunks, lhs_tail) = lhs.as_chunks::<LANES>();
+        let (rhs_chunks, rhs_tail) = rhs.as_chunks::<LANES>();
+        let mut acc = [T::default(); LANES];
+        for (x, y) in lhs_chunks.iter().zip(rhs_chunks) {
+            for k in 0..LANES {
+                acc[k] = acc[k] + x[k] * y[k];
+            }
+        }
+        let sum: T = acc.into_iter().sum();
+        lhs_tail.iter().zip(rhs_tail).fold(sum, |sum, (x, y)| sum + *x * *y)
+    }
+
+    // It requires the slices to have the same length
+    pub(crate) fn dot<T>(lhs: &[T], rhs: &[T]) -> T where T: SimdFloat {
+        if lhs.len() <= BLOCK {
+            return block_dot(lhs, rhs);
+        }
+        let (lhs_first, lhs_second) = lhs.split_at(lhs.len() / 2);
+        let (rhs_first, rhs_second) = rhs.split_at(rhs.len() / 2);
+        dot(lhs_first, rhs_first) + dot(lhs_second, rhs_second)
+    }
+
+    // It requires the slices to have the same length
+    pub(crate) fn add<T>(lhs: &[T], rhs: &[T], out: &mut [MaybeUninit<T>]) where T: SimdFloat {
+        for ((x, y), z) in lhs.iter().zip(rhs).zip(out) {
+            z.write(*x + *y);
+        }
+    }
+
+    // It requires the slices to have the same length
+    pub(crate) fn scale<T>(values: &[T], scalar: T, out: &mut [MaybeUninit<T>]) where T: SimdFloat {
+        for (x, z) in values.iter().zip(out) {
+            z.write(*x * scalar);
+        }
+    }
+
+    // It requires the slices to have the same length
+    pub(crate) fn axpy<T>(alpha: T, x: &[T], y: &mut [T]) where T: SimdFloat {
+        for (u, v) in x.iter().zip(y) {
+            *v = alpha * *u + *v;
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::simd::written;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::mem::MaybeUninit;
+
+    type Dot<T> = fn(&[T], &[T]) -> T;
+    type Add<T> = fn(&[T], &[T], &mut [MaybeUninit<T>]);
+    type Scale<T> = fn(&[T], T, &mut [MaybeUninit<T>]);
+    type Axpy<T> = fn(T, &[T], &mut [T]);
+
+    // The kernels supported by the CPU running the tests (as the portable ones always are)
+    macro_rules! supported {
+        ($float:ty, $sse2:ident, $avx2:ident, $avx512:ident) => {{
+            let mut kernels: Vec<(Dot<$float>, Add<$float>, Scale<$float>, Axpy<$float>)> = vec![(
+                super::portable::dot, super::portable::add, super::portable::scale, super::portable::axpy,
+            )];
+            #[cfg(target_arch = "x86_64")]
+            {
+                use super::{$sse2, $avx2, $avx512};
+                // SAFETY: the instruction set is supported by all the x86-64 CPUs
+                kernels.push((
+                    |x, y| unsafe { $sse2::dot(x, y) },
+                    |x, y, z| unsafe { $sse2::add(x, y, z) },
+                    |x, a, z| unsafe { $sse2::scale(x, a, z) },
+                    |a, x, y| unsafe { $sse2::axpy(a, x, y) },
+                ));
+                if std::is_x86_feature_detected!("avx2") {
+                    // SAFETY: the instruction set is supported by the CPU
+                    kernels.push((
+                        |x, y| unsafe { $avx2::dot(x, y) },
+                        |x, y, z| unsafe { $avx2::add(x, y, z) },
+                        |x, a, z| unsafe { $avx2::scale(x, a, z) },
+                        |a, x, y| unsafe { $avx2::axpy(a, x, y) },
+                    ));
+                }
+                if std::is_x86_feature_detected!("avx512f") {
+                    // SAFETY: the instruction set is supported by the CPU
+                    kernels.push((
+                        |x, y| unsafe { $avx512::dot(x, y) },
+                        |x, y, z| unsafe { $avx512::add(x, y, z) },
+                        |x, a, z| unsafe { $avx512::scale(x, a, z) },
+                        |a, x, y| unsafe { $avx512::axpy(a, x, y) },
+                    ));
+                }
+            }
+            kernels
+        }};
+    }
+
+    // Small integers (which are exactly represented, as well as their products and sums)
+    fn integers(len: usize, seed: usize) -> Vec<f64> {
+        (0..len).map(|i| ((i * 31 + seed * 17) % 23) as f64 - 11.0).collect()
+    }
+
+    #[test]
+    fn f64_kernels() {
+        for (dot, add, scale, axpy) in supported!(f64, sse2_f64, avx2_f64, avx512_f64) {
+            for len in (0..=70).chain([255, 256, 257, 1_000, 10_001]) {
+                let x = integers(len, 1);
+                let y = integers(len, 2);
+                let expected: f64 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+                assert_eq!(dot(&x, &y), expected, "dot of length {len}");
+
+                // SAFETY: the kernels write all the components
+                let z = unsafe { written(len, |z| add(&x, &y, z)) };
+                assert!(z.iter().enumerate().all(|(i, v)| *v == x[i] + y[i]), "add of length {len}");
+                let mut z = unsafe { written(len, |z| scale(&x, -3.0, z)) };
+                assert!(z.iter().enumerate().all(|(i, v)| *v == x[i] * -3.0), "scale of length {len}");
+                axpy(2.0, &x, &mut z);
+                assert!(z.iter().enumerate().all(|(i, v)| *v == -x[i]), "axpy of length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn f32_kernels() {
+        for (dot, add, scale, axpy) in supported!(f32, sse2_f32, avx2_f32, avx512_f32) {
+            for len in (0..=70).chain([255, 256, 257, 1_000, 10_001]) {
+                let x: Vec<f32> = integers(len, 3).into_iter().map(|v| v as f32).collect();
+                let y: Vec<f32> = integers(len, 4).into_iter().map(|v| v as f32).collect();
+                let expected: f32 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+                assert_eq!(dot(&x, &y), expected, "dot of length {len}");
+
+                // SAFETY: the kernels write all the components
+                let z = unsafe { written(len, |z| add(&x, &y, z)) };
+                assert!(z.iter().enumerate().all(|(i, v)| *v == x[i] + y[i]), "add of length {len}");
+                let mut z = unsafe { written(len, |z| scale(&x, 0.5, z)) };
+                assert!(z.iter().enumerate().all(|(i, v)| *v == x[i] * 0.5), "scale of length {len}");
+                axpy(-0.5, &x, &mut z);
+                assert!(z.iter().all(|v| *v == 0.0), "axpy of length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn f32_dot_accuracy() {
+        // The sequential sum of a million of 0.1 in single precision is off by almost 1%
+        let x = vec![0.1f32; 1_000_000];
+        let y = vec![1.0f32; 1_000_000];
+        let sequential: f32 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+        assert!((sequential - 100_000.0).abs() > 500.0);
+
+        let exact = 0.1f32 as f64 * 1e6;
+        for (dot, _, _, _) in supported!(f32, sse2_f32, avx2_f32, avx512_f32) {
+            let relative_error = (dot(&x, &y) as f64 - exact).abs() / exact;
+            assert!(relative_error < 1e-6, "relative error {relative_error}");
+        }
+    }
+}
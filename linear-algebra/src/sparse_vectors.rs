@@ -0,0 +1,187 @@
+use super::{Dimension, LinearAlgebraError};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// Representation of a mathematical vector most of whose components are zero
+///
+/// Only the non-zero components are stored, as pairs of their index (starting from 1, as when
+/// indexing a [`Vector`](crate::Vector)) and value sorted by index, so that operations take time
+/// proportional to the number of non-zero components (rather than to the dimension). The zero of
+/// the scalar type is taken to be its default value (such as `0`, `0.0` or `0d`).
+///
+/// # Usage
+/// To create a sparse vector, you can:
+/// - either invoke the [`SparseVector::new`] factory function,
+/// - or convert it from a (dense) [`Vector`](crate::Vector).
+///
+/// ```rust
+/// use beaumont_macros::*;
+/// use beaumont_linear_algebra::*;
+///
+/// // Signals over a universe of 5000 stocks
+/// let momentum = SparseVector::new(5000, [(42, 0.5), (7, -1.25)]);
+/// let value = SparseVector::new(5000, [(42, 2.0), (4999, 1.0)]);
+/// assert_eq!(momentum.dim(), 5000);
+/// assert_eq!(momentum.nnz(), 2);
+/// assert_eq!(momentum.get(7), -1.25);
+/// assert_eq!(momentum.get(8), 0.0);
+///
+/// // Operations against sparse vectors
+/// assert_eq!(momentum.dot(&value), 1.0);
+/// assert_eq!((&momentum + &value).nnz(), 3);
+///
+/// // Operations against dense vectors
+/// let weights = SparseVector::from(vector![0.0, 0.5, 0.0, 0.25]);
+/// let prices = vector![1.5, 2.0, -1.0, 4.0];
+/// assert_eq!(weights.dot_dense(&prices), 2.0);
+/// assert_eq!(&prices + &weights, vector![1.5, 2.5, -1.0, 4.25]);
+/// ```
+///
+/// # Memory
+/// As for [`Vector`](crate::Vector), sparse vectors are having a fixed dimension, their non-zero
+/// components contiguously heap-allocated, and requiring move semantics.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseVector<T> where T: Copy + PartialEq {
+    // The logical dimension (including the zero components)
+    dim: u64,
+    // The non-zero components, sorted by their (distinct) indices
+    entries: Box<[(u64, T)]>,
+}
+
+impl<T> Dimension for SparseVector<T> where T: Copy + PartialEq {
+
+    /// Returns the logical dimension of this sparse vector (including its zero components)
+    fn dim(&self) -> u64 {
+        self.dim
+    }
+}
+
+
+impl<T> SparseVector<T> where T: Copy + PartialEq + Default {
+
+    /// Same as the [`SparseVector::try_new`] function, but it panics instead of resulting
+    /// [`LinearAlgebraError`]
+    pub fn new(dim: u64, entries: impl IntoIterator<Item = (u64, T)>) -> Self {
+        Self::try_new(dim, entries)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Attempt to create a new sparse vector of the given dimension, from the given pairs of
+    /// indices (starting from 1) and values, in any order.
+    ///
+    /// Zero values are discarded, and so are all but the last value given for the same index. It
+    /// returns [`LinearAlgebraError::IndexOutOfBounds`] if any index is zero or exceeds the
+    /// dimension.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_linear_algebra::*;
+    /// let v = SparseVector::try_new(10, [(3, 1.5), (1, 0.0), (3, 2.5)]).unwrap();
+    /// assert_eq!(v.iter().collect::<Vec<_>>(), [(3, 2.5)]);
+    ///
+    /// let err = SparseVector::try_new(10, [(11, 1.5)]).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::IndexOutOfBounds { index: 11, dim: 10 });
+    /// ```
+    pub fn try_new(dim: u64, entries: impl IntoIterator<Item = (u64, T)>) -> Result<Self, LinearAlgebraError> {
+        let mut entries: Vec<(u64, T)> = entries.into_iter().collect();
+        if let Some(&(index, _)) = entries.iter().find(|(index, _)| *index == 0 || *index > dim) {
+            return Err(LinearAlgebraError::IndexOutOfBounds { index, dim });
+        }
+
+        // A stable sort keeps the values of the same index in the given order, so that reversing
+        // the entries makes the deduplication keep the last value of each index
+        entries.sort_by_key(|(index, _)| *index);
+        entries.reverse();
+        entries.dedup_by_key(|(index, _)| *index);
+        entries.reverse();
+        entries.retain(|(_, value)| *value != T::default());
+
+        Ok(Self { dim, entries: entries.into_boxed_slice() })
+    }
+
+    /// Create a new sparse vector of the given dimension, with all its components being zero
+    pub fn zeros(dim: u64) -> Self {
+        Self { dim, entries: Box::new([]) }
+    }
+
+    /// Read the component at the given index (starting from 1), being zero unless it's stored,
+    /// and it panics if the index is zero or exceeds the dimension.
+    pub fn get(&self, index: u64) -> T {
+        assert!(index >= 1 && index <= self.dim, "{}", LinearAlgebraError::IndexOutOfBounds { index, dim: self.dim });
+        self.entries
+            .binary_search_by_key(&index, |(i, _)| *i)
+            .map_or_else(|_| T::default(), |position| self.entries[position].1)
+    }
+}
+
+
+impl<T> SparseVector<T> where T: Copy + PartialEq {
+
+    /// Returns the number of the non-zero components of this sparse vector
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The iterator over the non-zero components of this sparse vector, as pairs of their index
+    /// (starting from 1) and value, sorted by index.
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, (u64, T)>> {
+        self.entries.iter().copied()
+    }
+
+    // Check this sparse vector to have the same dimension as the other vector
+    pub(crate) fn check_dim(&self, other: &impl Dimension) -> Result<(), LinearAlgebraError> {
+        if self.dim != other.dim() {
+            return Err(LinearAlgebraError::DimensionMismatch { expected: self.dim, found: other.dim() });
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_sorts_and_discards() {
+        let v = SparseVector::new(8, [(5, 1), (2, 0), (1, 3), (5, 7), (8, -1)]);
+        assert_eq!(v.entries[..], [(1, 3), (5, 7), (8, -1)]);
+        assert_eq!(v.dim(), 8);
+        assert_eq!(v.nnz(), 3);
+    }
+
+    #[test]
+    fn try_new_index_out_of_bounds() {
+        let err = SparseVector::try_new(8, [(0, 1)]).unwrap_err();
+        assert_eq!(err, LinearAlgebraError::IndexOutOfBounds { index: 0, dim: 8 });
+    }
+
+    #[test]
+    fn zeros() {
+        let v: SparseVector<f64> = SparseVector::zeros(5000);
+        assert_eq!(v.dim(), 5000);
+        assert_eq!(v.nnz(), 0);
+        assert_eq!(v.get(4999), 0.0);
+    }
+
+    #[test]
+    fn get() {
+        let v = SparseVector::new(10, [(3, 1.5), (7, -2.5)]);
+        assert_eq!(v.get(3), 1.5);
+        assert_eq!(v.get(7), -2.5);
+        assert_eq!(v.get(10), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 11 out of bounds (dimension 10)")]
+    fn get_out_of_bounds() {
+        let v = SparseVector::new(10, [(3, 1.5)]);
+        let _ = v.get(11);
+    }
+}
+
+
+mod from;
+mod ops;
@@ -0,0 +1,69 @@
+use super::SparseVector;
+use crate::{Dimension, Vector};
+use alloc::vec;
+
+
+/// Create a new sparse vector from the non-zero components of a (dense) vector
+impl<T> From<&Vector<T>> for SparseVector<T> where T: Copy + PartialEq + Default {
+    fn from(v: &Vector<T>) -> Self {
+        Self {
+            dim: v.dim(),
+            entries: v.iter()
+                .zip(1..)
+                .filter(|(value, _)| *value != T::default())
+                .map(|(value, index)| (index, value))
+                .collect(),
+        }
+    }
+}
+
+impl<T> From<Vector<T>> for SparseVector<T> where T: Copy + PartialEq + Default {
+    fn from(v: Vector<T>) -> Self {
+        Self::from(&v)
+    }
+}
+
+
+/// Create a new (dense) vector from a sparse vector, by filling in its zero components
+impl<T> From<&SparseVector<T>> for Vector<T> where T: Copy + PartialEq + Default {
+    fn from(v: &SparseVector<T>) -> Self {
+        let mut components = vec![T::default(); v.dim as usize];
+        for (index, value) in v.iter() {
+            components[(index - 1) as usize] = value;
+        }
+        Vector::from(components.into_boxed_slice())
+    }
+}
+
+impl<T> From<SparseVector<T>> for Vector<T> where T: Copy + PartialEq + Default {
+    fn from(v: SparseVector<T>) -> Self {
+        Self::from(&v)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_dense() {
+        let v = SparseVector::from(Vector::from([0.0, 1.5, 0.0, -0.0, 2.5]));
+        assert_eq!(v.dim(), 5);
+        assert_eq!(v.entries[..], [(2, 1.5), (5, 2.5)]);
+    }
+
+    #[test]
+    fn to_dense() {
+        let v = SparseVector::new(5, [(2, 1), (5, -3)]);
+        assert_eq!(Vector::from(&v), Vector::from([0, 1, 0, 0, -3]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let dense = Vector::from([0, 0, 7, 0, 1, 0]);
+        let sparse = SparseVector::from(&dense);
+        assert_eq!(Vector::from(sparse), dense);
+    }
+}
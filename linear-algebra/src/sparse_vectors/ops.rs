@@ -0,0 +1,3 @@
+mod dot;
+mod add;
+mod scale;
@@ -0,0 +1,196 @@
+use super::super::SparseVector;
+use crate::{Dimension, LinearAlgebraError, Vector};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign};
+
+impl<T> SparseVector<T> where T: Copy + PartialEq + Default + Add<Output = T> {
+
+    /// Add this sparse vector to the other sparse vector (see [`Vector::add`]), which results in
+    /// a sparse vector whose non-zero components are the union of theirs (but those cancelling
+    /// each other out).
+    ///
+    /// This method is a thin wrapper around the `+` operator applied to sparse vector references,
+    /// and therefore it panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_linear_algebra::*;
+    /// let v = SparseVector::new(10, [(1, 2), (5, 3)]);
+    /// let w = SparseVector::new(10, [(5, -3), (9, 4)]);
+    /// assert_eq!(v.add(&w), SparseVector::new(10, [(1, 2), (9, 4)]));
+    /// ```
+    pub fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    /// Same as the [`SparseVector::add`] method, but it returns an error (instead of panicking)
+    /// if the vectors have different dimensions.
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> {
+        self.check_dim(rhs)?;
+        Ok(self + rhs)
+    }
+
+    /// Add this sparse vector to the other (dense) vector, which results in a dense vector.
+    ///
+    /// This method is a thin wrapper around the `+` operator applied to a sparse vector reference
+    /// and a vector reference, and therefore it panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = SparseVector::new(3, [(2, 10)]);
+    /// assert_eq!(v.add_dense(&vector![1, 2, 3]), vector![1, 12, 3]);
+    /// ```
+    pub fn add_dense(&self, rhs: &Vector<T>) -> Vector<T> {
+        self + rhs
+    }
+
+    /// Same as the [`SparseVector::add_dense`] method, but it returns an error (instead of
+    /// panicking) if the vectors have different dimensions.
+    pub fn try_add_dense(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinearAlgebraError> {
+        self.check_dim(rhs)?;
+        Ok(self + rhs)
+    }
+}
+
+
+impl<T> Add<&SparseVector<T>> for &SparseVector<T> where T: Copy + PartialEq + Default + Add<Output = T> {
+    type Output = SparseVector<T>;
+
+    /// Add this sparse vector to the other sparse vector (see [`SparseVector::add`]), and it
+    /// panics if they have different dimensions.
+    fn add(self, rhs: &SparseVector<T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+
+        // Merge the (sorted) non-zero components of both vectors
+        let mut entries = Vec::with_capacity(self.nnz() + rhs.nnz());
+        let (mut i, mut j) = (0, 0);
+        while i < self.entries.len() && j < rhs.entries.len() {
+            let ((vi, v), (wi, w)) = (self.entries[i], rhs.entries[j]);
+            match vi.cmp(&wi) {
+                Ordering::Less => {
+                    entries.push((vi, v));
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    entries.push((wi, w));
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    let sum = v + w;
+                    if sum != T::default() {
+                        entries.push((vi, sum));
+                    }
+                    (i, j) = (i + 1, j + 1);
+                }
+            }
+        }
+        entries.extend_from_slice(&self.entries[i..]);
+        entries.extend_from_slice(&rhs.entries[j..]);
+
+        SparseVector { dim: self.dim, entries: entries.into_boxed_slice() }
+    }
+}
+
+
+impl<T> Add<&Vector<T>> for &SparseVector<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Vector<T>;
+
+    /// Add this sparse vector to the other (dense) vector (see [`SparseVector::add_dense`]), and
+    /// it panics if they have different dimensions.
+    fn add(self, rhs: &Vector<T>) -> Self::Output {
+        let mut sum: Vector<T> = rhs.iter().collect();
+        sum += self;
+        sum
+    }
+}
+
+impl<T> Add<&SparseVector<T>> for &Vector<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Vector<T>;
+
+    /// Add this vector to the other sparse vector, and it panics if they have different
+    /// dimensions.
+    fn add(self, rhs: &SparseVector<T>) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<T> Add<&SparseVector<T>> for Vector<T> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Vector<T>;
+
+    fn add(mut self, rhs: &SparseVector<T>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> AddAssign<&SparseVector<T>> for Vector<T> where T: Copy + PartialEq + Add<Output = T> {
+
+    /// Add the other sparse vector to this vector (in place), which only involves its non-zero
+    /// components, and it panics if they have different dimensions.
+    fn add_assign(&mut self, rhs: &SparseVector<T>) {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        let components = self.as_mut_slice();
+        for (index, value) in rhs.iter() {
+            let x = &mut components[(index - 1) as usize];
+            *x = *x + value;
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_sparse() {
+        let v = SparseVector::new(10, [(1, 1.5), (4, 2.0), (10, 1.0)]);
+        let w = SparseVector::new(10, [(2, 3.0), (4, -2.0), (7, 0.5)]);
+        let sum = &v + &w;
+        assert_eq!(sum.entries[..], [(1, 1.5), (2, 3.0), (7, 0.5), (10, 1.0)]);
+        assert_eq!(Vector::from(&sum), &Vector::from(&v) + &Vector::from(&w));
+    }
+
+    #[test]
+    fn add_sparse_empty() {
+        let v = SparseVector::new(3, [(2, 1)]);
+        assert_eq!(&v + &SparseVector::zeros(3), v);
+        assert_eq!(&SparseVector::zeros(3) + &v, v);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn add_sparse_vectors_of_different_dimensions() {
+        let v = SparseVector::new(3, [(2, 1)]);
+        let _ = &v + &SparseVector::new(4, [(2, 1)]);
+    }
+
+    #[test]
+    fn add_dense() {
+        let v = SparseVector::new(4, [(1, 10), (4, -1)]);
+        let w = Vector::from([1, 2, 3, 4]);
+        let expected = Vector::from([11, 2, 3, 3]);
+        assert_eq!(&v + &w, expected);
+        assert_eq!(&w + &v, expected);
+        assert_eq!(Vector::from([1, 2, 3, 4]) + &v, expected);
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut w = Vector::from([0.5, 0.5, 0.5]);
+        w += &SparseVector::new(3, [(3, 1.0)]);
+        assert_eq!(w, Vector::from([0.5, 0.5, 1.5]));
+    }
+
+    #[test]
+    fn try_add() {
+        let v = SparseVector::new(4, [(2, 2)]);
+        let err = LinearAlgebraError::DimensionMismatch { expected: 4, found: 3 };
+        assert_eq!(v.try_add(&SparseVector::zeros(3)), Err(err.clone()));
+        assert_eq!(v.try_add_dense(&Vector::from([1, 2, 3])), Err(err));
+    }
+}
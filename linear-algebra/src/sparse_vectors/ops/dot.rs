@@ -0,0 +1,99 @@
+use super::super::SparseVector;
+use crate::{Dimension, LinearAlgebraError, Vector};
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+impl<T> SparseVector<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+
+    /// Compute the dot product of this and the other sparse vector (see [`Vector::dot`]), which
+    /// only involves the components that are non-zero in both of them.
+    ///
+    /// It panics if the vectors have different dimensions.
+    pub fn dot(&self, other: &SparseVector<T>) -> T {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+
+        // Merge the (sorted) non-zero components of both vectors
+        let (mut i, mut j) = (0, 0);
+        let products = core::iter::from_fn(|| {
+            while i < self.entries.len() && j < other.entries.len() {
+                let ((vi, v), (wi, w)) = (self.entries[i], other.entries[j]);
+                match vi.cmp(&wi) {
+                    Ordering::Less => i += 1,
+                    Ordering::Greater => j += 1,
+                    Ordering::Equal => {
+                        (i, j) = (i + 1, j + 1);
+                        return Some(v * w);
+                    }
+                }
+            }
+            None
+        });
+        products.sum()
+    }
+
+    /// Same as the [`SparseVector::dot`] method, but it returns an error (instead of panicking)
+    /// if the vectors have different dimensions.
+    pub fn try_dot(&self, other: &SparseVector<T>) -> Result<T, LinearAlgebraError> {
+        self.check_dim(other)?;
+        Ok(self.dot(other))
+    }
+
+    /// Compute the dot product of this sparse vector and the other (dense) vector, which only
+    /// involves the non-zero components of this sparse vector.
+    ///
+    /// It panics if the vectors have different dimensions.
+    pub fn dot_dense(&self, other: &Vector<T>) -> T {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        let components = other.as_slice();
+        self.iter()
+            .map(|(index, value)| value * components[(index - 1) as usize])
+            .sum()
+    }
+
+    /// Same as the [`SparseVector::dot_dense`] method, but it returns an error (instead of
+    /// panicking) if the vectors have different dimensions.
+    pub fn try_dot_dense(&self, other: &Vector<T>) -> Result<T, LinearAlgebraError> {
+        self.check_dim(other)?;
+        Ok(self.dot_dense(other))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dot_sparse() {
+        let v = SparseVector::new(100, [(1, 2), (50, 3), (99, 4)]);
+        let w = SparseVector::new(100, [(2, 7), (50, 5), (99, -1), (100, 8)]);
+        assert_eq!(v.dot(&w), 11);
+        assert_eq!(w.dot(&v), 11);
+    }
+
+    #[test]
+    fn dot_sparse_disjoint() {
+        let v = SparseVector::new(10, [(1, 2.5)]);
+        let w = SparseVector::new(10, [(2, 2.5)]);
+        assert_eq!(v.dot(&w), 0.0);
+    }
+
+    #[test]
+    fn dot_dense() {
+        let v = SparseVector::new(4, [(2, 2), (4, -1)]);
+        let w = Vector::from([1, 2, 3, 4]);
+        assert_eq!(v.dot_dense(&w), 0);
+        assert_eq!(v.dot_dense(&w), w.dot(&Vector::from(&v)));
+    }
+
+    #[test]
+    fn try_dot() {
+        let v = SparseVector::new(4, [(2, 2)]);
+        let err = LinearAlgebraError::DimensionMismatch { expected: 4, found: 3 };
+        assert_eq!(v.try_dot(&SparseVector::new(3, [(1, 1)])), Err(err.clone()));
+        assert_eq!(v.try_dot_dense(&Vector::from([1, 2, 3])), Err(err));
+        assert_eq!(v.try_dot_dense(&Vector::from([1, 2, 3, 4])), Ok(4));
+    }
+}
@@ -0,0 +1,70 @@
+use super::super::SparseVector;
+use core::ops::Mul;
+
+impl<T> SparseVector<T> where T: Copy + PartialEq + Default + Mul<Output = T> {
+
+    /// Multiply this sparse vector by a scalar (see [`Vector::scale_by`](crate::Vector::scale_by)),
+    /// which only involves its non-zero components.
+    ///
+    /// This method is a thin wrapper around the `*` operator applied to a sparse vector reference
+    /// and a scalar.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_linear_algebra::*;
+    /// let v = SparseVector::new(10, [(1, 2), (5, 3)]);
+    /// assert_eq!(v.scale_by(2), SparseVector::new(10, [(1, 4), (5, 6)]));
+    /// assert_eq!(v.scale_by(0).nnz(), 0);
+    /// ```
+    pub fn scale_by(&self, scalar: T) -> Self {
+        self * scalar
+    }
+}
+
+
+impl<T> Mul<T> for &SparseVector<T> where T: Copy + PartialEq + Default + Mul<Output = T> {
+    type Output = SparseVector<T>;
+
+    /// Multiply this sparse vector by a scalar (see [`SparseVector::scale_by`])
+    fn mul(self, scalar: T) -> Self::Output {
+        SparseVector {
+            dim: self.dim,
+            entries: self.iter()
+                .map(|(index, value)| (index, value * scalar))
+                .filter(|(_, value)| *value != T::default())
+                .collect(),
+        }
+    }
+}
+
+impl<T> Mul<T> for SparseVector<T> where T: Copy + PartialEq + Default + Mul<Output = T> {
+    type Output = SparseVector<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        &self * scalar
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn scale_by() {
+        let v = SparseVector::new(5000, [(1, 1.5), (4999, -2.0)]);
+        let scaled = v.scale_by(2.0);
+        assert_eq!(scaled.entries[..], [(1, 3.0), (4999, -4.0)]);
+        assert_eq!((v * 0.5).entries[..], [(1, 0.75), (4999, -1.0)]);
+    }
+
+    #[test]
+    fn scale_by_decimal() {
+        let v = SparseVector::new(3, [(2, Decimal::new(150, 2))]);
+        let scaled = &v * Decimal::new(2, 0);
+        assert_eq!(scaled.get(2), Decimal::new(3, 0));
+        assert_eq!(scaled.get(1), Decimal::new(0, 0));
+    }
+}
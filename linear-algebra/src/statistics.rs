@@ -0,0 +1,47 @@
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// The estimator of the statistics of a vector, depending on whether its components are the whole
+/// population, or just a sample of it (such as the returns observed over a period of time).
+///
+/// See [`Vector::variance`](crate::Vector::variance) and its relatives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Estimator {
+    /// The components are the whole population (e.g. the variance is divided by $n$)
+    Population,
+    /// The components are a sample of the population, and the estimator corrects for the bias
+    /// (e.g. the variance is divided by $n - 1$, as per Bessel's correction)
+    Sample,
+}
+
+impl Estimator {
+
+    // The "delta degrees of freedom" (that is subtracted from the count of the components)
+    pub(crate) fn ddof(self) -> usize {
+        match self {
+            Estimator::Population => 0,
+            Estimator::Sample => 1,
+        }
+    }
+}
+
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// The interpolation method of a quantile falling between two components of a (sorted) vector.
+///
+/// Given the $n$ sorted components $x_1 \le \dots \le x_n$, the quantile $q$ is at the (fractional)
+/// position $h = 1 + (n - 1) q$, that is between $x_{\lfloor h \rfloor}$ and $x_{\lceil h \rceil}$.
+///
+/// See [`Vector::quantile`](crate::Vector::quantile).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// The linear interpolation between the two components (the default of most spreadsheets and
+    /// statistical packages)
+    Linear,
+    /// The lower of the two components
+    Lower,
+    /// The higher of the two components
+    Higher,
+    /// The nearest of the two components (the lower one on ties)
+    Nearest,
+    /// The mean of the two components
+    Midpoint,
+}
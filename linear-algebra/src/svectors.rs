@@ -0,0 +1,116 @@
+use super::Dimension;
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// Representation of a mathematical vector with a dimension known at compile time
+///
+/// It's the same as [`Vector`](crate::Vector), but its components are held in a fixed-size array
+/// (instead of a heap-allocated boxed slice), so that small vectors (such as the sensitivities of
+/// an option to a few risk factors) never allocate.
+///
+/// # Usage
+/// To create a stack-allocated vector, you can:
+/// - either use the [`beaumont_macros::vector!`] macro (with the `; SVector` suffix),
+/// - or invoke the [`SVector::from`] converter function.
+///
+/// ```rust
+/// use beaumont_macros::*;
+/// use beaumont_linear_algebra::*;
+///
+/// let v1 = vector![1, 2, 3; SVector];
+/// let v2 = SVector::from([1, 2, 3]);
+/// assert_eq!(v1, v2);
+///
+/// // Negating, adding, scaling, and dot product
+/// assert_eq!(-v1, SVector::from([-1, -2, -3]));
+/// assert_eq!(v1 + v2, SVector::from([2, 4, 6]));
+/// assert_eq!(2 * v1, SVector::from([2, 4, 6]));
+/// assert_eq!(v1.dot(&v2), 14);
+///
+/// // Converting from and to heap-allocated vectors
+/// let v3: Vector<i32> = v1.into();
+/// assert_eq!(v3, vector![1, 2, 3]);
+/// assert_eq!(SVector::<i32, 3>::try_from(&v3), Ok(v1));
+/// ```
+///
+/// Operations between vectors of different dimensions do not compile:
+///
+/// ```rust,compile_fail
+/// use beaumont_linear_algebra::*;
+///
+/// let v1 = SVector::from([1, 2, 3]);
+/// let v2 = SVector::from([1, 2]);
+/// let v3 = v1 + v2;
+/// ```
+///
+/// # Memory
+/// Differently from [`Vector`](crate::Vector), these vectors are:
+///
+/// - having a dimension fixed at compile time,
+/// - stack-allocated (unless explicitly boxed),
+/// - and provided with copy semantics (instead of move semantics).
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SVector<T, const N: usize> where T: Copy + PartialEq {
+    components: [T; N],
+}
+
+impl<T, const N: usize> Dimension for SVector<T, N> where T: Copy + PartialEq {
+
+    /// Returns the number of these vector components (which is known at compile time).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_linear_algebra::*;
+    /// let v = SVector::from([1, 2, 3]);
+    /// assert_eq!(v.dim(), 3);
+    /// ```
+    fn dim(&self) -> u64 {
+        N as u64
+    }
+}
+
+
+impl<T, const N: usize> SVector<T, N> where T: Copy + PartialEq {
+
+    /// Returns the components of this vector as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.components
+    }
+
+    /// Returns the components of this vector as a mutable slice (which can't be resized)
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components
+    }
+
+    /// The iterator over the components of this vector.
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, T>> {
+        self.components.iter().copied()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn dim() {
+        let v = SVector { components: [1, 2, 3] };
+        assert_eq!(v.dim(), 3);
+        assert_eq!(SVector::<f64, 0> { components: [] }.dim(), 0);
+    }
+
+    #[test]
+    fn copy_semantics() {
+        let v1 = SVector { components: [1.5, 2.5] };
+        let mut v2 = v1;
+        v2.as_mut_slice()[0] = 0.0;
+        assert_eq!(v1.as_slice(), [1.5, 2.5]);
+        assert_eq!(v2.iter().collect::<Vec<_>>(), [0.0, 2.5]);
+    }
+}
+
+
+mod from;
+mod ops;
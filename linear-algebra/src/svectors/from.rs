@@ -0,0 +1,76 @@
+use super::SVector;
+use crate::{Dimension, LinearAlgebraError, Vector};
+
+
+/// Create a new stack-allocated vector from a fixed-size array
+impl<T, const N: usize> From<[T; N]> for SVector<T, N> where T: Copy + PartialEq {
+    fn from(components: [T; N]) -> Self {
+        Self { components }
+    }
+}
+
+/// Unwrap the components of a stack-allocated vector into a fixed-size array
+impl<T, const N: usize> From<SVector<T, N>> for [T; N] where T: Copy + PartialEq {
+    fn from(v: SVector<T, N>) -> Self {
+        v.components
+    }
+}
+
+/// Create a new (heap-allocated) vector from a stack-allocated vector
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T> where T: Copy + PartialEq {
+    fn from(v: SVector<T, N>) -> Self {
+        Vector::from(v.components)
+    }
+}
+
+/// Create a new stack-allocated vector from a (heap-allocated) vector, which is required to have
+/// the same dimension
+impl<T, const N: usize> TryFrom<&Vector<T>> for SVector<T, N> where T: Copy + PartialEq {
+    type Error = LinearAlgebraError;
+
+    fn try_from(v: &Vector<T>) -> Result<Self, Self::Error> {
+        let components: [T; N] = v
+            .as_slice()
+            .try_into()
+            .map_err(|_| LinearAlgebraError::DimensionMismatch { expected: N as u64, found: v.dim() })?;
+        Ok(Self { components })
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vector<T>> for SVector<T, N> where T: Copy + PartialEq {
+    type Error = LinearAlgebraError;
+
+    fn try_from(v: Vector<T>) -> Result<Self, Self::Error> {
+        Self::try_from(&v)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_array() {
+        let v = SVector::from([1.5, 2.5]);
+        assert_eq!(v.components, [1.5, 2.5]);
+        assert_eq!(<[f64; 2]>::from(v), [1.5, 2.5]);
+    }
+
+    #[test]
+    fn to_vector() {
+        let v = Vector::from(SVector::from([1, 2, 3]));
+        assert_eq!(v, Vector::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn try_from_vector() {
+        let v = Vector::from([1, 2, 3]);
+        assert_eq!(SVector::<i32, 3>::try_from(&v), Ok(SVector::from([1, 2, 3])));
+        assert_eq!(SVector::<i32, 3>::try_from(v), Ok(SVector::from([1, 2, 3])));
+
+        let err = SVector::<i32, 4>::try_from(Vector::from([1, 2, 3])).unwrap_err();
+        assert_eq!(err, LinearAlgebraError::DimensionMismatch { expected: 4, found: 3 });
+    }
+}
@@ -0,0 +1,6 @@
+mod idx;
+mod neg;
+mod add;
+mod sub;
+mod scale;
+mod dot;
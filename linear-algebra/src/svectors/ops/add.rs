@@ -0,0 +1,43 @@
+use super::super::SVector;
+use core::ops::{Add, AddAssign};
+
+impl<T, const N: usize> Add for SVector<T, N> where T: Copy + PartialEq + Add<Output = T> {
+    type Output = Self;
+
+    /// Add the other vector to this vector (see [`Vector::add`](crate::Vector::add)), which are
+    /// required to have the same dimension at compile time.
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T, const N: usize> AddAssign for SVector<T, N> where T: Copy + PartialEq + Add<Output = T> {
+
+    /// Add the other vector to this vector (in place)
+    fn add_assign(&mut self, rhs: Self) {
+        for (x, y) in self.components.iter_mut().zip(rhs.components) {
+            *x = *x + y;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add() {
+        let v = SVector { components: [1, 2, 3] };
+        let w = SVector { components: [4, 5, 6] };
+        assert_eq!((v + w).components, [5, 7, 9]);
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut v = SVector { components: [1.5, 2.5] };
+        v += SVector { components: [1.0, -1.0] };
+        assert_eq!(v.components, [2.5, 1.5]);
+    }
+}
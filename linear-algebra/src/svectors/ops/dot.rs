@@ -0,0 +1,49 @@
+use super::super::SVector;
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+impl<T, const N: usize> SVector<T, N> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+
+    /// Compute the dot product of this and the other vector (see [`Vector::dot`](crate::Vector::dot)),
+    /// which are required to have the same dimension at compile time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let deltas = vector![0.52, -0.31, 0.18; SVector];
+    /// let positions = vector![100.0, 200.0, -50.0; SVector];
+    /// assert_eq!(deltas.dot(&positions), -19.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .map(|(v, w)| *v * *w)
+            .sum()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dot() {
+        let v = SVector { components: [1, 2, 3] };
+        let w = SVector { components: [4, 5, 6] };
+        assert_eq!(v.dot(&w), 32);
+    }
+
+    #[test]
+    fn dot_of_dual() {
+        use beaumont_numbers::Dual;
+        // d/dx (x * 3 + 2 * 5) = 3
+        let x = Dual::variable(2.0);
+        let v = SVector { components: [x, Dual::constant(2.0)] };
+        let w = SVector { components: [Dual::constant(3.0), Dual::constant(5.0)] };
+        let r = v.dot(&w);
+        assert_eq!((r.value(), r.derivative()), (16.0, 3.0));
+    }
+}
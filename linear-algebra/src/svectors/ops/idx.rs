@@ -0,0 +1,56 @@
+use core::ops::{Index, IndexMut};
+use crate::SVector;
+
+impl<T, const N: usize> Index<u64> for SVector<T, N> where T: Copy + PartialEq {
+    type Output = T;
+
+    /// Read the component at the given index, with it starting from 1 (not 0).
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3; SVector];
+    /// assert_eq!(v[2], 2);
+    /// ```
+    fn index(&self, index: u64) -> &Self::Output {
+        &(self.components[(index - 1) as usize])
+    }
+}
+
+impl<T, const N: usize> IndexMut<u64> for SVector<T, N> where T: Copy + PartialEq {
+
+    /// Write the component at the given index, with it starting from 1 (not 0).
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3; SVector];
+    /// v[2] = 5;
+    /// assert_eq!(v, vector![1, 5, 3; SVector]);
+    /// ```
+    fn index_mut(&mut self, index: u64) -> &mut Self::Output {
+        &mut (self.components[(index - 1) as usize])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn idx() {
+        let mut v = SVector { components: [1, 2, 3] };
+        v[1] += 10;
+        assert_eq!(v[1], 11);
+        assert_eq!(v[3], 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn idx_out_of_bounds() {
+        let v = SVector { components: [1, 2, 3] };
+        let _ = v[4];
+    }
+}
@@ -0,0 +1,23 @@
+use super::super::SVector;
+use core::ops::Neg;
+
+impl<T, const N: usize> Neg for SVector<T, N> where T: Copy + PartialEq + Neg<Output = T> {
+    type Output = Self;
+
+    /// Negate this vector (see [`Vector::neg`](crate::Vector::neg))
+    fn neg(self) -> Self::Output {
+        Self { components: self.components.map(|x| -x) }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neg() {
+        let v = SVector { components: [1.5, -2.0, 0.0] };
+        assert_eq!((-v).components, [-1.5, 2.0, -0.0]);
+    }
+}
@@ -0,0 +1,77 @@
+use super::super::SVector;
+use core::ops::{Mul, MulAssign};
+
+impl<T, const N: usize> SVector<T, N> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Multiply this vector by a scalar (see [`Vector::scale_by`](crate::Vector::scale_by)).
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::* ;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3; SVector];
+    /// assert_eq!(v.scale_by(2), vector![2, 4, 6; SVector]);
+    /// assert_eq!(v * 2, vector![2, 4, 6; SVector]);
+    /// ```
+    pub fn scale_by(self, scalar: T) -> Self {
+        self * scalar
+    }
+}
+
+
+impl<T, const N: usize> Mul<T> for SVector<T, N> where T: Copy + PartialEq + Mul<Output = T> {
+    type Output = Self;
+
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T, const N: usize> MulAssign<T> for SVector<T, N> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Multiply this vector by a scalar (in place)
+    fn mul_assign(&mut self, scalar: T) {
+        for x in self.components.iter_mut() {
+            *x = *x * scalar;
+        }
+    }
+}
+
+
+impl_scalar_mul!(SVector<_, const N: usize>);
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn scale_by() {
+        let v = SVector { components: [1.5f64, -2.0] };
+        assert_eq!(v.scale_by(2.0).components, [3.0, -4.0]);
+        assert_eq!((2.0 * v).components, [3.0, -4.0]);
+    }
+
+    #[test]
+    fn mul_assign() {
+        let mut v = SVector { components: [1, -2, 3] };
+        v *= -3;
+        assert_eq!(v.components, [-3, 6, -9]);
+    }
+
+    #[test]
+    fn mul_decimal_on_the_left() {
+        let v = SVector { components: [Decimal::new(150, 2), Decimal::new(-2, 0)] };
+        let scaled = Decimal::new(2, 0) * v;
+        assert_eq!(scaled.components, [Decimal::new(3, 0), Decimal::new(-4, 0)]);
+    }
+
+    #[test]
+    fn mul_complex_on_the_left() {
+        use beaumont_numbers::Complex;
+        let v = SVector { components: [Complex::new(1, 0), Complex::new(1, 1)] };
+        assert_eq!((Complex::new(0, 1) * v).components, [Complex::new(0, 1), Complex::new(-1, 1)]);
+    }
+}
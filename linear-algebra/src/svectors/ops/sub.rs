@@ -0,0 +1,43 @@
+use super::super::SVector;
+use core::ops::{Sub, SubAssign};
+
+impl<T, const N: usize> Sub for SVector<T, N> where T: Copy + PartialEq + Sub<Output = T> {
+    type Output = Self;
+
+    /// Subtract the other vector from this vector (see [`Vector::sub`](crate::Vector::sub)),
+    /// which are required to have the same dimension at compile time.
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T, const N: usize> SubAssign for SVector<T, N> where T: Copy + PartialEq + Sub<Output = T> {
+
+    /// Subtract the other vector from this vector (in place)
+    fn sub_assign(&mut self, rhs: Self) {
+        for (x, y) in self.components.iter_mut().zip(rhs.components) {
+            *x = *x - y;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sub() {
+        let v = SVector { components: [4, 5, 6] };
+        let w = SVector { components: [1, 2, 4] };
+        assert_eq!((v - w).components, [3, 3, 2]);
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut v = SVector { components: [1.5, 2.5] };
+        v -= SVector { components: [1.0, -1.0] };
+        assert_eq!(v.components, [0.5, 3.5]);
+    }
+}
@@ -0,0 +1,420 @@
+use super::{Dimension, LinearAlgebraError, Vector};
+use core::fmt;
+use core::ops::{Bound, Range, RangeBounds};
+
+/// A trait for the types that can be borrowed as a (read-only) view of a vector, such as vectors
+/// and their views, so that the operations (such as [`Vector::dot`] and [`Vector::add`]) accept
+/// any of them as their operands.
+pub trait AsVectorView<T> where T: Copy + PartialEq {
+
+    /// Borrow this vector as a view of all its components
+    fn as_view(&self) -> VectorView<'_, T>;
+}
+
+
+/// Representation of a (read-only) view of the components of a vector, borrowing them rather
+/// than copying them
+///
+/// The components of a view are the ones of a range of the vector (such as the last 20
+/// observations of a time series), possibly taking every n-th of them (a "strided" view), and
+/// possibly in reversed order. As for vectors, they are indexed starting from 1.
+///
+/// # Usage
+/// To create a view, you can:
+/// - either invoke the [`Vector::view`] or the [`Vector::slice`] methods,
+/// - or narrow down another view (see [`VectorView::slice`], [`VectorView::step_by`] and
+///   [`VectorView::reversed`]).
+///
+/// ```rust
+/// use beaumont_macros::*;
+/// use beaumont_linear_algebra::*;
+///
+/// let prices = vector![10, 11, 12, 13, 14, 15, 16];
+///
+/// // The last 3 prices (without copying them)
+/// let recent = prices.slice(5..);
+/// assert_eq!(recent, vector![14, 15, 16]);
+/// assert_eq!(recent[1], 14);
+///
+/// // Every other price, from the latest one
+/// let sampled = prices.view().reversed().step_by(2);
+/// assert_eq!(sampled, vector![16, 14, 12, 10]);
+///
+/// // Operations accept views as well as vectors (through the `AsVectorView` trait)
+/// assert_eq!(recent.dot(&vector![1, 1, 1]), 45);
+/// assert_eq!(prices.slice(1..=3).add(&recent), vector![24, 26, 28]);
+/// assert_eq!(recent.scale_by(2), vector![28, 30, 32]);
+///
+/// // Rolling windows
+/// let sums: Vec<i32> = prices.windows(3).map(|w| w.iter().sum()).collect();
+/// assert_eq!(sums, [33, 36, 39, 42, 45]);
+/// ```
+///
+/// # Memory
+/// Views are borrowing the components of a vector (which therefore cannot be modified while
+/// viewed), and they are provided with copy semantics (as shared references are).
+///
+#[derive(Clone, Copy)]
+pub struct VectorView<'a, T> where T: Copy + PartialEq {
+    // The underlying components, spanning from the first to the last component of this view
+    pub(crate) components: &'a [T],
+    // How the components of this view are laid out in the span
+    pub(crate) layout: Layout,
+}
+
+impl<'a, T> Dimension for VectorView<'a, T> where T: Copy + PartialEq {
+
+    /// Returns the number of the components of this view
+    fn dim(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+
+impl<'a, T> VectorView<'a, T> where T: Copy + PartialEq {
+
+    /// Create a view of the given range of the components of this view, indexed starting from 1
+    /// (such as `2..=4`, or `5..` for all the components from the 5th one).
+    ///
+    /// It panics if the range exceeds the dimension of this view.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4, 5];
+    /// let view = v.view().reversed();
+    /// assert_eq!(view.slice(2..=4), vector![4, 3, 2]);
+    /// assert_eq!(view.slice(..3), vector![5, 4]);
+    /// ```
+    pub fn slice(self, range: impl RangeBounds<u64>) -> Self {
+        let range = positions(range, self.len());
+        self.subview(range.start, range.end)
+    }
+
+    /// Create a view of every `step`-th component of this view, starting from the first one.
+    ///
+    /// It panics if the step is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4, 5];
+    /// assert_eq!(v.view().step_by(2), vector![1, 3, 5]);
+    /// assert_eq!(v.view().step_by(3), vector![1, 4]);
+    /// ```
+    pub fn step_by(self, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        let (span, layout) = self.layout.step_by(self.components.len(), step);
+        VectorView { components: &self.components[span], layout }
+    }
+
+    /// Create a view of the components of this view in reversed order
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.view().reversed(), vector![3, 2, 1]);
+    /// ```
+    pub fn reversed(self) -> Self {
+        VectorView { components: self.components, layout: self.layout.reversed() }
+    }
+
+    /// Copy the components of this view into a new (owned) vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4];
+    /// let w: Vector<i32> = v.slice(3..).to_vector();
+    /// assert_eq!(w, vector![3, 4]);
+    /// ```
+    pub fn to_vector(&self) -> Vector<T> {
+        self.iter().collect()
+    }
+
+    // The number of the components of this view
+    pub(crate) fn len(&self) -> usize {
+        self.layout.len(self.components.len())
+    }
+
+    // Create a view of the components from `start` to `end` (starting from 0, exclusive)
+    pub(crate) fn subview(self, start: usize, end: usize) -> Self {
+        let span = self.layout.slice(self.components.len(), start, end);
+        VectorView { components: &self.components[span], layout: self.layout }
+    }
+
+    // Check this view to have the same dimension as the other vector
+    pub(crate) fn check_dim(&self, other: &impl Dimension) -> Result<(), LinearAlgebraError> {
+        if self.dim() != other.dim() {
+            return Err(LinearAlgebraError::DimensionMismatch { expected: self.dim(), found: other.dim() });
+        }
+        Ok(())
+    }
+}
+
+
+impl<T> Vector<T> where T: Copy + PartialEq {
+
+    /// Create a view of all the components of this vector (see [`VectorView`])
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.view(), v);
+    /// ```
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView { components: self.as_slice(), layout: Layout::CONTIGUOUS }
+    }
+
+    /// Create a view of the given range of the components of this vector, indexed starting from 1
+    /// (such as `2..=4`, or `5..` for all the components from the 5th one).
+    ///
+    /// It panics if the range exceeds the dimension of this vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let returns = vector![0.01, -0.02, 0.03, 0.005];
+    /// assert_eq!(returns.slice(2..=3), vector![-0.02, 0.03]);
+    /// assert_eq!(returns.slice(3..).dim(), 2);
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<u64>) -> VectorView<'_, T> {
+        self.view().slice(range)
+    }
+}
+
+
+impl<T> AsVectorView<T> for Vector<T> where T: Copy + PartialEq {
+    fn as_view(&self) -> VectorView<'_, T> {
+        self.view()
+    }
+}
+
+impl<'a, T> AsVectorView<T> for VectorView<'a, T> where T: Copy + PartialEq {
+    fn as_view(&self) -> VectorView<'_, T> {
+        *self
+    }
+}
+
+
+impl<'a, T> fmt::Debug for VectorView<'a, T> where T: Copy + PartialEq + fmt::Debug {
+
+    // Only the components of this view are relevant (rather than their layout)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, 'b, T> PartialEq<VectorView<'b, T>> for VectorView<'a, T> where T: Copy + PartialEq {
+    fn eq(&self, other: &VectorView<'b, T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> PartialEq<Vector<T>> for VectorView<'a, T> where T: Copy + PartialEq {
+    fn eq(&self, other: &Vector<T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> PartialEq<VectorView<'a, T>> for Vector<T> where T: Copy + PartialEq {
+    fn eq(&self, other: &VectorView<'a, T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> From<VectorView<'a, T>> for Vector<T> where T: Copy + PartialEq {
+    fn from(view: VectorView<'a, T>) -> Self {
+        view.to_vector()
+    }
+}
+
+
+// The layout of the components of a view in the span of the underlying components (from the
+// first to the last component of the view): every `stride`-th one, possibly in reversed order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Layout {
+    stride: usize,
+    reversed: bool,
+}
+
+impl Layout {
+    pub(crate) const CONTIGUOUS: Layout = Layout { stride: 1, reversed: false };
+
+    // The layout of every `stride`-th component of a span (such as a column of a matrix)
+    pub(crate) fn strided(stride: usize) -> Self {
+        debug_assert!(stride != 0);
+        Layout { stride, reversed: false }
+    }
+
+    // The number of the components of a view over a span of the given length
+    pub(crate) fn len(self, span: usize) -> usize {
+        if span == 0 { 0 } else { (span - 1) / self.stride + 1 }
+    }
+
+    // The position in the span of the component at the given position in the view (from 0)
+    pub(crate) fn position(self, span: usize, i: usize) -> usize {
+        let j = if self.reversed { self.len(span) - 1 - i } else { i };
+        j * self.stride
+    }
+
+    pub(crate) fn stride(self) -> usize {
+        self.stride
+    }
+
+    pub(crate) fn is_reversed(self) -> bool {
+        self.reversed
+    }
+
+    pub(crate) fn reversed(self) -> Self {
+        Layout { stride: self.stride, reversed: !self.reversed }
+    }
+
+    // The sub-span of the components from `start` to `end` of the view (from 0, exclusive)
+    pub(crate) fn slice(self, span: usize, start: usize, end: usize) -> Range<usize> {
+        if start >= end {
+            return 0..0;
+        }
+        let len = self.len(span);
+        let (first, last) = if self.reversed { (len - end, len - 1 - start) } else { (start, end - 1) };
+        first * self.stride..last * self.stride + 1
+    }
+
+    // The sub-span and the layout of every `step`-th component of the view
+    pub(crate) fn step_by(self, span: usize, step: usize) -> (Range<usize>, Layout) {
+        let len = self.len(span);
+        let layout = Layout { stride: self.stride * step, reversed: self.reversed };
+        if len == 0 {
+            return (0..0, layout);
+        }
+        let last = (len - 1) / step * step;
+        (self.slice(span, 0, last + 1), layout)
+    }
+}
+
+
+// Convert the given range of indices (starting from 1) into the range of the positions (starting
+// from 0) of the components of a view, and it panics if it exceeds the given dimension
+pub(crate) fn positions(range: impl RangeBounds<u64>, dim: usize) -> Range<usize> {
+    let dim = dim as u64;
+    let start = match range.start_bound() {
+        Bound::Included(&index) => {
+            assert!(index >= 1, "{}", LinearAlgebraError::IndexOutOfBounds { index, dim });
+            index - 1
+        }
+        Bound::Excluded(&index) => index,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&index) => index,
+        Bound::Excluded(&index) => index.saturating_sub(1),
+        Bound::Unbounded => dim,
+    };
+    assert!(end <= dim, "{}", LinearAlgebraError::IndexOutOfBounds { index: end, dim });
+    assert!(start <= end, "range starts at index {} but ends at index {}", start + 1, end);
+    start as usize..end as usize
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn slice() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        assert_eq!(v.slice(2..=4).components, [2, 3, 4]);
+        assert_eq!(v.slice(2..4).components, [2, 3]);
+        assert_eq!(v.slice(..).components, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(v.slice(6..).components, [6]);
+        assert_eq!(v.slice(7..).dim(), 0);
+        assert_eq!(v.slice(3..3).dim(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 7 out of bounds (dimension 6)")]
+    fn slice_out_of_bounds() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        let _ = v.slice(2..=7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 0 out of bounds (dimension 6)")]
+    fn slice_from_zero() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        let _ = v.slice(0..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "range starts at index 4 but ends at index 2")]
+    fn slice_decreasing() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        let (start, end) = (4, 2);
+        let _ = v.slice(start..=end);
+    }
+
+    #[test]
+    fn step_by() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let strided = v.view().step_by(3);
+        assert_eq!(strided.components, [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(strided, Vector::from([1, 4, 7]));
+
+        // The span is trimmed to the last component of the strided view
+        let strided = v.slice(..6).step_by(2);
+        assert_eq!(strided.components, [1, 2, 3, 4, 5]);
+        assert_eq!(strided.step_by(2), Vector::from([1, 5]));
+    }
+
+    #[test]
+    fn reversed() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let reversed = v.view().reversed();
+        assert_eq!(reversed, Vector::from([7, 6, 5, 4, 3, 2, 1]));
+        assert_eq!(reversed.slice(2..=3), Vector::from([6, 5]));
+        assert_eq!(reversed.step_by(3), Vector::from([7, 4, 1]));
+        assert_eq!(reversed.step_by(4), Vector::from([7, 3]));
+        assert_eq!(reversed.step_by(4).reversed(), Vector::from([3, 7]));
+        assert_eq!(reversed.reversed(), v);
+    }
+
+    #[test]
+    fn composed() {
+        let v: Vector<i32> = (1..=20).collect();
+        let view = v.slice(3..=17).reversed().step_by(4).slice(2..);
+        assert_eq!(view, Vector::from([13, 9, 5]));
+        assert_eq!(view.dim(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn step_by_zero() {
+        let v = Vector::from([1, 2, 3]);
+        let _ = v.view().step_by(0);
+    }
+
+    #[test]
+    fn empty() {
+        let v: Vector<f64> = Vector::from([]);
+        let view = v.view().reversed().step_by(2);
+        assert_eq!(view.dim(), 0);
+        assert_eq!(view, v);
+    }
+}
+
+
+mod mutable;
+mod iter;
+mod ops;
+
+pub use mutable::*;
+pub use iter::*;
@@ -0,0 +1,250 @@
+use super::{Layout, VectorView};
+use core::iter::{FusedIterator, StepBy};
+
+/// Iterator over the components of a vector view (by value, as they are `Copy`).
+pub struct VectorViewIterator<'a, T> where T: Copy + PartialEq {
+    // The iterator over the components of the span (in their order), which shall outlive this field
+    components: StepBy<core::slice::Iter<'a, T>>,
+    // Whether the components are iterated from the back of the span
+    reversed: bool,
+}
+
+impl<'a, T> VectorViewIterator<'a, T> where T: Copy + PartialEq {
+    pub(crate) fn new(components: &'a [T], layout: Layout) -> Self {
+        VectorViewIterator {
+            components: components.iter().step_by(layout.stride()),
+            reversed: layout.is_reversed(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for VectorViewIterator<'a, T> where T: Copy + PartialEq {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = if self.reversed { self.components.next_back() } else { self.components.next() };
+        next.copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.components.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for VectorViewIterator<'a, T> where T: Copy + PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = if self.reversed { self.components.next() } else { self.components.next_back() };
+        next.copied()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorViewIterator<'a, T> where T: Copy + PartialEq {}
+
+impl<'a, T> FusedIterator for VectorViewIterator<'a, T> where T: Copy + PartialEq {}
+
+
+/// Iterator over the (possibly overlapping) views of the components of a vector view, such as the
+/// ones returned by [`VectorView::windows`] and [`VectorView::chunks`].
+pub struct VectorViewsIterator<'a, T> where T: Copy + PartialEq {
+    // The view whose components are viewed
+    view: VectorView<'a, T>,
+    // The positions (from 0) of the first components of the remaining views
+    starts: StepBy<core::ops::Range<usize>>,
+    // The (maximum) number of the components of each view
+    size: usize,
+}
+
+impl<'a, T> VectorViewsIterator<'a, T> where T: Copy + PartialEq {
+
+    // The view starting at the given position (possibly shorter than the others, if it's the last)
+    fn view_at(&self, start: usize) -> VectorView<'a, T> {
+        self.view.subview(start, (start + self.size).min(self.view.len()))
+    }
+}
+
+impl<'a, T> Iterator for VectorViewsIterator<'a, T> where T: Copy + PartialEq {
+    type Item = VectorView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.starts.next().map(|start| self.view_at(start))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.starts.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for VectorViewsIterator<'a, T> where T: Copy + PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.starts.next_back().map(|start| self.view_at(start))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorViewsIterator<'a, T> where T: Copy + PartialEq {}
+
+impl<'a, T> FusedIterator for VectorViewsIterator<'a, T> where T: Copy + PartialEq {}
+
+
+impl<'a, T> VectorView<'a, T> where T: Copy + PartialEq {
+
+    /// The iterator over the components of this view.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4, 5];
+    /// let view = v.view().reversed().step_by(2);
+    /// assert_eq!(view.iter().collect::<Vec<_>>(), [5, 3, 1]);
+    /// assert_eq!(view.iter().rev().collect::<Vec<_>>(), [1, 3, 5]);
+    /// ```
+    pub fn iter(&self) -> VectorViewIterator<'a, T> {
+        VectorViewIterator::new(self.components, self.layout)
+    }
+
+    /// The iterator over all the overlapping views of `size` consecutive components of this view
+    /// (such as the rolling windows of a time series), from the first to the last one.
+    ///
+    /// There are no views if this view has less than `size` components, and it panics if the size
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4];
+    /// let mut windows = v.view().windows(3);
+    /// assert_eq!(windows.next().unwrap(), vector![1, 2, 3]);
+    /// assert_eq!(windows.next().unwrap(), vector![2, 3, 4]);
+    /// assert!(windows.next().is_none());
+    /// ```
+    pub fn windows(self, size: usize) -> VectorViewsIterator<'a, T> {
+        assert!(size != 0, "window size must be non-zero");
+        let count = (self.len() + 1).saturating_sub(size);
+        VectorViewsIterator { view: self, starts: (0..count).step_by(1), size }
+    }
+
+    /// The iterator over the non-overlapping views of `size` consecutive components of this view,
+    /// from the first to the last one (which has less components, if they are not a multiple of
+    /// the size).
+    ///
+    /// It panics if the size is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4, 5];
+    /// let mut chunks = v.view().chunks(2);
+    /// assert_eq!(chunks.next().unwrap(), vector![1, 2]);
+    /// assert_eq!(chunks.next().unwrap(), vector![3, 4]);
+    /// assert_eq!(chunks.next().unwrap(), vector![5]);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    pub fn chunks(self, size: usize) -> VectorViewsIterator<'a, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        VectorViewsIterator { view: self, starts: (0..self.len()).step_by(size), size }
+    }
+}
+
+
+impl<T> crate::Vector<T> where T: Copy + PartialEq {
+
+    /// Same as the [`VectorView::windows`] method, applied to a view of all the components of
+    /// this vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let prices = vector![10.0, 11.0, 12.0, 14.0];
+    /// let moving_averages: Vec<f64> = prices.windows(2).map(|w| w.iter().sum::<f64>() / 2.0).collect();
+    /// assert_eq!(moving_averages, [10.5, 11.5, 13.0]);
+    /// ```
+    pub fn windows(&self, size: usize) -> VectorViewsIterator<'_, T> {
+        self.view().windows(size)
+    }
+
+    /// Same as the [`VectorView::chunks`] method, applied to a view of all the components of this
+    /// vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let daily = vector![1, 2, 3, 4, 5, 6, 7];
+    /// let weekly: Vec<i32> = daily.chunks(5).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(weekly, [15, 13]);
+    /// ```
+    pub fn chunks(&self, size: usize) -> VectorViewsIterator<'_, T> {
+        self.view().chunks(size)
+    }
+}
+
+
+impl<'a, T> IntoIterator for VectorView<'a, T> where T: Copy + PartialEq {
+    type Item = T;
+    type IntoIter = VectorViewIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &VectorView<'a, T> where T: Copy + PartialEq {
+    type Item = T;
+    type IntoIter = VectorViewIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::Vector;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iter_strided_reversed() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let view = v.view().reversed().step_by(3);
+        let mut iter = view.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn windows() {
+        let v = Vector::from([1, 2, 3, 4, 5]);
+        let windows: Vec<Vector<i32>> = v.view().reversed().windows(4).map(Vector::from).collect();
+        assert_eq!(windows, [Vector::from([5, 4, 3, 2]), Vector::from([4, 3, 2, 1])]);
+        assert_eq!(v.windows(5).len(), 1);
+        assert_eq!(v.windows(6).len(), 0);
+        assert_eq!(v.windows(1).next_back().unwrap(), Vector::from([5]));
+    }
+
+    #[test]
+    fn chunks() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let chunks: Vec<Vector<i32>> = v.view().step_by(2).chunks(3).map(Vector::from).collect();
+        assert_eq!(chunks, [Vector::from([1, 3, 5]), Vector::from([7])]);
+        assert_eq!(v.chunks(7).len(), 1);
+        assert_eq!(v.chunks(3).next_back().unwrap(), Vector::from([7]));
+
+        let empty: Vector<i32> = Vector::from([]);
+        assert_eq!(empty.chunks(3).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_of_zero() {
+        let v = Vector::from([1, 2, 3]);
+        let _ = v.windows(0);
+    }
+}
@@ -0,0 +1,276 @@
+use super::{positions, AsVectorView, Layout, VectorView};
+use crate::{Dimension, Vector};
+use core::fmt;
+use core::iter::{FusedIterator, StepBy};
+use core::ops::{Add, Mul, RangeBounds};
+
+/// Representation of a mutable view of the components of a vector, which allows modifying them
+/// in place (without copying them)
+///
+/// It's the same as [`VectorView`], but it borrows the components mutably, so that there's just
+/// one view at a time (and therefore it's not `Copy`). Narrowing it down consumes it, but it can
+/// be reborrowed with the [`VectorViewMut::view_mut`] method.
+///
+/// ```rust
+/// use beaumont_macros::*;
+/// use beaumont_linear_algebra::*;
+///
+/// let mut v = vector![1, 2, 3, 4, 5, 6];
+///
+/// // Scaling the last 3 components
+/// v.slice_mut(4..).scale_by_in_place(10);
+/// assert_eq!(v, vector![1, 2, 3, 40, 50, 60]);
+///
+/// // Zeroing every other component
+/// let mut odd = v.view_mut().step_by(2);
+/// odd.fill(0);
+/// assert_eq!(v, vector![0, 2, 0, 40, 0, 60]);
+/// ```
+pub struct VectorViewMut<'a, T> where T: Copy + PartialEq {
+    // The underlying components, spanning from the first to the last component of this view
+    pub(crate) components: &'a mut [T],
+    // How the components of this view are laid out in the span
+    pub(crate) layout: Layout,
+}
+
+impl<'a, T> Dimension for VectorViewMut<'a, T> where T: Copy + PartialEq {
+
+    /// Returns the number of the components of this view
+    fn dim(&self) -> u64 {
+        self.layout.len(self.components.len()) as u64
+    }
+}
+
+
+impl<'a, T> VectorViewMut<'a, T> where T: Copy + PartialEq {
+
+    /// Reborrow this mutable view as a (read-only) view
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView { components: self.components, layout: self.layout }
+    }
+
+    /// Reborrow this mutable view, so that it can be narrowed down without being consumed
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3, 4];
+    /// let mut view = v.view_mut();
+    /// view.view_mut().slice(..=2).fill(0);
+    /// view.view_mut().slice(4..).fill(9);
+    /// assert_eq!(v, vector![0, 0, 3, 9]);
+    /// ```
+    pub fn view_mut(&mut self) -> VectorViewMut<'_, T> {
+        VectorViewMut { components: self.components, layout: self.layout }
+    }
+
+    /// Same as the [`VectorView::slice`] method, but for mutable views.
+    pub fn slice(self, range: impl RangeBounds<u64>) -> Self {
+        let range = positions(range, self.dim() as usize);
+        let span = self.layout.slice(self.components.len(), range.start, range.end);
+        VectorViewMut { components: &mut self.components[span], layout: self.layout }
+    }
+
+    /// Same as the [`VectorView::step_by`] method, but for mutable views.
+    pub fn step_by(self, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        let (span, layout) = self.layout.step_by(self.components.len(), step);
+        VectorViewMut { components: &mut self.components[span], layout }
+    }
+
+    /// Same as the [`VectorView::reversed`] method, but for mutable views.
+    pub fn reversed(self) -> Self {
+        VectorViewMut { components: self.components, layout: self.layout.reversed() }
+    }
+
+    /// The iterator over the components of this view.
+    pub fn iter(&self) -> super::VectorViewIterator<'_, T> {
+        self.view().iter()
+    }
+
+    /// The iterator over mutable references to the components of this view, which allows
+    /// modifying them in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3, 4];
+    /// for (i, c) in v.view_mut().reversed().iter_mut().enumerate() {
+    ///     *c += 10 * i as i32;
+    /// }
+    /// assert_eq!(v, vector![31, 22, 13, 4]);
+    /// ```
+    pub fn iter_mut(&mut self) -> VectorViewIteratorMut<'_, T> {
+        VectorViewIteratorMut {
+            components: self.components.iter_mut().step_by(self.layout.stride()),
+            reversed: self.layout.is_reversed(),
+        }
+    }
+
+    /// Set all the components of this view to the given value (see [`Vector::fill`])
+    pub fn fill(&mut self, value: T) {
+        self.iter_mut().for_each(|x| *x = value);
+    }
+
+    /// Copy the components of the other vector (or view) into this view, and it panics if they
+    /// have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3, 4];
+    /// v.slice_mut(3..).copy_from(&vector![30, 40]);
+    /// assert_eq!(v, vector![1, 2, 30, 40]);
+    /// ```
+    pub fn copy_from(&mut self, other: &impl AsVectorView<T>) {
+        let other = other.as_view();
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        for (x, y) in self.iter_mut().zip(other.iter()) {
+            *x = y;
+        }
+    }
+
+    /// Replace each component of this view with the result of the given function applied to it
+    /// (see [`Vector::map_in_place`])
+    pub fn map_in_place(&mut self, f: impl Fn(T) -> T) {
+        for x in self.iter_mut() {
+            *x = f(*x);
+        }
+    }
+
+    /// Add the other vector (or view) to this view in place (see [`Vector::add_in_place`]), and
+    /// it panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3, 4];
+    /// v.view_mut().step_by(2).add_in_place(&vector![10, 20]);
+    /// assert_eq!(v, vector![11, 2, 23, 4]);
+    /// ```
+    pub fn add_in_place(&mut self, rhs: &impl AsVectorView<T>) where T: Add<Output = T> {
+        let rhs = rhs.as_view();
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x = *x + y;
+        }
+    }
+
+    /// Multiply this view by a scalar in place (see [`Vector::scale_by_in_place`])
+    pub fn scale_by_in_place(&mut self, scalar: T) where T: Mul<Output = T> {
+        self.map_in_place(|x| x * scalar);
+    }
+}
+
+
+impl<T> Vector<T> where T: Copy + PartialEq {
+
+    /// Create a mutable view of all the components of this vector (see [`VectorViewMut`])
+    pub fn view_mut(&mut self) -> VectorViewMut<'_, T> {
+        VectorViewMut { components: self.as_mut_slice(), layout: Layout::CONTIGUOUS }
+    }
+
+    /// Create a mutable view of the given range of the components of this vector, indexed
+    /// starting from 1 (see [`Vector::slice`]).
+    ///
+    /// It panics if the range exceeds the dimension of this vector.
+    pub fn slice_mut(&mut self, range: impl RangeBounds<u64>) -> VectorViewMut<'_, T> {
+        self.view_mut().slice(range)
+    }
+}
+
+
+impl<'a, T> AsVectorView<T> for VectorViewMut<'a, T> where T: Copy + PartialEq {
+    fn as_view(&self) -> VectorView<'_, T> {
+        self.view()
+    }
+}
+
+impl<'a, T> fmt::Debug for VectorViewMut<'a, T> where T: Copy + PartialEq + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view().fmt(f)
+    }
+}
+
+impl<'a, T> PartialEq<Vector<T>> for VectorViewMut<'a, T> where T: Copy + PartialEq {
+    fn eq(&self, other: &Vector<T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+
+/// Iterator over mutable references to the components of a mutable vector view.
+pub struct VectorViewIteratorMut<'a, T> where T: Copy + PartialEq {
+    // The iterator over the components of the span (in their order), which shall outlive this field
+    components: StepBy<core::slice::IterMut<'a, T>>,
+    // Whether the components are iterated from the back of the span
+    reversed: bool,
+}
+
+impl<'a, T> Iterator for VectorViewIteratorMut<'a, T> where T: Copy + PartialEq {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reversed { self.components.next_back() } else { self.components.next() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.components.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for VectorViewIteratorMut<'a, T> where T: Copy + PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.reversed { self.components.next() } else { self.components.next_back() }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorViewIteratorMut<'a, T> where T: Copy + PartialEq {}
+
+impl<'a, T> FusedIterator for VectorViewIteratorMut<'a, T> where T: Copy + PartialEq {}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn narrowed_down() {
+        let mut v: Vector<i32> = (1..=10).collect();
+        let mut view = v.slice_mut(2..=9).reversed().step_by(3);
+        assert_eq!(view, Vector::from([9, 6, 3]));
+        view.scale_by_in_place(-1);
+        assert_eq!(v, Vector::from([1, 2, -3, 4, 5, -6, 7, 8, -9, 10]));
+    }
+
+    #[test]
+    fn iter_mut_both_ends() {
+        let mut v = Vector::from([1, 2, 3, 4, 5]);
+        let mut view = v.view_mut().reversed();
+        let mut iter = view.iter_mut();
+        *iter.next().unwrap() = 50;
+        *iter.next_back().unwrap() = 10;
+        assert_eq!(iter.len(), 3);
+        assert_eq!(v, Vector::from([10, 2, 3, 4, 50]));
+    }
+
+    #[test]
+    fn copy_from_view() {
+        let source = Vector::from([1, 2, 3, 4]);
+        let mut v = Vector::from([0; 4]);
+        v.view_mut().reversed().copy_from(&source.view());
+        assert_eq!(v, Vector::from([4, 3, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn add_in_place_different_dimensions() {
+        let mut v = Vector::from([1, 2, 3, 4]);
+        v.slice_mut(2..).add_in_place(&Vector::from([1, 2]));
+    }
+}
@@ -0,0 +1,5 @@
+mod idx;
+mod dot;
+mod add;
+mod sub;
+mod scale;
@@ -0,0 +1,61 @@
+use super::super::{AsVectorView, VectorView};
+use crate::{Dimension, LinearAlgebraError};
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+impl<'a, T> VectorView<'a, T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
+
+    /// Compute the dot product of this view and the other vector (or view), see
+    /// [`Vector::dot`](crate::Vector::dot).
+    ///
+    /// It panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let prices = vector![9, 10, 12, 8];
+    /// let positions = vector![100, 50, 25];
+    /// assert_eq!(prices.slice(2..).dot(&positions), 1800);
+    /// ```
+    pub fn dot(&self, other: &impl AsVectorView<T>) -> T {
+        let other = other.as_view();
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        self.iter()
+            .zip(other.iter())
+            .map(|(v, w)| v * w)
+            .sum()
+    }
+
+    /// Same as the [`VectorView::dot`] method, but it returns an error (instead of panicking) if
+    /// they have different dimensions.
+    pub fn try_dot(&self, other: &impl AsVectorView<T>) -> Result<T, LinearAlgebraError> {
+        self.check_dim(&other.as_view())?;
+        Ok(self.dot(other))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::{LinearAlgebraError, Vector};
+
+    #[test]
+    fn dot_views() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        let odd = v.view().step_by(2);
+        let even = v.slice(2..).step_by(2).reversed();
+        assert_eq!(odd.dot(&even), 6 + 12 + 10);
+        assert_eq!(v.dot(&v.view()), 91);
+        assert_eq!(v.slice(..=3).dot(&Vector::from([1, 1, 1])), 6);
+    }
+
+    #[test]
+    fn try_dot_views() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6]);
+        let err = LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 };
+        assert_eq!(v.view().step_by(2).try_dot(&v.slice(5..)), Err(err));
+        assert_eq!(v.try_dot(&v.slice(2..)), Err(LinearAlgebraError::DimensionMismatch { expected: 6, found: 5 }));
+    }
+}
@@ -0,0 +1,87 @@
+use super::super::{VectorView, VectorViewMut};
+use crate::{Dimension, LinearAlgebraError};
+use core::ops::{Index, IndexMut};
+
+impl<'a, T> Index<u64> for VectorView<'a, T> where T: Copy + PartialEq {
+    type Output = T;
+
+    /// Read the component at the given index, with it starting from 1 (not 0), and it panics if
+    /// the index is zero or exceeds the dimension of this view.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4];
+    /// assert_eq!(v.view().reversed()[1], 4);
+    /// ```
+    fn index(&self, index: u64) -> &Self::Output {
+        &self.components[position(index, self.dim(), |i| self.layout.position(self.components.len(), i))]
+    }
+}
+
+impl<'a, T> Index<u64> for VectorViewMut<'a, T> where T: Copy + PartialEq {
+    type Output = T;
+
+    /// Read the component at the given index, with it starting from 1 (not 0), and it panics if
+    /// the index is zero or exceeds the dimension of this view.
+    fn index(&self, index: u64) -> &Self::Output {
+        &self.components[position(index, self.dim(), |i| self.layout.position(self.components.len(), i))]
+    }
+}
+
+impl<'a, T> IndexMut<u64> for VectorViewMut<'a, T> where T: Copy + PartialEq {
+
+    /// Write the component at the given index, with it starting from 1 (not 0), and it panics if
+    /// the index is zero or exceeds the dimension of this view.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3, 4];
+    /// v.view_mut().reversed()[1] = 0;
+    /// assert_eq!(v, vector![1, 2, 3, 0]);
+    /// ```
+    fn index_mut(&mut self, index: u64) -> &mut Self::Output {
+        let span = self.components.len();
+        let layout = self.layout;
+        &mut self.components[position(index, self.dim(), |i| layout.position(span, i))]
+    }
+}
+
+// The position in the span of the component at the given index (starting from 1)
+fn position(index: u64, dim: u64, position: impl Fn(usize) -> usize) -> usize {
+    assert!(index >= 1 && index <= dim, "{}", LinearAlgebraError::IndexOutOfBounds { index, dim });
+    position((index - 1) as usize)
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::Vector;
+
+    #[test]
+    fn index() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let view = v.view().reversed().step_by(3);
+        assert_eq!([view[1], view[2], view[3]], [7, 4, 1]);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let mut view = v.view_mut().step_by(2).reversed();
+        view[1] = 70;
+        view[4] = 10;
+        assert_eq!(v, Vector::from([10, 2, 3, 4, 5, 6, 70]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 4 out of bounds (dimension 3)")]
+    fn index_out_of_bounds() {
+        let v = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+        let _ = v.view().step_by(3)[4];
+    }
+}
@@ -0,0 +1,35 @@
+use super::super::VectorView;
+use crate::Vector;
+use core::ops::Mul;
+
+impl<'a, T> VectorView<'a, T> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Multiply this view by a scalar (see [`Vector::scale_by`]), which results in a new (owned)
+    /// vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4];
+    /// assert_eq!(v.view().step_by(2).scale_by(10), vector![10, 30]);
+    /// ```
+    pub fn scale_by(&self, scalar: T) -> Vector<T> {
+        self.iter().map(|x| x * scalar).collect()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::Vector;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn scale_view() {
+        let v = Vector::from([Decimal::new(150, 2), Decimal::new(-2, 0)]);
+        let scaled = v.view().reversed().scale_by(Decimal::new(5, 1));
+        assert_eq!(scaled, Vector::from([Decimal::new(-1, 0), Decimal::new(75, 2)]));
+    }
+}
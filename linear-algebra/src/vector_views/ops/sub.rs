@@ -0,0 +1,57 @@
+use super::super::{AsVectorView, VectorView};
+use crate::{Dimension, LinearAlgebraError, Vector};
+use core::ops::Sub;
+
+impl<'a, T> VectorView<'a, T> where T: Copy + PartialEq + Sub<Output = T> {
+
+    /// Subtract the other vector (or view) from this view, see [`Vector::sub`], which results in
+    /// a new (owned) vector.
+    ///
+    /// It panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3, 4];
+    /// assert_eq!(v.slice(3..).sub(&v.slice(..=2)), vector![2, 2]);
+    /// ```
+    pub fn sub(&self, rhs: &impl AsVectorView<T>) -> Vector<T> {
+        let rhs = rhs.as_view();
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        self.iter()
+            .zip(rhs.iter())
+            .map(|(x, y)| x - y)
+            .collect()
+    }
+
+    /// Same as the [`VectorView::sub`] method, but it returns an error (instead of panicking) if
+    /// they have different dimensions.
+    pub fn try_sub(&self, rhs: &impl AsVectorView<T>) -> Result<Vector<T>, LinearAlgebraError> {
+        self.check_dim(&rhs.as_view())?;
+        Ok(self.sub(rhs))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::{LinearAlgebraError, Vector};
+
+    #[test]
+    fn sub_views() {
+        let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.view().reversed().sub(&v), Vector::from([3.0, 1.0, -1.0, -3.0]));
+        assert_eq!(v.sub(&v.view().reversed()), Vector::from([-3.0, -1.0, 1.0, 3.0]));
+        assert_eq!(v.slice(2..=3).sub(&Vector::from([0.5, 0.5])), Vector::from([1.5, 2.5]));
+    }
+
+    #[test]
+    fn try_sub_views() {
+        let v = Vector::from([1, 2, 3]);
+        let err = LinearAlgebraError::DimensionMismatch { expected: 2, found: 3 };
+        assert_eq!(v.slice(2..).try_sub(&v), Err(err));
+        assert_eq!(v.try_sub(&v.slice(2..)), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 }));
+    }
+}
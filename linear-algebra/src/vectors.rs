@@ -1,4 +1,5 @@
-use super::Dimension;
+use super::{Dimension, LinearAlgebraError};
+use alloc::boxed::Box;
 
 #[cfg_attr(doc, beaumont_macros::allow_katex)]
 /// Representation of a mathematical vector
@@ -54,16 +55,17 @@ use super::Dimension;
 /// assert_eq!(v1[1], 1);
 ///
 /// // Negating
-/// let negated = v1.neg();
+/// let negated = -&v1;
 /// assert_eq!(negated, vector![-1, -2, -3]);
 ///
 /// // Scaling (a.k.a. "scalar multiplication")
 /// let v2 = v1.scale_by(2);
 /// assert_eq!(v2, vector![2, 4, 6]);
+/// assert_eq!(2 * &v1, vector![2, 4, 6]);
 ///
 /// // Adding
 /// let v3 = Vector::from([1, 2, 3]);
-/// let v4 = v3.add(&v2);
+/// let v4 = &v3 + &v2;
 /// assert_eq!(v4, vector![3, 6, 9]);
 /// 
 /// // Dot product
@@ -75,7 +77,8 @@ use super::Dimension;
 /// # Memory
 /// To make them perform well and look like built-in types, our mathematical vectors are:
 ///
-/// - immutable (cannot be modified after creation),
+/// - having a fixed dimension (although their components can be modified in place, such as by
+///   indexing, or by the in-place operations that don't allocate),
 /// - having components contiguously allocated (in a fixed-size array),
 /// - always heap-allocated,
 /// - and requiring move semantics (copy semantics could not be implemented)
@@ -110,10 +113,57 @@ impl<T> Dimension for Vector<T> where T: Copy + PartialEq {
 }
 
 
+impl<T> Vector<T> where T: Copy + PartialEq {
+
+    /// Returns the components of this vector as a slice
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.components
+    }
+
+    /// Returns the components of this vector as a mutable slice (which can't be resized)
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v.as_mut_slice().rotate_left(1);
+    /// assert_eq!(v, vector![2, 3, 1]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.components
+    }
+
+    // Check this vector to have the same dimension as the other (as required by most operations)
+    pub(crate) fn check_dim(&self, other: &impl Dimension) -> Result<(), LinearAlgebraError> {
+        if self.dim() != other.dim() {
+            return Err(LinearAlgebraError::DimensionMismatch { expected: self.dim(), found: other.dim() });
+        }
+        Ok(())
+    }
+}
+
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn check_dim() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1, 2, 3, 4, 5]) };
+        assert_eq!(v.check_dim(&v), Ok(()));
+        assert_eq!(v.check_dim(&w), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 5 }));
+    }
+
     #[test]
     fn dim() {
         let v = Vector { components: Box::new([1, 2, 3]) };
@@ -125,3 +175,8 @@ mod test {
 mod from;
 mod ops;
 mod iter;
+mod display;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "simd")]
+mod simd;
@@ -0,0 +1,101 @@
+use super::Vector;
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Write;
+
+impl<T> fmt::Display for Vector<T> where T: Copy + PartialEq + fmt::Display {
+
+    // Vectors are written as their bracketed components (such as "[1.5, -2, 3]"). Formatting
+    // flags (such as the width and the precision) are applied to each component.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+        for (i, x) in self.components.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(x, f)?;
+        }
+        f.write_char(']')
+    }
+}
+
+
+impl<T> Vector<T> where T: Copy + PartialEq + fmt::Display {
+
+    /// Render this vector as a column of a LaTeX `pmatrix` environment, which can be rendered
+    /// by KaTeX as well (such as in the documentation, see [`beaumont_macros::allow_katex`]).
+    ///
+    /// Note that the backslashes need to be escaped in Markdown (such as doc comments), where
+    /// the line breaks are written as `\\\\`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.5, -2.0, 3.0];
+    /// assert_eq!(v.to_latex(), "\\begin{pmatrix}\n1.5 \\\\\n-2 \\\\\n3\n\\end{pmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::from("\\begin{pmatrix}\n");
+        for (i, x) in self.components.iter().enumerate() {
+            if i > 0 {
+                latex.push_str(" \\\\\n");
+            }
+            // Writing into a string never fails
+            let _ = write!(latex, "{x}");
+        }
+        if !self.components.is_empty() {
+            latex.push('\n');
+        }
+        latex.push_str("\\end{pmatrix}");
+        latex
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn display() {
+        assert_eq!(Vector::from([1, -2, 3]).to_string(), "[1, -2, 3]");
+        assert_eq!(Vector::from([0.5]).to_string(), "[0.5]");
+        assert_eq!(Vector::<i32>::from([]).to_string(), "[]");
+    }
+
+    #[test]
+    fn display_with_flags() {
+        let v = Vector::from([1.0, -2.5, 1.0 / 3.0]);
+        assert_eq!(format!("{v:.2}"), "[1.00, -2.50, 0.33]");
+        assert_eq!(format!("{v:>6.1}"), "[   1.0,   -2.5,    0.3]");
+        assert_eq!(format!("{:+}", Vector::from([1, -2])), "[+1, -2]");
+
+        let d = Vector::from([Decimal::new(15, 1), Decimal::new(-2125, 3)]);
+        assert_eq!(format!("{d:.2}"), "[1.50, -2.13]");
+        assert_eq!(format!("{d:>7.1}"), "[    1.5,    -2.1]");
+        assert_eq!(format!("{d:+}"), "[+1.5, -2.125]");
+    }
+
+    #[test]
+    fn display_complex() {
+        use beaumont_numbers::Complex;
+        let v = Vector::from([Complex::new(1.0, -0.5), Complex::new(0.0, 2.0)]);
+        assert_eq!(v.to_string(), "[1-0.5i, 0+2i]");
+
+        // The imaginary part of a conjugate can be a negative zero
+        let conjugates = Vector::from([Complex::new(1.0, 0.0).conj()]);
+        assert_eq!(conjugates.to_string(), "[1-0i]");
+        assert_eq!(conjugates.to_latex(), "\\begin{pmatrix}\n1-0i\n\\end{pmatrix}");
+    }
+
+    #[test]
+    fn to_latex() {
+        assert_eq!(Vector::from([1, 2]).to_latex(), "\\begin{pmatrix}\n1 \\\\\n2\n\\end{pmatrix}");
+        assert_eq!(Vector::<i32>::from([]).to_latex(), "\\begin{pmatrix}\n\\end{pmatrix}");
+    }
+}
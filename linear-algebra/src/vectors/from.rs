@@ -1,4 +1,5 @@
 use super::Vector;
+use alloc::boxed::Box;
 
 
 /// Create a new mathematical vector from a boxed slice
@@ -22,6 +23,7 @@ impl<T, const LEN: usize> From<[T; LEN]> for Vector<T> where T: PartialEq + Copy
 #[cfg(test)]
 mod test {
     use super::Vector;
+    use alloc::boxed::Box;
 
     #[test]
     fn from_array_of_integers() {
@@ -34,6 +36,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn from_array_of_floats() {
         // from a given fixed-size array
         let arr: [f32; 6] = [1.01, 2.32, 3.675, 4.8976, 5.234567, 6.897654321];
@@ -1,51 +1,194 @@
 use crate::{Dimension, Vector};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
-/// Iterator over the components of a vector.
+/// Iterator over the components of a vector (by value, as they are `Copy`).
 pub struct VectorIterator<'a, T> where T: Copy + PartialEq  {
-    // The referred iterable vector shall outlive this field
-    iterable: &'a Vector<T>,
-    // The current index (state) of the iterator
-    index: u64
+    // The iterator over the components of the referred vector, which shall outlive this field
+    components: core::slice::Iter<'a, T>,
 }
 
 impl<'a, T> Iterator for VectorIterator<'a, T> where T: Copy + PartialEq  {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.iterable.dim() {
-            let component = self.iterable.components[self.index as usize];
-            self.index += 1;
-            Some(component)
-        }
-        else {
-            None
-        }
+        self.components.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.components.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.components.nth(n).copied()
     }
 }
 
+impl<'a, T> DoubleEndedIterator for VectorIterator<'a, T> where T: Copy + PartialEq  {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.components.next_back().copied()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorIterator<'a, T> where T: Copy + PartialEq  {}
+
+impl<'a, T> FusedIterator for VectorIterator<'a, T> where T: Copy + PartialEq  {}
+
+
 impl<T> Vector<T> where T: Copy + PartialEq {
     /// The iterator over the components of this vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.iter().len(), 3);
+    /// assert_eq!(v.iter().rev().collect::<Vector<_>>(), vector![3, 2, 1]);
+    /// ```
     pub fn iter(&self) -> VectorIterator<'_, T> {
         // Using `'_` to let the compiler infer the correct, anonymous lifetime
-        VectorIterator { iterable: self, index: 0}
+        VectorIterator { components: self.components.iter() }
+    }
+
+    /// The iterator over mutable references to the components of this vector, which allows
+    /// modifying them in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// for c in v.iter_mut() {
+    ///     *c *= 10;
+    /// }
+    /// assert_eq!(v, vector![10, 20, 30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.components.iter_mut()
+    }
+
+    /// Create a new vector by applying the given function to each component of this vector,
+    /// which may also change the type of the components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.map(|x| x * x), vector![1, 4, 9]);
+    /// assert_eq!(v.map(|x| x as f64 / 2.0), vector![0.5, 1.0, 1.5]);
+    /// ```
+    pub fn map<U>(&self, f: impl Fn(T) -> U) -> Vector<U> where U: Copy + PartialEq {
+        self.iter().map(f).collect()
+    }
+
+    /// Create a new vector by combining the correspondent components of this vector and the
+    /// other vector by the given function, and it panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let prices = vector![10.0, 20.0, 30.0];
+    /// let quantities = vector![1, -2, 3];
+    /// assert_eq!(prices.zip_with(&quantities, |p, q| p * q as f64), vector![10.0, -40.0, 90.0]);
+    /// ```
+    pub fn zip_with<U, R>(&self, other: &Vector<U>, f: impl Fn(T, U) -> R) -> Vector<R>
+    where U: Copy + PartialEq, R: Copy + PartialEq {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        self.iter().zip(other.iter()).map(|(x, y)| f(x, y)).collect()
+    }
+}
+
+
+/// Iterate over the components of a vector by value (as [`Vector::iter`] does)
+impl<'a, T> IntoIterator for &'a Vector<T> where T: Copy + PartialEq {
+    type Item = T;
+    type IntoIter = VectorIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterate over mutable references to the components of a vector (as [`Vector::iter_mut`] does)
+impl<'a, T> IntoIterator for &'a mut Vector<T> where T: Copy + PartialEq {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Consume a vector, iterating over its components
+impl<T> IntoIterator for Vector<T> where T: Copy + PartialEq {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+
+/// Collect the items of an iterator into a new vector
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_macros::*;
+/// # use beaumont_linear_algebra::*;
+/// let v: Vector<u32> = (1..=3).collect();
+/// assert_eq!(v, vector![1, 2, 3]);
+/// ```
+impl<T> FromIterator<T> for Vector<T> where T: Copy + PartialEq {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let components: Vec<T> = iter.into_iter().collect();
+        Vector { components: components.into_boxed_slice() }
+    }
+}
+
+impl<'a, T> FromIterator<&'a T> for Vector<T> where T: Copy + PartialEq + 'a {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
     }
 }
 
 
-// TODO impl<'a, T> IntoIterator for &'a Vector<T> where T: Copy + PartialEq {
-//     type Item = &'a T;
-//     type IntoIter = VectorIterator<'a, &'a T>;
-//
-//     fn into_iter(self) -> Self::IntoIter {
-//         VectorIterator { iterable: self, index: 0}
-//     }
-// }
+/// Append the items of an iterator to a vector, which increases its dimension.
+///
+/// As the components are held in a fixed-size array, the vector is reallocated (so prefer
+/// collecting all the components at once, when possible).
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_macros::*;
+/// # use beaumont_linear_algebra::*;
+/// let mut v = vector![1, 2];
+/// v.extend([3, 4]);
+/// assert_eq!(v, vector![1, 2, 3, 4]);
+/// ```
+impl<T> Extend<T> for Vector<T> where T: Copy + PartialEq {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut components = core::mem::take(&mut self.components).into_vec();
+        components.extend(iter);
+        self.components = components.into_boxed_slice();
+    }
+}
+
+impl<'a, T> Extend<&'a T> for Vector<T> where T: Copy + PartialEq + 'a {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
 
 
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::{boxed::Box, vec::Vec};
 
     #[test]
     fn iterator() {
@@ -54,4 +197,83 @@ mod test {
             assert_eq!(i, c);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn iterator_exact_size_and_double_ended() {
+        let v = Vector { components: Box::new([1, 2, 3, 4]) };
+        let mut it = v.iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next_back(), Some(3));
+        assert_eq!(it.next(), Some(2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn iterator_mut() {
+        let mut v = Vector { components: Box::new([0, 1, 2]) };
+        v.iter_mut().for_each(|c| *c += 1);
+        assert_eq!(v.components[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn into_iterator() {
+        let mut v = Vector { components: Box::new([1, 2, 3]) };
+
+        let mut sum = 0;
+        for c in &v {
+            sum += c;
+        }
+        assert_eq!(sum, 6);
+
+        for c in &mut v {
+            *c *= 2;
+        }
+        assert_eq!(v.components[..], [2, 4, 6]);
+
+        let owned: Vec<i32> = v.into_iter().rev().collect();
+        assert_eq!(owned, [6, 4, 2]);
+    }
+
+    #[test]
+    fn from_iterator() {
+        let v: Vector<i32> = [1, 2, 3].iter().collect();
+        assert_eq!(v.components[..], [1, 2, 3]);
+
+        let empty: Vector<f64> = core::iter::empty::<f64>().collect();
+        assert_eq!(empty.dim(), 0);
+    }
+
+    #[test]
+    fn extend() {
+        let mut v = Vector { components: Box::new([1.5]) };
+        v.extend([2.5, 3.5]);
+        v.extend(&[4.5]);
+        assert_eq!(v.components[..], [1.5, 2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn map() {
+        let v = Vector { components: Box::new([1, -2, 3]) };
+        let w: Vector<bool> = v.map(|x| x > 0);
+        assert_eq!(w.components[..], [true, false, true]);
+    }
+
+    #[test]
+    fn zip_with() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1.5, 2.5, 3.5]) };
+        assert_eq!(v.zip_with(&w, |x, y| x as f64 + y).components[..], [2.5, 4.5, 6.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn zip_with_vectors_of_different_dimensions() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1, 2]) };
+        let _ = v.zip_with(&w, |x, y| x + y);
+    }
+}
@@ -4,4 +4,13 @@ mod idx;
 mod neg;
 mod scale;
 mod add;
-mod dot;
\ No newline at end of file
+mod sub;
+mod div;
+mod component;
+mod in_place;
+mod dot;
+mod norm;
+mod rounding;
+mod cross;
+mod stats;
+mod rounding_stats;
@@ -1,5 +1,5 @@
-use std::ops::Add;
-use crate::Dimension;
+use core::ops::{Add, AddAssign};
+use crate::{AsVectorView, Dimension, LinearAlgebraError};
 use super::super::Vector;
 
 impl<A> Vector<A> where A: Copy + PartialEq + Add<Output = A> {
@@ -35,26 +35,100 @@ impl<A> Vector<A> where A: Copy + PartialEq + Add<Output = A> {
     /// \end{pmatrix}
     /// $$
     ///
-    pub fn add(&self, rhs: &Self) -> Self {
-        if self.dim() != rhs.dim() {
-            panic!("must have the same dimension");
-        }
+    /// The other vector can be a view as well (see [`AsVectorView`]), and it panics if the vectors
+    /// have different dimensions (as the `+` operator applied to vector references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// let w = vector![4, 5, 6];
+    /// assert_eq!(Vector::add(&v, &w), vector![5, 7, 9]);
+    /// assert_eq!(&v + &w, vector![5, 7, 9]);
+    /// ```
+    pub fn add(&self, rhs: &impl AsVectorView<A>) -> Self {
+        self.view().add(rhs)
+    }
+
+    /// Same as the [`Vector::add`] method, but it returns an error (instead of panicking) if the
+    /// vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.try_add(&vector![4, 5, 6]), Ok(vector![5, 7, 9]));
+    ///
+    /// let err = v.try_add(&vector![4, 5, 6, 7, 8]).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::DimensionMismatch { expected: 3, found: 5 });
+    /// ```
+    pub fn try_add(&self, rhs: &impl AsVectorView<A>) -> Result<Self, LinearAlgebraError> {
+        self.check_dim(&rhs.as_view())?;
+        Ok(self.add(rhs))
+    }
+}
+
+
+impl<A> Add<&Vector<A>> for &Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+    type Output = Vector<A>;
+
+    /// Add this vector to the other vector (see [`Vector::add`]), and it panics if they have
+    /// different dimensions.
+    fn add(self, rhs: &Vector<A>) -> Self::Output {
+        self.view().add(rhs)
+    }
+}
+
+impl<A> Add<Vector<A>> for &Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+    type Output = Vector<A>;
+
+    fn add(self, rhs: Vector<A>) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<A> Add<&Vector<A>> for Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+    type Output = Vector<A>;
+
+    fn add(mut self, rhs: &Vector<A>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
 
-        let components: Vec<A> =
-            self.components.iter()
-                .zip(&rhs.components)
-                .map(|(x, y)| x.add(*y) )
-                .collect();
+impl<A> Add<Vector<A>> for Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+    type Output = Vector<A>;
 
-        Vector {
-            components: components.into_boxed_slice()
+    fn add(self, rhs: Vector<A>) -> Self::Output {
+        self + &rhs
+    }
+}
+
+
+impl<A> AddAssign<&Vector<A>> for Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+
+    /// Add the other vector to this vector (in place), and it panics if they have different
+    /// dimensions.
+    fn add_assign(&mut self, rhs: &Vector<A>) {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        for (x, y) in self.components.iter_mut().zip(&rhs.components) {
+            *x = *x + *y;
         }
     }
 }
 
+impl<A> AddAssign<Vector<A>> for Vector<A> where A: Copy + PartialEq + Add<Output = A> {
+    fn add_assign(&mut self, rhs: Vector<A>) {
+        *self += &rhs;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     #[should_panic(expected = "must have the same dimension")]
@@ -87,6 +161,39 @@ mod test {
         assert_eq!(v3.components[2], 9.999);
     }
 
+    #[test]
+    fn add_operator_owned_and_borrowed() {
+        let v1 = Vector::from([1, 2, 3]);
+        let v2 = Vector::from([4, 5, 6]);
+        let expected = Vector::from([5, 7, 9]);
+        assert_eq!(&v1 + &v2, expected);
+        assert_eq!(&v1 + Vector::from([4, 5, 6]), expected);
+        assert_eq!(Vector::from([1, 2, 3]) + &v2, expected);
+        assert_eq!(v1 + v2, expected);
+    }
+
+    #[test]
+    fn try_add() {
+        let v1 = Vector::from([1, 2, 3]);
+        assert_eq!(v1.try_add(&Vector::from([4, 5, 6])), Ok(Vector::from([5, 7, 9])));
+        assert_eq!(v1.try_add(&Vector::from([4])), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 1 }));
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut v = Vector::from([1.5, 2.5]);
+        v += &Vector::from([1.0, -1.0]);
+        v += Vector::from([0.5, 0.5]);
+        assert_eq!(v, Vector::from([3.0, 2.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn add_assign_vectors_of_different_dimensions() {
+        let mut v = Vector::from([1, 2, 3]);
+        v += Vector::from([1]);
+    }
+
 
     // #[test]
     // #[allow_decimals]
@@ -0,0 +1,112 @@
+use super::super::Vector;
+use crate::LinearAlgebraError;
+use core::ops::{Div, Mul};
+
+impl<T> Vector<T> where T: Copy + PartialEq {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Multiply this vector by the other vector, component by component (a.k.a. the "Hadamard
+    /// product"), and it panics if they have different dimensions.
+    ///
+    /// $$
+    /// \vec{v} \odot \vec{w}
+    /// \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// v_{1} w_{1} \\\\
+    /// v_{2} w_{2} \\\\
+    /// \dots \\\\
+    /// v_{n} w_{n}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::* ;
+    /// # use beaumont_linear_algebra::*;
+    /// let prices = vector![10, 20, 30];
+    /// let quantities = vector![1, -2, 3];
+    /// assert_eq!(prices.component_mul(&quantities), vector![10, -40, 90]);
+    /// ```
+    pub fn component_mul(&self, rhs: &Self) -> Self where T: Mul<Output = T> {
+        self.zip_with(rhs, |x, y| x * y)
+    }
+
+    /// Same as the [`Vector::component_mul`] method, but it returns an error (instead of
+    /// panicking) if the vectors have different dimensions.
+    pub fn try_component_mul(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> where T: Mul<Output = T> {
+        self.check_dim(rhs)?;
+        Ok(self.component_mul(rhs))
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Divide this vector by the other vector, component by component, and it panics if they
+    /// have different dimensions.
+    ///
+    /// $$
+    /// \vec{v} \oslash \vec{w}
+    /// \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// v_{1} / w_{1} \\\\
+    /// v_{2} / w_{2} \\\\
+    /// \dots \\\\
+    /// v_{n} / w_{n}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::* ;
+    /// # use beaumont_linear_algebra::*;
+    /// let values = vector![10.0, -40.0, 90.0];
+    /// let quantities = vector![1.0, -2.0, 3.0];
+    /// assert_eq!(values.component_div(&quantities), vector![10.0, 20.0, 30.0]);
+    /// ```
+    pub fn component_div(&self, rhs: &Self) -> Self where T: Div<Output = T> {
+        self.zip_with(rhs, |x, y| x / y)
+    }
+
+    /// Same as the [`Vector::component_div`] method, but it returns an error (instead of
+    /// panicking) if the vectors have different dimensions.
+    pub fn try_component_div(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> where T: Div<Output = T> {
+        self.check_dim(rhs)?;
+        Ok(self.component_div(rhs))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn component_mul() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([4, 5, 6]) };
+        assert_eq!(v.component_mul(&w), Vector { components: Box::new([4, 10, 18]) });
+    }
+
+    #[test]
+    fn component_div() {
+        let v = Vector { components: Box::new([4.0, 10.0, 18.0]) };
+        let w = Vector { components: Box::new([4.0, 5.0, 6.0]) };
+        assert_eq!(v.component_div(&w), Vector { components: Box::new([1.0, 2.0, 3.0]) });
+    }
+
+    #[test]
+    fn try_component_mul_and_div() {
+        let v = Vector { components: Box::new([4, 10]) };
+        let w = Vector { components: Box::new([2, 5, 1]) };
+        let err = LinearAlgebraError::DimensionMismatch { expected: 2, found: 3 };
+        assert_eq!(v.try_component_mul(&w), Err(err.clone()));
+        assert_eq!(v.try_component_div(&w), Err(err));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn component_mul_vectors_of_different_dimensions() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([4]) };
+        let _ = v.component_mul(&w);
+    }
+}
@@ -0,0 +1,105 @@
+use super::super::Vector;
+use crate::{Dimension, LinearAlgebraError};
+use core::ops::{Add, Mul, Neg};
+
+impl<T> Vector<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Neg<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the cross product of this and the other vector, which are required to be
+    /// tridimensional (and it panics otherwise).
+    ///
+    /// The result is a vector orthogonal to both vectors, with magnitude equal to the area of the
+    /// parallelogram they span:
+    ///
+    /// $$
+    /// \vec{v} \times \vec{w}
+    /// \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// v_{2} w_{3} - v_{3} w_{2} \\\\
+    /// v_{3} w_{1} - v_{1} w_{3} \\\\
+    /// v_{1} w_{2} - v_{2} w_{1}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let x = vector![1, 0, 0];
+    /// let y = vector![0, 1, 0];
+    /// assert_eq!(x.cross(&y), vector![0, 0, 1]);
+    /// assert_eq!(y.cross(&x), vector![0, 0, -1]);
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        assert_eq!(self.dim(), 3, "must be tridimensional");
+        assert_eq!(other.dim(), 3, "must be tridimensional");
+
+        let [v1, v2, v3] = [self.components[0], self.components[1], self.components[2]];
+        let [w1, w2, w3] = [other.components[0], other.components[1], other.components[2]];
+        Vector::from([
+            v2 * w3 + -(v3 * w2),
+            v3 * w1 + -(v1 * w3),
+            v1 * w2 + -(v2 * w1),
+        ])
+    }
+
+    /// Same as the [`Vector::cross`] method, but it returns an error (instead of panicking) if
+    /// either vector is not tridimensional.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let err = vector![1, 0].try_cross(&vector![0, 1]).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 });
+    /// ```
+    pub fn try_cross(&self, other: &Self) -> Result<Self, LinearAlgebraError> {
+        for v in [self, other] {
+            if v.dim() != 3 {
+                return Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: v.dim() });
+            }
+        }
+        Ok(self.cross(other))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn cross_is_orthogonal() {
+        let v = Vector { components: Box::new([1.0, 2.0, 3.0]) };
+        let w = Vector { components: Box::new([-4.0, 5.0, 0.5]) };
+        let c = v.cross(&w);
+        assert_eq!(c.components[..], [-14.0, -12.5, 13.0]);
+        assert_eq!(c.dot(&v), 0.0);
+        assert_eq!(c.dot(&w), 0.0);
+    }
+
+    #[test]
+    fn cross_of_decimals() {
+        let v = Vector { components: Box::new([Decimal::new(15, 1), Decimal::new(0, 0), Decimal::new(0, 0)]) };
+        let w = Vector { components: Box::new([Decimal::new(0, 0), Decimal::new(2, 0), Decimal::new(0, 0)]) };
+        assert_eq!(v.cross(&w).components[..], [Decimal::new(0, 0), Decimal::new(0, 0), Decimal::new(3, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be tridimensional")]
+    fn cross_of_non_tridimensional_vectors() {
+        let v = Vector { components: Box::new([1, 2, 3, 4]) };
+        let _ = v.cross(&v);
+    }
+
+    #[test]
+    fn try_cross() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1, 2]) };
+        assert_eq!(v.try_cross(&v), Ok(Vector::from([0, 0, 0])));
+        assert_eq!(v.try_cross(&w), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 }));
+    }
+}
@@ -0,0 +1,77 @@
+use super::super::Vector;
+use core::ops::{Div, DivAssign};
+
+impl<T> Div<T> for &Vector<T> where T: Copy + PartialEq + Div<Output = T> {
+    type Output = Vector<T>;
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Divide this vector by a scalar.
+    ///
+    /// Given a vector $\vec{v}$ in the n-dimensional space and a scalar $a$, the division
+    /// by the scalar is defined by dividing each component:
+    ///
+    /// $$
+    /// \frac{\vec{v}}{a} \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// v_{1} / a \\\\
+    /// v_{2} / a \\\\
+    /// \dots \\\\
+    /// v_{n} / a
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::* ;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 2.0, 3.0];
+    /// assert_eq!(&v / 2.0, vector![0.5, 1.0, 1.5]);
+    /// ```
+    fn div(self, scalar: T) -> Self::Output {
+        Vector {
+            components: self.components.iter().map(|x| *x / scalar).collect()
+        }
+    }
+}
+
+impl<T> Div<T> for Vector<T> where T: Copy + PartialEq + Div<Output = T> {
+    type Output = Vector<T>;
+
+    fn div(mut self, scalar: T) -> Self::Output {
+        self /= scalar;
+        self
+    }
+}
+
+impl<T> DivAssign<T> for Vector<T> where T: Copy + PartialEq + Div<Output = T> {
+
+    /// Divide this vector by a scalar (in place)
+    fn div_assign(&mut self, scalar: T) {
+        for x in self.components.iter_mut() {
+            *x = *x / scalar;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn div_operator_owned_and_borrowed() {
+        let v = Vector { components: Box::new([3.0, -4.5]) };
+        let expected = Vector { components: Box::new([1.0, -1.5]) };
+        assert_eq!(&v / 3.0, expected);
+        assert_eq!(v / 3.0, expected);
+    }
+
+    #[test]
+    fn div_assign() {
+        let mut v = Vector { components: Box::new([7, -9]) };
+        v /= 2;
+        assert_eq!(v.components[0], 3);
+        assert_eq!(v.components[1], -4);
+    }
+}
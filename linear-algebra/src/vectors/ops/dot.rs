@@ -1,6 +1,7 @@
 use super::super::Vector;
-use std::ops::{Add, Mul};
-use std::iter::Sum;
+use crate::{AsVectorView, LinearAlgebraError};
+use core::ops::{Add, Mul};
+use core::iter::Sum;
 
 impl<T> Vector<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T> + Sum<T> {
 
@@ -21,19 +22,36 @@ impl<T> Vector<T> where T: Copy + PartialEq + Add<Output = T> + Mul<Output = T>
     /// Its result is a scalar number that represents how much two vectors are similar to each other,
     /// with a value close to 1 meaning they are very similar.
     ///
-    pub fn dot(&self, other: &Vector<T>) -> T {
-        self.components
-            .iter()
-            .zip(&other.components)
-            .map(|(v, w)| v.mul(*w))
-            .sum()
+    /// The other vector can be a view as well (see [`AsVectorView`]), and it panics if the vectors
+    /// have different dimensions.
+    ///
+    pub fn dot(&self, other: &impl AsVectorView<T>) -> T {
+        self.view().dot(other)
+    }
 
+    /// Same as the [`Vector::dot`] method, but it returns an error (instead of panicking) if the
+    /// vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.try_dot(&vector![4, 5, 6]), Ok(32));
+    ///
+    /// let err = v.try_dot(&vector![4, 5, 6, 7, 8]).unwrap_err();
+    /// assert_eq!(err, LinearAlgebraError::DimensionMismatch { expected: 3, found: 5 });
+    /// ```
+    pub fn try_dot(&self, other: &impl AsVectorView<T>) -> Result<T, LinearAlgebraError> {
+        self.check_dim(&other.as_view())?;
+        Ok(self.dot(other))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     fn dot_product_arrays_of_integers() {
@@ -51,6 +69,44 @@ mod tests {
         assert_eq!(r, 32.0);
     }
 
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn dot_product_vectors_of_different_dimensions() {
+        // It used to silently truncate the longer vector
+        let u = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([4, 5, 6, 7, 8]) };
+        let _ = u.dot(&w);
+    }
+
+    #[test]
+    fn try_dot() {
+        let u = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([4, 5, 6, 7, 8]) };
+        assert_eq!(u.try_dot(&w), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 5 }));
+        assert_eq!(u.try_dot(&u), Ok(14));
+    }
+
+    #[test]
+    fn dot_product_vectors_of_duals() {
+        use beaumont_numbers::Dual;
+        // Differentiate the dot product with respect to the weight x
+        let x = Dual::variable(2.0);
+        let u = Vector { components: Box::new([x, x * x, Dual::constant(3.0)]) };
+        let w = Vector { components: Box::new([Dual::constant(4.0), Dual::constant(5.0), x]) };
+        let r = u.dot(&w);
+        assert_eq!(r.value(), 34.0);
+        assert_eq!(r.derivative(), 27.0);
+    }
+
+    #[test]
+    fn dot_product_vectors_of_complex() {
+        use beaumont_numbers::Complex;
+        let u = Vector { components: Box::new([Complex::new(1, 2), Complex::new(0, 1)]) };
+        let w = Vector { components: Box::new([Complex::new(3, -1), Complex::new(0, 1)]) };
+        let r = u.dot(&w);
+        assert_eq!(r, Complex::new(4, 5));
+    }
+
     // #[test]
     // #[allow_decimals]
     // TODO fn dot_product_vectors_of_decimals() {
@@ -14,7 +14,10 @@ impl<T> PartialEq for Vector<T> where T: Copy + PartialEq {
     /// &\vec{v} = \vec{w} \iff v_i = w_i \quad \forall i = 1, 2, \dots, n
     /// \end{aligned}
     /// $$
+    ///
+    /// Therefore, vectors of different dimensions are never equal.
     fn eq(&self, other: &Self) -> bool {
+        self.components.len() == other.components.len() &&
         self.components.iter()
             .zip(&other.components)
             .all(|(x, y)| x.eq(y) )
@@ -25,6 +28,7 @@ impl<T> PartialEq for Vector<T> where T: Copy + PartialEq {
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     fn eq_vectors_of_integers() {
@@ -37,4 +41,12 @@ mod test {
         let v = Vector { components: Box::new([1.0, 2.0, 3.0]) };
         assert_eq!(v, v);
     }
+
+    #[test]
+    fn ne_vectors_of_different_dimensions() {
+        let v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1, 2, 3, 4]) };
+        assert_ne!(v, w);
+        assert_ne!(w, v);
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 
-use core::ops::Index;
+use core::ops::{Index, IndexMut};
 use crate::Vector;
 
 impl<T> Index<u64> for Vector<T> where T: PartialEq + Copy {
@@ -19,13 +19,39 @@ impl<T> Index<u64> for Vector<T> where T: PartialEq + Copy {
     }
 }
 
+impl<T> IndexMut<u64> for Vector<T> where T: PartialEq + Copy {
+
+    /// Write the component at the given index, with it starting from 1 (not 0).
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v[2] = 5;
+    /// assert_eq!(v, vector![1, 5, 3]);
+    /// ```
+    fn index_mut(&mut self, index: u64) -> &mut Self::Output {
+        &mut (self.components[(index - 1) as usize])
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     fn idx() {
         let v = Vector { components: Box::new([1, 2, 3, 4, 5, 6]) };
         assert_eq!(v[3], 3);
     }
+
+    #[test]
+    fn idx_mut() {
+        let mut v = Vector { components: Box::new([1, 2, 3]) };
+        v[1] += 10;
+        v[3] = 0;
+        assert_eq!(v.components[..], [11, 2, 0]);
+    }
 }
\ No newline at end of file
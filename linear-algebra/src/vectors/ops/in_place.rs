@@ -0,0 +1,124 @@
+use super::super::Vector;
+use crate::AsVectorView;
+use core::ops::{Add, Mul, Neg};
+
+// The following operations modify the components of a vector in place, and therefore they never
+// allocate (which matters in hot loops, such as updating rolling windows on every tick).
+
+impl<T> Vector<T> where T: Copy + PartialEq {
+
+    /// Set all the components of this vector to the given value
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v.fill(0);
+    /// assert_eq!(v, vector![0, 0, 0]);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        self.components.fill(value);
+    }
+
+    /// Replace each component of this vector with the result of the given function applied to it
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v.map_in_place(|x| x * x + 1);
+    /// assert_eq!(v, vector![2, 5, 10]);
+    /// ```
+    pub fn map_in_place(&mut self, f: impl Fn(T) -> T) {
+        for x in self.components.iter_mut() {
+            *x = f(*x);
+        }
+    }
+
+    /// Same as the [`Vector::add`] method, but it adds the other vector (or view) to this vector in
+    /// place (as the `+=` operator does), and it panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v.add_in_place(&vector![4, 5, 6]);
+    /// assert_eq!(v, vector![5, 7, 9]);
+    /// ```
+    pub fn add_in_place(&mut self, rhs: &impl AsVectorView<T>) where T: Add<Output = T> {
+        self.view_mut().add_in_place(rhs);
+    }
+
+    /// Same as the [`Vector::scale_by`] method, but it multiplies this vector by a scalar in place
+    /// (as the `*=` operator does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, 2, 3];
+    /// v.scale_by_in_place(2);
+    /// assert_eq!(v, vector![2, 4, 6]);
+    /// ```
+    pub fn scale_by_in_place(&mut self, scalar: T) where T: Mul<Output = T> {
+        *self *= scalar;
+    }
+
+    /// Same as the [`Vector::neg`] method, but it negates this vector in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut v = vector![1, -2, 3];
+    /// v.neg_in_place();
+    /// assert_eq!(v, vector![-1, 2, -3]);
+    /// ```
+    pub fn neg_in_place(&mut self) where T: Neg<Output = T> {
+        self.map_in_place(|x| -x);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn fill() {
+        let mut v = Vector { components: Box::new([1.5, 2.5]) };
+        v.fill(0.0);
+        assert_eq!(v.components[..], [0.0, 0.0]);
+    }
+
+    #[test]
+    fn map_in_place() {
+        let mut v = Vector { components: Box::new([1, -2, 3]) };
+        v.map_in_place(|x: i32| x.abs() * 10);
+        assert_eq!(v.components[..], [10, 20, 30]);
+    }
+
+    #[test]
+    fn in_place_ops_keep_the_same_buffer() {
+        let mut v = Vector { components: Box::new([1, 2, 3]) };
+        let w = Vector { components: Box::new([1, 1, 1]) };
+        let buffer = v.as_slice().as_ptr();
+
+        v.add_in_place(&w);
+        v.scale_by_in_place(3);
+        v.neg_in_place();
+        assert_eq!(v.components[..], [-6, -9, -12]);
+        assert_eq!(v.as_slice().as_ptr(), buffer);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn add_in_place_vectors_of_different_dimensions() {
+        let mut v = Vector { components: Box::new([1, 2, 3]) };
+        v.add_in_place(&Vector { components: Box::new([1]) });
+    }
+}
@@ -19,27 +19,48 @@ impl<A> Vector<A> where A: Copy + PartialEq + Neg<Output = A> {
     /// \end{pmatrix}
     /// $$
     ///
+    /// This method is a thin wrapper around the `-` (unary) operator applied to a vector reference.
+    ///
     /// # Examples
     /// ```rust
     /// # use beaumont_macros::*;
     /// # use beaumont_linear_algebra::*;
     /// let v = vector![1, 2, 3];
-    /// let negated = v.neg();
-    /// assert_eq!(negated, vector![-1, -2, -3]);
+    /// assert_eq!(Vector::neg(&v), vector![-1, -2, -3]);
+    /// assert_eq!(-&v, vector![-1, -2, -3]);
     /// ```
-    ///
     pub fn neg(&self) -> Vector<A> {
+        -self
+    }
+}
+
+
+impl<A> Neg for &Vector<A> where A: Copy + PartialEq + Neg<Output = A> {
+    type Output = Vector<A>;
+
+    /// Negate this vector (see [`Vector::neg`])
+    fn neg(self) -> Self::Output {
         Vector {
-            components: self.components.iter().map(|x| x.neg()).collect()
+            components: self.components.iter().map(|x| -*x).collect()
         }
     }
 }
 
+impl<A> Neg for Vector<A> where A: Copy + PartialEq + Neg<Output = A> {
+    type Output = Vector<A>;
+
+    fn neg(mut self) -> Self::Output {
+        self.neg_in_place();
+        self
+    }
+}
+
 
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
     use beaumont_macros::*;
     use beaumont_numbers::*;
 
@@ -73,4 +94,11 @@ mod test {
         assert_eq!(negated.components[1], -2.22d);
         assert_eq!(negated.components[2], -3.333d);
     }
+
+    #[test]
+    fn neg_operator_owned_and_borrowed() {
+        let v = Vector::from([1, -2, 3]);
+        assert_eq!(-&v, Vector::from([-1, 2, -3]));
+        assert_eq!(-v, Vector::from([-1, 2, -3]));
+    }
 }
@@ -0,0 +1,292 @@
+use super::super::Vector;
+use core::ops::{Add, Neg};
+#[cfg(feature = "std")]
+use beaumont_numbers::Real;
+
+impl<T> Vector<T> where T: Copy + PartialOrd + Default + Add<Output = T> + Neg<Output = T> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the L1 norm (a.k.a. the "Manhattan" norm) of this vector, that is the sum of the
+    /// absolute values of its components.
+    ///
+    /// $$
+    /// \\|\vec{v}\\|_1 \quad \equiv \quad \sum_{i=1}^n |v_{i}|
+    /// $$
+    ///
+    /// Being computed exactly, it's available for integers and decimals too (and it panics in
+    /// case of overflow, as the `+` operator does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, -2, 3];
+    /// assert_eq!(v.norm_l1(), 6);
+    /// ```
+    pub fn norm_l1(&self) -> T {
+        self.iter().fold(T::default(), |sum, x| sum + abs(x))
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the L∞ norm (a.k.a. the "maximum" norm) of this vector, that is the greatest of
+    /// the absolute values of its components (or zero, if it has no components).
+    ///
+    /// $$
+    /// \\|\vec{v}\\|_\infty \quad \equiv \quad \max_{i} |v_{i}|
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, -5, 3];
+    /// assert_eq!(v.norm_inf(), 5);
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        self.iter().map(abs).fold(T::default(), |max, x| if x > max { x } else { max })
+    }
+}
+
+// The absolute value of a scalar (as long as its default value is zero)
+fn abs<T>(x: T) -> T where T: PartialOrd + Default + Neg<Output = T> {
+    if x < T::default() { -x } else { x }
+}
+
+
+// The following operations require elementary functions (such as square roots), therefore they
+// are available for floats (when the `std` feature is enabled). See the `rounding_*` methods for
+// their decimal counterparts.
+#[cfg(feature = "std")]
+impl<T> Vector<T> where T: Real {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the L2 norm (a.k.a. the "Euclidean" norm, or the magnitude) of this vector.
+    ///
+    /// $$
+    /// \\|\vec{v}\\|_2 \quad \equiv \quad \sqrt{\vec{v} \cdot \vec{v}}
+    /// \quad \equiv \quad \sqrt{\sum_{i=1}^n v_{i}^2}
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![3.0, -4.0];
+    /// assert_eq!(v.norm_l2(), 5.0);
+    /// ```
+    pub fn norm_l2(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Create the unit vector (with L2 norm equal to one) having the same direction of this
+    /// vector, or `None` if this vector is the zero vector.
+    ///
+    /// $$
+    /// \hat{v} \quad \equiv \quad \frac{\vec{v}}{\\|\vec{v}\\|}
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![3.0, -4.0];
+    /// assert_eq!(v.normalize(), Some(vector![0.6, -0.8]));
+    /// assert_eq!(vector![0.0, 0.0].normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm_l2();
+        (norm != T::default()).then(|| self.map(|x| x / norm))
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the Euclidean distance between this and the other vector (such as the tracking
+    /// error of a portfolio against its benchmark), and it panics if they have different
+    /// dimensions.
+    ///
+    /// $$
+    /// d(\vec{v}, \vec{w}) \quad \equiv \quad \\|\vec{v} - \vec{w}\\|_2
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 2.0, 3.0];
+    /// let w = vector![4.0, 6.0, 3.0];
+    /// assert_eq!(v.distance(&w), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Self) -> T {
+        (self - other).norm_l2()
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the cosine of the angle between this and the other vector, which tells how much
+    /// they are similar to each other (from -1 for opposite directions, to 1 for the same
+    /// direction), regardless of their magnitudes.
+    ///
+    /// $$
+    /// \cos \theta \quad \equiv \quad \frac{\vec{v} \cdot \vec{w}}{\\|\vec{v}\\| \\|\vec{w}\\|}
+    /// $$
+    ///
+    /// It returns `None` if either vector is the zero vector (as the angle is not defined), and it
+    /// panics if they have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 1.0];
+    /// assert_eq!(v.cosine_similarity(&vector![2.0, 2.0]), Some(1.0));
+    /// assert_eq!(v.cosine_similarity(&vector![1.0, -1.0]), Some(0.0));
+    /// assert_eq!(v.cosine_similarity(&vector![0.0, 0.0]), None);
+    /// ```
+    pub fn cosine_similarity(&self, other: &Self) -> Option<T> {
+        // Taking a single square root (of the product of the squared norms) is more accurate
+        let dot = self.dot(other);
+        let norms = (self.dot(self) * other.dot(other)).sqrt();
+        if norms == T::default() {
+            return None;
+        }
+
+        // Rounding errors could make the cosine fall (slightly) outside of its range
+        let one = T::from_f64(1.0);
+        let cosine = dot / norms;
+        Some(if cosine > one { one } else if cosine < -one { -one } else { cosine })
+    }
+
+    /// Compute the angle between this and the other vector (in radians, from zero to $\pi$).
+    ///
+    /// It returns `None` if either vector is the zero vector, and it panics if they have
+    /// different dimensions (see [`Vector::cosine_similarity`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let v = vector![1.0, 0.0];
+    /// assert_eq!(v.angle_between(&vector![0.0, 3.0]), Some(FRAC_PI_2));
+    /// ```
+    pub fn angle_between(&self, other: &Self) -> Option<T> {
+        self.cosine_similarity(other).map(T::acos)
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the projection of this vector onto the other vector (such as the exposure of a
+    /// portfolio to a factor).
+    ///
+    /// $$
+    /// \mathrm{proj}_{\vec{w}} \vec{v} \quad \equiv \quad
+    /// \frac{\vec{v} \cdot \vec{w}}{\vec{w} \cdot \vec{w}} \vec{w}
+    /// $$
+    ///
+    /// It returns `None` if the other vector is the zero vector, and it panics if they have
+    /// different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![2.0, 3.0];
+    /// assert_eq!(v.project_onto(&vector![4.0, 0.0]), Some(vector![2.0, 0.0]));
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Option<Self> {
+        let squared_norm = other.dot(other);
+        if squared_norm == T::default() {
+            return None;
+        }
+        let factor = self.dot(other) / squared_norm;
+        Some(other.map(|x| x * factor))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+    use beaumont_numbers::Decimal;
+
+    #[test]
+    fn norm_l1() {
+        let v = Vector { components: Box::new([1.5, -2.5, 0.0]) };
+        assert_eq!(v.norm_l1(), 4.0);
+
+        let v = Vector { components: Box::new([Decimal::new(-150, 2), Decimal::new(25, 1)]) };
+        assert_eq!(v.norm_l1(), Decimal::new(4, 0));
+    }
+
+    #[test]
+    fn norm_inf() {
+        let v = Vector { components: Box::new([1, -7, 3]) };
+        assert_eq!(v.norm_inf(), 7);
+
+        let v = Vector { components: Box::new([Decimal::new(-150, 2), Decimal::new(1499, 3)]) };
+        assert_eq!(v.norm_inf(), Decimal::new(15, 1));
+    }
+
+    #[test]
+    fn norms_of_empty_vector() {
+        let v: Vector<i64> = Vector { components: Box::new([]) };
+        assert_eq!(v.norm_l1(), 0);
+        assert_eq!(v.norm_inf(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    mod float {
+        use super::*;
+        use core::f64::consts::PI;
+
+        #[test]
+        fn norm_l2() {
+            let v = Vector { components: Box::new([1.0f32, 2.0, 2.0]) };
+            assert_eq!(v.norm_l2(), 3.0);
+        }
+
+        #[test]
+        fn normalize() {
+            let v = Vector { components: Box::new([0.0, 2.0, 0.0]) };
+            assert_eq!(v.normalize().unwrap().components[..], [0.0, 1.0, 0.0]);
+
+            let v = Vector { components: Box::new([1.0f64, 1.0, 1.0, 1.0]) };
+            assert!((v.normalize().unwrap().norm_l2() - 1.0).abs() < 1e-15);
+        }
+
+        #[test]
+        #[should_panic(expected = "must have the same dimension")]
+        fn distance_of_vectors_of_different_dimensions() {
+            let v = Vector { components: Box::new([1.0, 2.0]) };
+            let _ = v.distance(&Vector { components: Box::new([1.0]) });
+        }
+
+        #[test]
+        fn cosine_similarity_is_clamped() {
+            let v = Vector { components: Box::new([0.1, 0.2, 0.3]) };
+            let w = v.map(|x| x * 3.0);
+            let cosine = v.cosine_similarity(&w).unwrap();
+            assert!(cosine <= 1.0);
+            assert_eq!(v.angle_between(&w).unwrap(), cosine.acos());
+        }
+
+        #[test]
+        fn angle_between_opposite_vectors() {
+            let v = Vector { components: Box::new([1.0, -2.0]) };
+            let w = Vector { components: Box::new([-2.0, 4.0]) };
+            assert!((v.angle_between(&w).unwrap() - PI).abs() < 1e-7);
+        }
+
+        #[test]
+        fn project_onto() {
+            let v = Vector { components: Box::new([1.0, 2.0, 3.0]) };
+            let w = Vector { components: Box::new([1.0, 1.0, 0.0]) };
+            let p = v.project_onto(&w).unwrap();
+            assert_eq!(p.components[..], [1.5, 1.5, 0.0]);
+            // The rejection is orthogonal to the other vector
+            assert_eq!((&v - &p).dot(&w), 0.0);
+
+            assert!(v.project_onto(&Vector { components: Box::new([0.0, 0.0, 0.0]) }).is_none());
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use super::super::Vector;
+use crate::Dimension;
+use alloc::vec::Vec;
+use beaumont_numbers::{Decimal, RoundingMode};
+
+// The following operations are the decimal counterparts of the ones requiring elementary functions
+// for floats (such as square roots and divisions), which are rounded according to a rounding mode.
+// As for the decimal numbers, they return `None` (instead of panicking) if their results cannot be
+// represented. The angle between vectors is not available, as it is not a decimal quantity.
+
+impl Vector<Decimal> {
+
+    /// Same as the [`Vector::norm_l2`] method, but for decimals: the sum of the squares of the
+    /// components is computed exactly, and only its square root is rounded according to the
+    /// given rounding mode (see [`Decimal::rounding_norm`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let v = vector![3d, -4d];
+    /// assert_eq!(v.rounding_norm_l2(&rm), Some(5d));
+    /// # }
+    /// ```
+    pub fn rounding_norm_l2(&self, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        Decimal::rounding_norm(self.as_slice(), rounding_mode)
+    }
+
+    /// Same as the [`Vector::normalize`] method, but for decimals: each component is divided by
+    /// the (unrounded) L2 norm, and only the quotient is rounded according to the given rounding
+    /// mode (see [`Decimal::rounding_normalize`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let v = vector![1d, 1d];
+    /// assert_eq!(v.rounding_normalize(&rm), Some(vector![0.70710678d, 0.70710678d]));
+    /// # }
+    /// ```
+    pub fn rounding_normalize(&self, rounding_mode: &RoundingMode) -> Option<Self> {
+        Decimal::rounding_normalize(self.as_slice(), rounding_mode).map(|unit| unit.into_iter().collect())
+    }
+
+    /// Same as the [`Vector::distance`] method, but for decimals: the differences of the
+    /// components are computed exactly, and only the distance is rounded according to the given
+    /// rounding mode.
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let portfolio = vector![0.012d, -0.003d, 0.021d];
+    /// let benchmark = vector![0.010d, -0.001d, 0.020d];
+    /// assert_eq!(portfolio.rounding_distance(&benchmark, &rm), Some(0.003d));
+    /// # }
+    /// ```
+    pub fn rounding_distance(&self, other: &Self, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        let differences: Vec<Decimal> =
+            self.iter()
+                .zip(other.iter())
+                .map(|(x, y)| y.checked_neg().and_then(|y| x.checked_add(y)))
+                .collect::<Option<_>>()?;
+        Decimal::rounding_norm(&differences, rounding_mode)
+    }
+
+    /// Same as the [`Vector::cosine_similarity`] method, but for decimals: the dot product and the
+    /// L2 norms are computed exactly, and only their ratio is rounded according to the given
+    /// rounding mode (see [`Decimal::rounding_cosine_similarity`]).
+    ///
+    /// It returns `None` if either vector is the zero vector, and it panics if they have different
+    /// dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let value = vector![0.5d, 0.3d, -0.2d];
+    /// let momentum = vector![0.4d, 0.4d, -0.1d];
+    /// assert_eq!(value.rounding_cosine_similarity(&momentum, &rm).unwrap().to_string(), "0.96013024");
+    /// # }
+    /// ```
+    pub fn rounding_cosine_similarity(&self, other: &Self, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        Decimal::rounding_cosine_similarity(self.as_slice(), other.as_slice(), rounding_mode)
+    }
+
+    /// Same as the [`Vector::project_onto`] method, but for decimals: the dot products are
+    /// computed exactly, their ratio is rounded, and so is each component of the projection.
+    ///
+    /// It returns `None` if the other vector is the zero vector, and it panics if they have
+    /// different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let v = vector![2d, 3d];
+    /// assert_eq!(v.rounding_project_onto(&vector![1d, 1d], &rm), Some(vector![2.5d, 2.5d]));
+    /// # }
+    /// ```
+    pub fn rounding_project_onto(&self, other: &Self, rounding_mode: &RoundingMode) -> Option<Self> {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        let dot = Decimal::dot(self.as_slice(), other.as_slice()).ok()?;
+        let squared_norm = Decimal::dot(other.as_slice(), other.as_slice()).ok()?;
+        let factor = dot.rounding_div(squared_norm, rounding_mode)?;
+        other.iter().map(|x| x.rounding_mul(factor, rounding_mode)).collect()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use beaumont_macros::*;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_norm_l2() {
+        // √(1 + 1 + 1) = 1.7320508075...
+        let v = vector![1d, -1d, 1d];
+        assert_eq!(v.rounding_norm_l2(&RM), Some(Decimal::new(173205081, 8)));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_normalize_zero_vector() {
+        let v = vector![0.00d, 0.00d];
+        assert_eq!(v.rounding_normalize(&RM), None);
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_normalize_small_magnitudes() {
+        // The L2 norm 0.0000000223606... would be rounded to 0.00000002
+        let v = vector![0.00000001d, 0.00000002d];
+        assert_eq!(v.rounding_normalize(&RM), Some(vector![0.4472136d, 0.89442719d]));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_distance_overflow() {
+        let v = vector![2147483647d];
+        let w = vector![-1d];
+        assert_eq!(v.rounding_distance(&w, &RM), None);
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_cosine_similarity() {
+        let v = vector![0.3d, 0.3d];
+        assert_eq!(v.rounding_cosine_similarity(&v, &RM), Some(Decimal::new(1, 0)));
+
+        let w = vector![-0.3d, -0.3d];
+        assert_eq!(v.rounding_cosine_similarity(&w, &RM), Some(Decimal::new(-1, 0)));
+
+        let zero = vector![0d, 0d];
+        assert_eq!(v.rounding_cosine_similarity(&zero, &RM), None);
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_cosine_similarity_small_magnitudes() {
+        let v = vector![0.0001d, 0.0001d];
+        let w = vector![0.0001d, 0.0000d];
+        assert_eq!(v.rounding_cosine_similarity(&w, &RM), Some(Decimal::new(70710678, 8)));
+
+        let tiny = vector![0.00000001d, 0.00000002d];
+        assert_eq!(tiny.rounding_cosine_similarity(&tiny, &RM), Some(Decimal::new(1, 0)));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_project_onto() {
+        // Projecting onto (3, 0, 0) with a repeating factor: 1/3 = 0.33333333
+        let v = vector![1d, 5d, 7d];
+        let w = vector![3d, 0d, 0d];
+        let p = v.rounding_project_onto(&w, &RM).unwrap();
+        assert_eq!(p, vector![0.99999999d, 0d, 0d]);
+    }
+
+    #[test]
+    #[allow_decimals]
+    #[should_panic(expected = "must have the same dimension")]
+    fn rounding_project_onto_vectors_of_different_dimensions() {
+        let v = vector![1d, 2d];
+        let _ = v.rounding_project_onto(&vector![1d], &RM);
+    }
+}
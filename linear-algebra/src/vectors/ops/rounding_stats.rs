@@ -0,0 +1,306 @@
+use super::super::Vector;
+use crate::{Estimator, Interpolation};
+use alloc::vec::Vec;
+use beaumont_numbers::{Decimal, RoundingMode};
+
+// The following statistics are the decimal counterparts of the ones requiring divisions (and
+// square roots) for floats, which are rounded according to a rounding mode. The sum, the extrema
+// and the cumulative sums are exact, therefore they are available for decimals as they are.
+
+impl Vector<Decimal> {
+
+    /// Same as the [`Vector::mean`] method, but for decimals: the sum of the components is
+    /// computed exactly, and only the mean is rounded according to the given rounding mode (see
+    /// [`Decimal::rounding_mean`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = vector![0.01d, -0.02d, 0.04d];
+    /// assert_eq!(returns.rounding_mean(&rm), Some(0.01d));
+    /// # }
+    /// ```
+    pub fn rounding_mean(&self, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        Decimal::rounding_mean(self.as_slice(), rounding_mode)
+    }
+
+    /// Same as the [`Vector::variance`] method, but for decimals: the variance is computed
+    /// exactly (so that it doesn't need Welford's algorithm), and only then rounded according to
+    /// the given rounding mode (see [`Decimal::rounding_variance`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = vector![0.01d, -0.02d, 0.04d];
+    /// assert_eq!(returns.rounding_variance(Estimator::Population, &rm), Some(0.0006d));
+    /// assert_eq!(returns.rounding_variance(Estimator::Sample, &rm), Some(0.0009d));
+    /// # }
+    /// ```
+    pub fn rounding_variance(&self, estimator: Estimator, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        Decimal::rounding_variance(self.as_slice(), estimator.ddof(), rounding_mode)
+    }
+
+    /// Same as the [`Vector::std_dev`] method, but for decimals: the variance is computed exactly,
+    /// and only its square root is rounded according to the given rounding mode (see
+    /// [`Decimal::rounding_std_dev`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = vector![0.01d, -0.02d, 0.04d];
+    /// assert_eq!(returns.rounding_std_dev(Estimator::Sample, &rm), Some(0.03d));
+    /// # }
+    /// ```
+    pub fn rounding_std_dev(&self, estimator: Estimator, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        Decimal::rounding_std_dev(self.as_slice(), estimator.ddof(), rounding_mode)
+    }
+
+    /// Same as the [`Vector::quantile`] method, but for decimals: the interpolation between the
+    /// two nearest components is rounded according to the given rounding mode.
+    ///
+    /// It returns `None` if this vector has no components, if the quantile is out of range, or
+    /// if the interpolation cannot be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = vector![0.03d, -0.01d, 0.02d, -0.04d, 0.01d];
+    /// assert_eq!(returns.rounding_quantile(0.1d, Interpolation::Linear, &rm), Some(-0.028d));
+    /// assert_eq!(returns.rounding_quantile(0.1d, Interpolation::Lower, &rm), Some(-0.04d));
+    /// # }
+    /// ```
+    pub fn rounding_quantile(&self, q: Decimal, interpolation: Interpolation, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let (zero, one) = (Decimal::default(), Decimal::from(1));
+        if self.components.is_empty() || q < zero || q > one {
+            return None;
+        }
+        let mut sorted: Vec<Decimal> = self.iter().collect();
+        sorted.sort();
+
+        // The (fractional) position of the quantile, starting from 0 (whose integer part is not
+        // affected by the conversion into a float, given that decimals have few digits)
+        let last = sorted.len() - 1;
+        let h = Decimal::from(i32::try_from(last).ok()?).checked_mul(q)?;
+        let lower = (h.to_f64() as usize).min(last);
+        let fraction = h.checked_add(-Decimal::from(lower as i32))?;
+        let (a, b) = (sorted[lower], sorted[(lower + 1).min(last)]);
+
+        match interpolation {
+            Interpolation::Linear => {
+                let delta = b.checked_add(a.checked_neg()?)?;
+                a.checked_add(delta.rounding_mul(fraction, rounding_mode)?)
+            }
+            Interpolation::Lower => Some(a),
+            Interpolation::Higher => Some(if fraction > zero { b } else { a }),
+            Interpolation::Nearest => Some(if fraction > Decimal::new(5, 1) { b } else { a }),
+            Interpolation::Midpoint =>
+                if fraction > zero { Decimal::rounding_mean(&[a, b], rounding_mode) } else { Some(a) },
+        }
+    }
+
+    /// Same as the [`Vector::median`] method, but for decimals: the mean of the two middle
+    /// components (if they are even) is rounded according to the given rounding mode.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// assert_eq!(vector![0.03d, -0.01d, 0.02d].rounding_median(&rm), Some(0.02d));
+    /// assert_eq!(vector![0.03d, -0.01d, 0.02d, 0.01d].rounding_median(&rm), Some(0.015d));
+    /// # }
+    /// ```
+    pub fn rounding_median(&self, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        self.rounding_quantile(Decimal::new(5, 1), Interpolation::Linear, rounding_mode)
+    }
+
+    /// Same as the [`Vector::cumprod`] method, but for decimals: each cumulative product is
+    /// rounded according to the given rounding mode (as in [`Decimal::rounding_mul`]), rather than
+    /// overflowing the scaling factor.
+    ///
+    /// It returns `None` if any cumulative product cannot be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let gross_returns = vector![1.0123d, 0.9876d, 1.0345d];
+    /// let growth = gross_returns.rounding_cumprod(&rm).unwrap();
+    /// assert_eq!(growth, vector![1.0123d, 0.99974748d, 1.03423877d]);
+    /// # }
+    /// ```
+    pub fn rounding_cumprod(&self, rounding_mode: &RoundingMode) -> Option<Self> {
+        let mut product: Option<Decimal> = None;
+        self.iter()
+            .map(|x| {
+                let p = match product {
+                    Some(p) => p.rounding_mul(x, rounding_mode)?,
+                    None => x,
+                };
+                product = Some(p);
+                Some(p)
+            })
+            .collect()
+    }
+
+    /// Same as the [`Vector::skewness`] method, but for decimals. Being a dimensionless measure of
+    /// shape (rather than an amount), it's computed with floats from the components, and then
+    /// rounded according to the given rounding mode.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let v = vector![1d, 1d, 1d, 5d];
+    /// assert_eq!(v.rounding_skewness(Estimator::Population, &rm), Some(1.15470054d));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rounding_skewness(&self, estimator: Estimator, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let floats: Vector<f64> = self.iter().map(Decimal::to_f64).collect();
+        Decimal::rounding_from_f64(floats.skewness(estimator)?, rounding_mode)
+    }
+
+    /// Same as the [`Vector::kurtosis`] method, but for decimals. Being a dimensionless measure of
+    /// shape (rather than an amount), it's computed with floats from the components, and then
+    /// rounded according to the given rounding mode.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// # use beaumont_numbers::*;
+    /// # #[allow_decimals]
+    /// # fn main() {
+    /// let rm = RoundingMode::HalfUp;
+    /// let v = vector![-1d, 1d, -1d, 1d];
+    /// assert_eq!(v.rounding_kurtosis(Estimator::Sample, &rm), Some(-6d));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rounding_kurtosis(&self, estimator: Estimator, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let floats: Vector<f64> = self.iter().map(Decimal::to_f64).collect();
+        Decimal::rounding_from_f64(floats.kurtosis(estimator)?, rounding_mode)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use beaumont_macros::*;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    #[allow_decimals]
+    fn exact_statistics() {
+        let v = vector![1.05d, -0.2d, 3d];
+        assert_eq!(v.sum(), Decimal::new(385, 2));
+        assert_eq!(v.cumsum(), vector![1.05d, 0.85d, 3.85d]);
+        assert_eq!((v.min(), v.max()), (Some(Decimal::new(-2, 1)), Some(Decimal::new(3, 0))));
+        assert_eq!((v.argmin(), v.argmax()), (Some(2), Some(3)));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_mean_and_variance() {
+        let v = vector![2d, 4d, 4d, 4d, 5d, 5d, 7d, 9d];
+        assert_eq!(v.rounding_mean(&RM), Some(Decimal::new(5, 0)));
+        assert_eq!(v.rounding_variance(Estimator::Population, &RM), Some(Decimal::new(4, 0)));
+        assert_eq!(v.rounding_std_dev(Estimator::Population, &RM), Some(Decimal::new(2, 0)));
+        assert_eq!(v.rounding_variance(Estimator::Sample, &RM), Some(Decimal::new(457142857, 8)));
+
+        let empty = Vector::<Decimal>::from([]);
+        assert_eq!(empty.rounding_mean(&RM), None);
+        assert_eq!(empty.rounding_std_dev(Estimator::Population, &RM), None);
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_quantile() {
+        let v = vector![10d, 40d, 20d, 30d, 50d];
+        let q = Decimal::new(9, 1);
+        assert_eq!(v.rounding_quantile(q, Interpolation::Linear, &RM), Some(Decimal::new(46, 0)));
+        assert_eq!(v.rounding_quantile(q, Interpolation::Lower, &RM), Some(Decimal::new(40, 0)));
+        assert_eq!(v.rounding_quantile(q, Interpolation::Higher, &RM), Some(Decimal::new(50, 0)));
+        assert_eq!(v.rounding_quantile(q, Interpolation::Nearest, &RM), Some(Decimal::new(50, 0)));
+        assert_eq!(v.rounding_quantile(q, Interpolation::Midpoint, &RM), Some(Decimal::new(45, 0)));
+        assert_eq!(v.rounding_quantile(Decimal::new(1, 0), Interpolation::Linear, &RM), Some(Decimal::new(50, 0)));
+        assert_eq!(v.rounding_median(&RM), Some(Decimal::new(30, 0)));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_quantile_rounded() {
+        // The quantile 1/3 falls at 2/3 of the way between the first two components
+        let v = vector![0d, 1d, 2d, 3d];
+        let q = Decimal::new(33333333, 8);
+        let quantile = v.rounding_quantile(q, Interpolation::Linear, &RM).unwrap();
+        assert_eq!(quantile, Decimal::new(99999999, 8));
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_quantile_undefined() {
+        assert_eq!(Vector::<Decimal>::from([]).rounding_median(&RM), None);
+        let v = vector![1d, 2d];
+        assert_eq!(v.rounding_quantile(Decimal::new(11, 1), Interpolation::Linear, &RM), None);
+        assert_eq!(v.rounding_quantile(Decimal::new(-1, 8), Interpolation::Linear, &RM), None);
+    }
+
+    #[test]
+    #[allow_decimals]
+    fn rounding_cumprod() {
+        // The exact products would overflow the scaling factor
+        let v = vector![1.1d, 1.01d, 1.001d, 1.0001d];
+        let growth = v.rounding_cumprod(&RM).unwrap();
+        assert_eq!(growth, vector![1.1d, 1.111d, 1.112111d, 1.11222221d]);
+        assert_eq!(Vector::<Decimal>::from([]).rounding_cumprod(&RM), Some(Vector::<Decimal>::from([])));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow_decimals]
+    fn rounding_skewness_and_kurtosis() {
+        let v = vector![-1d, 1d, -1d, 1d];
+        assert_eq!(v.rounding_skewness(Estimator::Sample, &RM), Some(Decimal::new(0, 0)));
+        assert_eq!(v.rounding_kurtosis(Estimator::Population, &RM), Some(Decimal::new(-2, 0)));
+
+        let v = vector![0.5d, 0.5d];
+        assert_eq!(v.rounding_skewness(Estimator::Population, &RM), None);
+    }
+}
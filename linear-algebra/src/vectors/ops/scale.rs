@@ -1,5 +1,5 @@
 use super::super::Vector;
-use std::ops::Mul;
+use core::ops::{Mul, MulAssign};
 
 impl<T> Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
 
@@ -27,6 +27,9 @@ impl<T> Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
     /// \end{pmatrix}
     /// $$
     ///
+    /// This method is a thin wrapper around the `*` operator applied to a vector reference and a
+    /// scalar (which can be written on either side, for the built-in numerical types and decimals).
+    ///
     /// # Examples:
     /// ```rust
     /// # use beaumont_macros::* ;
@@ -34,18 +37,54 @@ impl<T> Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
     /// let v = vector![1, 2, 3];
     /// let scaled = v.scale_by(2);
     /// assert_eq!(scaled, vector![2, 4, 6]);
+    /// assert_eq!(&v * 2, vector![2, 4, 6]);
+    /// assert_eq!(2 * &v, vector![2, 4, 6]);
     /// ```
     ///
     pub fn scale_by(&self, scalar: T) -> Self {
+        self * scalar
+    }
+}
+
+
+impl<T> Mul<T> for &Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
+    type Output = Vector<T>;
+
+    /// Multiply this vector by a scalar (see [`Vector::scale_by`])
+    fn mul(self, scalar: T) -> Self::Output {
         Vector {
-            components: self.components.iter().map(|x| x.mul(scalar)).collect()
+            components: self.components.iter().map(|x| *x * scalar).collect()
         }
     }
 }
 
+impl<T> Mul<T> for Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
+    type Output = Vector<T>;
+
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Multiply this vector by a scalar (in place)
+    fn mul_assign(&mut self, scalar: T) {
+        for x in self.components.iter_mut() {
+            *x = *x * scalar;
+        }
+    }
+}
+
+
+impl_scalar_mul!(Vector<_>, &Vector<_>);
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
+    use beaumont_numbers::Decimal;
 
     #[test]
     fn scale_vectors_of_integers() {
@@ -57,6 +96,16 @@ mod test {
         assert_eq!(scaled.components[2], 6);
     }
 
+    #[test]
+    fn scale_vectors_of_complex() {
+        use beaumont_numbers::Complex;
+        // Multiplying by i rotates each component by 90 degrees
+        let v = Vector { components: Box::new([Complex::new(1, 0), Complex::new(1, 1)]) };
+        let scaled = v.scale_by(Complex::new(0, 1));
+        assert_eq!(scaled.components[0], Complex::new(0, 1));
+        assert_eq!(scaled.components[1], Complex::new(-1, 1));
+    }
+
     #[test]
     fn scale_vectors_of_floats() {
         let v = Vector { components: Box::new([1.1, 2.22, 3.333]) };
@@ -67,6 +116,41 @@ mod test {
         assert_eq!(scaled.components[2], 6.666);
     }
 
+    #[test]
+    fn mul_operator_owned_and_borrowed() {
+        let v = Vector::from([1.5, -2.0]);
+        let expected = Vector::from([3.0, -4.0]);
+        assert_eq!(&v * 2.0, expected);
+        assert_eq!(2.0 * &v, expected);
+        assert_eq!(2.0 * Vector::from([1.5, -2.0]), expected);
+        assert_eq!(v * 2.0, expected);
+    }
+
+    #[test]
+    fn mul_assign() {
+        let mut v = Vector::from([1, -2, 3]);
+        v *= -3;
+        assert_eq!(v, Vector::from([-3, 6, -9]));
+    }
+
+    #[test]
+    fn mul_decimal_on_the_left() {
+        let v = Vector::from([Decimal::new(150, 2), Decimal::new(-2, 0)]);
+        let scaled = Decimal::new(2, 0) * v;
+        assert_eq!(scaled, Vector::from([Decimal::new(3, 0), Decimal::new(-4, 0)]));
+    }
+
+    #[test]
+    fn mul_complex_and_dual_on_the_left() {
+        use beaumont_numbers::{Complex, Dual};
+        let v = Vector::from([Complex::new(1, 0), Complex::new(1, 1)]);
+        assert_eq!(Complex::new(0, 1) * &v, Vector::from([Complex::new(0, 1), Complex::new(-1, 1)]));
+
+        // The derivative of 3x at x = 2 (and at x = -1)
+        let x = Vector::from([Dual::variable(2.0), Dual::variable(-1.0)]);
+        assert_eq!(Dual::constant(3.0) * x, Vector::from([Dual::new(6.0, [3.0]), Dual::new(-3.0, [3.0])]));
+    }
+
     // #[test]
     // #[allow_decimals]
     // TODO fn scale_vectors_of_decimals() {
@@ -0,0 +1,501 @@
+use super::super::Vector;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul};
+#[cfg(feature = "std")]
+use crate::{Estimator, Interpolation};
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use beaumont_numbers::Real;
+
+impl<T> Vector<T> where T: Copy + PartialEq + Default + Add<Output = T> {
+
+    /// Compute the sum of the components of this vector (or zero, if it has no components).
+    ///
+    /// Being computed exactly, it's available for integers and decimals too (and it panics in
+    /// case of overflow, as the `+` operator does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1, -2, 3];
+    /// assert_eq!(v.sum(), 2);
+    /// ```
+    pub fn sum(&self) -> T {
+        self.iter().fold(T::default(), |sum, x| sum + x)
+    }
+
+    /// Compute the cumulative sums of the components of this vector, that is the vector whose
+    /// i-th component is the sum of the first i components (such as the cumulative P&L from the
+    /// daily ones).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let pnl = vector![100, -30, 50];
+    /// assert_eq!(pnl.cumsum(), vector![100, 70, 120]);
+    /// ```
+    pub fn cumsum(&self) -> Self {
+        self.iter()
+            .scan(T::default(), |sum, x| {
+                *sum = *sum + x;
+                Some(*sum)
+            })
+            .collect()
+    }
+}
+
+
+impl<T> Vector<T> where T: Copy + PartialEq + Mul<Output = T> {
+
+    /// Compute the cumulative products of the components of this vector, that is the vector whose
+    /// i-th component is the product of the first i components (such as the growth of an
+    /// investment from its gross returns).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let gross_returns = vector![1.5, 0.5, 2.0];
+    /// assert_eq!(gross_returns.cumprod(), vector![1.5, 0.75, 1.5]);
+    /// ```
+    pub fn cumprod(&self) -> Self {
+        let mut product = None;
+        self.iter()
+            .map(|x| {
+                let p = product.map_or(x, |p| p * x);
+                product = Some(p);
+                p
+            })
+            .collect()
+    }
+}
+
+
+// The following operations only compare the components, which are ignored when not comparable
+// (such as the NaN floats).
+impl<T> Vector<T> where T: Copy + PartialOrd {
+
+    /// Find the least component of this vector, or `None` if it has no (comparable) components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = Vector::from([3.0, f64::NAN, -1.5, 2.0]);
+    /// assert_eq!(v.min(), Some(-1.5));
+    /// ```
+    pub fn min(&self) -> Option<T> {
+        self.extremum(Ordering::Less).map(|(_, x)| x)
+    }
+
+    /// Find the greatest component of this vector, or `None` if it has no (comparable) components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = Vector::from([3.0, f64::NAN, -1.5, 2.0]);
+    /// assert_eq!(v.max(), Some(3.0));
+    /// ```
+    pub fn max(&self) -> Option<T> {
+        self.extremum(Ordering::Greater).map(|(_, x)| x)
+    }
+
+    /// Find the index (starting from 1) of the least component of this vector (the first one, if
+    /// repeated), or `None` if it has no (comparable) components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![3, -1, 2, -1];
+    /// assert_eq!(v.argmin(), Some(2));
+    /// ```
+    pub fn argmin(&self) -> Option<u64> {
+        self.extremum(Ordering::Less).map(|(index, _)| index)
+    }
+
+    /// Find the index (starting from 1) of the greatest component of this vector (the first one,
+    /// if repeated), or `None` if it has no (comparable) components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![3, -1, 3, 2];
+    /// assert_eq!(v.argmax(), Some(1));
+    /// ```
+    pub fn argmax(&self) -> Option<u64> {
+        self.extremum(Ordering::Greater).map(|(index, _)| index)
+    }
+
+    // Find the first component (along with its index) that compares with all the others as given
+    fn extremum(&self, ordering: Ordering) -> Option<(u64, T)> {
+        self.iter()
+            .zip(1..)
+            .filter(|(x, _)| x.partial_cmp(x).is_some())
+            .fold(None, |extremum, (x, index)| match extremum {
+                Some((_, e)) if x.partial_cmp(&e) != Some(ordering) => extremum,
+                _ => Some((index, x)),
+            })
+    }
+}
+
+
+// The following operations require elementary functions (such as square roots), therefore they
+// are available for floats (when the `std` feature is enabled). See the `rounding_*` methods for
+// their decimal counterparts.
+#[cfg(feature = "std")]
+impl<T> Vector<T> where T: Real {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the arithmetic mean of the components of this vector, or `None` if it has no
+    /// components.
+    ///
+    /// $$
+    /// \bar{v} \quad \equiv \quad \frac{1}{n} \sum_{i=1}^n v_{i}
+    /// $$
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 2.0, 4.5];
+    /// assert_eq!(v.mean(), Some(2.5));
+    /// ```
+    pub fn mean(&self) -> Option<T> {
+        (!self.components.is_empty()).then(|| self.sum() / T::from_f64(self.components.len() as f64))
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the variance of the components of this vector, according to the given estimator
+    /// (dividing the sum of the squared deviations from the mean by $n$ for the population, or
+    /// by $n - 1$ for a sample).
+    ///
+    /// $$
+    /// \sigma^2 \quad \equiv \quad \frac{1}{n} \sum_{i=1}^n (v_{i} - \bar{v})^2
+    /// \qquad
+    /// s^2 \quad \equiv \quad \frac{1}{n - 1} \sum_{i=1}^n (v_{i} - \bar{v})^2
+    /// $$
+    ///
+    /// It's computed in a single pass with Welford's algorithm, which doesn't suffer from the
+    /// catastrophic cancellation of the textbook formula (such as for prices with a large mean
+    /// and a small variance). It returns `None` if there are too few components.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// assert_eq!(v.variance(Estimator::Population), Some(4.0));
+    /// assert_eq!(v.variance(Estimator::Sample), Some(32.0 / 7.0));
+    /// assert_eq!(vector![1.0].variance(Estimator::Sample), None);
+    /// ```
+    pub fn variance(&self, estimator: Estimator) -> Option<T> {
+        let moments = self.moments();
+        let n = moments.n.checked_sub(estimator.ddof()).filter(|n| *n > 0)?;
+        Some(moments.m2 / T::from_f64(n as f64))
+    }
+
+    /// Compute the standard deviation of the components of this vector (such as the volatility
+    /// of returns), that is the square root of their variance according to the given estimator
+    /// (see [`Vector::variance`]).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// assert_eq!(v.std_dev(Estimator::Population), Some(2.0));
+    /// ```
+    pub fn std_dev(&self, estimator: Estimator) -> Option<T> {
+        self.variance(estimator).map(T::sqrt)
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the skewness of the components of this vector, that is the asymmetry of their
+    /// distribution about the mean (positive when the right tail is longer).
+    ///
+    /// $$
+    /// g_1 \quad \equiv \quad \frac{m_3}{m_2^{3/2}}
+    /// \qquad
+    /// G_1 \quad \equiv \quad \frac{\sqrt{n (n - 1)}}{n - 2} g_1
+    /// $$
+    ///
+    /// where $m_k$ are the central moments $\frac{1}{n} \sum_{i=1}^n (v_{i} - \bar{v})^k$, for
+    /// the population and a sample respectively. It returns `None` if there are too few
+    /// components (at least 3 for a sample), or if they are all equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 1.0, 1.0, 5.0];
+    /// let skewness = v.skewness(Estimator::Population).unwrap();
+    /// assert!((skewness - 2.0 / 3.0f64.sqrt()).abs() < 1e-15);
+    /// assert_eq!(vector![1.0, 2.0, 3.0].skewness(Estimator::Sample), Some(0.0));
+    /// ```
+    pub fn skewness(&self, estimator: Estimator) -> Option<T> {
+        let moments = self.moments();
+        if moments.m2 == T::default() {
+            return None;
+        }
+        let n = T::from_f64(moments.n as f64);
+        let g1 = n.sqrt() * moments.m3 / (moments.m2 * moments.m2.sqrt());
+        match estimator {
+            Estimator::Population => Some(g1),
+            Estimator::Sample if moments.n >= 3 => {
+                let one = T::from_f64(1.0);
+                let two = T::from_f64(2.0);
+                Some(g1 * (n * (n - one)).sqrt() / (n - two))
+            }
+            Estimator::Sample => None,
+        }
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Compute the excess kurtosis of the components of this vector, that is the heaviness of the
+    /// tails of their distribution compared to a normal distribution (whose excess kurtosis is
+    /// zero).
+    ///
+    /// $$
+    /// g_2 \quad \equiv \quad \frac{m_4}{m_2^2} - 3
+    /// \qquad
+    /// G_2 \quad \equiv \quad \frac{n - 1}{(n - 2) (n - 3)} \left( (n + 1) g_2 + 6 \right)
+    /// $$
+    ///
+    /// where $m_k$ are the central moments (see [`Vector::skewness`]), for the population and a
+    /// sample respectively. It returns `None` if there are too few components (at least 4 for a
+    /// sample), or if they are all equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![-1.0, 1.0, -1.0, 1.0];
+    /// assert_eq!(v.kurtosis(Estimator::Population), Some(-2.0));
+    /// assert_eq!(v.kurtosis(Estimator::Sample), Some(-6.0));
+    /// ```
+    pub fn kurtosis(&self, estimator: Estimator) -> Option<T> {
+        let moments = self.moments();
+        if moments.m2 == T::default() {
+            return None;
+        }
+        let n = T::from_f64(moments.n as f64);
+        let [one, two, three, six] = [1.0, 2.0, 3.0, 6.0].map(T::from_f64);
+        let g2 = n * moments.m4 / (moments.m2 * moments.m2) - three;
+        match estimator {
+            Estimator::Population => Some(g2),
+            Estimator::Sample if moments.n >= 4 =>
+                Some((n - one) / ((n - two) * (n - three)) * ((n + one) * g2 + six)),
+            Estimator::Sample => None,
+        }
+    }
+
+    /// Compute the given quantile (from 0 to 1) of the components of this vector (such as the
+    /// historical value at risk from returns), interpolating between the two nearest components
+    /// according to the given method.
+    ///
+    /// It returns `None` if this vector has no components, if any of them is NaN, or if the
+    /// quantile is out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![4.0, 1.0, 3.0, 2.0];
+    /// assert_eq!(v.quantile(0.5, Interpolation::Linear), Some(2.5));
+    /// assert_eq!(v.quantile(0.5, Interpolation::Lower), Some(2.0));
+    /// assert_eq!(v.quantile(0.5, Interpolation::Higher), Some(3.0));
+    /// assert_eq!(v.quantile(0.25, Interpolation::Linear), Some(1.75));
+    /// assert_eq!(v.quantile(0.25, Interpolation::Nearest), Some(2.0));
+    /// assert_eq!(v.quantile(0.25, Interpolation::Midpoint), Some(1.5));
+    /// assert_eq!(v.quantile(1.5, Interpolation::Linear), None);
+    /// ```
+    pub fn quantile(&self, q: T, interpolation: Interpolation) -> Option<T> {
+        let [zero, half, one, two] = [0.0, 0.5, 1.0, 2.0].map(T::from_f64);
+        if self.components.is_empty() || !(q >= zero && q <= one) {
+            return None;
+        }
+        if self.iter().any(|x| x.partial_cmp(&x).is_none()) {
+            return None;
+        }
+        let mut sorted: Vec<T> = self.iter().collect();
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+
+        // The (fractional) position of the quantile, starting from 0
+        let last = sorted.len() - 1;
+        let h = T::from_f64(last as f64) * q;
+        let lower = (h.to_f64().floor() as usize).min(last);
+        let fraction = h - T::from_f64(lower as f64);
+        let (a, b) = (sorted[lower], sorted[(lower + 1).min(last)]);
+
+        Some(match interpolation {
+            Interpolation::Linear => a + (b - a) * fraction,
+            Interpolation::Lower => a,
+            Interpolation::Higher => if fraction > zero { b } else { a },
+            Interpolation::Nearest => if fraction > half { b } else { a },
+            Interpolation::Midpoint => if fraction > zero { (a + b) / two } else { a },
+        })
+    }
+
+    /// Compute the median of the components of this vector, that is the quantile 0.5 (with the
+    /// linear interpolation, so that it's the mean of the two middle components if they are even).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// assert_eq!(vector![3.0, 1.0, 2.0].median(), Some(2.0));
+    /// assert_eq!(vector![3.0, 1.0, 2.0, 10.0].median(), Some(2.5));
+    /// ```
+    pub fn median(&self) -> Option<T> {
+        self.quantile(T::from_f64(0.5), Interpolation::Linear)
+    }
+
+    // Compute the count and the central moments (multiplied by the count) of the components in a
+    // single pass, with the numerically stable updates by Welford (and by Terriberry, for the
+    // third and the fourth moments)
+    fn moments(&self) -> Moments<T> {
+        let [one, two, three, four, six] = [1.0, 2.0, 3.0, 4.0, 6.0].map(T::from_f64);
+        let zero = T::default();
+        let mut m = Moments { n: 0, mean: zero, m2: zero, m3: zero, m4: zero };
+        for x in self.iter() {
+            m.n += 1;
+            let n = T::from_f64(m.n as f64);
+            let delta = x - m.mean;
+            let delta_n = delta / n;
+            let delta_n2 = delta_n * delta_n;
+            let term = delta * delta_n * (n - one);
+            m.mean = m.mean + delta_n;
+            m.m4 = m.m4 + term * delta_n2 * (n * n - three * n + three) + six * delta_n2 * m.m2 - four * delta_n * m.m3;
+            m.m3 = m.m3 + term * delta_n * (n - two) - three * delta_n * m.m2;
+            m.m2 = m.m2 + delta * (x - m.mean);
+        }
+        m
+    }
+}
+
+// The count, the mean, and the sums of the powers of the deviations from the mean (that is the
+// central moments multiplied by the count)
+#[cfg(feature = "std")]
+struct Moments<T> {
+    n: usize,
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sum_and_cumsum() {
+        let v = Vector::from([1, 2, 3, 4]);
+        assert_eq!(v.sum(), 10);
+        assert_eq!(v.cumsum(), Vector::from([1, 3, 6, 10]));
+
+        let empty: Vector<i32> = Vector::from([]);
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.cumsum(), empty);
+    }
+
+    #[test]
+    fn cumprod() {
+        let v = Vector::from([2, -3, 4]);
+        assert_eq!(v.cumprod(), Vector::from([2, -6, -24]));
+    }
+
+    #[test]
+    fn min_max() {
+        let v = Vector::from([2, -3, 4, -3, 4]);
+        assert_eq!((v.min(), v.max()), (Some(-3), Some(4)));
+        assert_eq!((v.argmin(), v.argmax()), (Some(2), Some(3)));
+
+        let empty: Vector<i32> = Vector::from([]);
+        assert_eq!((empty.min(), empty.argmax()), (None, None));
+    }
+
+    #[test]
+    fn min_max_ignoring_nan() {
+        let v = Vector::from([f64::NAN, 1.5, -0.5, f64::NAN]);
+        assert_eq!((v.argmin(), v.argmax()), (Some(3), Some(2)));
+
+        let v = Vector::from([f64::NAN]);
+        assert_eq!((v.min(), v.max()), (None, None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn variance_without_cancellation() {
+        // The textbook formula (the mean of the squares minus the square of the mean) would lose
+        // all the significant digits
+        let v = Vector::from([1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        assert_eq!(v.variance(Estimator::Sample), Some(30.0));
+        assert_eq!(v.variance(Estimator::Population), Some(22.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn variance_too_few() {
+        let empty: Vector<f64> = Vector::from([]);
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.variance(Estimator::Population), None);
+        assert_eq!(Vector::from([1.5]).variance(Estimator::Population), Some(0.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn skewness_and_kurtosis() {
+        // Compared with the (two-pass) definitions
+        let v = Vector::from([1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 10.0]);
+        let close = |x: Option<f64>, y: f64| (x.unwrap() - y).abs() < 1e-12;
+        assert!(close(v.skewness(Estimator::Population), 1.7962008374788356));
+        assert!(close(v.skewness(Estimator::Sample), 2.240256043894075));
+        assert!(close(v.kurtosis(Estimator::Population), 2.0329218106995883));
+        assert!(close(v.kurtosis(Estimator::Sample), 5.669135802469135));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn skewness_and_kurtosis_undefined() {
+        let v = Vector::from([2.0, 2.0, 2.0, 2.0]);
+        assert_eq!(v.skewness(Estimator::Population), None);
+        assert_eq!(v.kurtosis(Estimator::Population), None);
+
+        let v = Vector::from([1.0, 2.0, 4.0]);
+        assert!(v.skewness(Estimator::Sample).is_some());
+        assert_eq!(v.kurtosis(Estimator::Sample), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn quantile() {
+        let v = Vector::from([10.0, 40.0, 20.0, 30.0, 50.0]);
+        assert_eq!(v.quantile(0.0, Interpolation::Linear), Some(10.0));
+        assert_eq!(v.quantile(1.0, Interpolation::Linear), Some(50.0));
+        assert_eq!(v.quantile(0.9, Interpolation::Linear), Some(46.0));
+        assert_eq!(v.quantile(0.9, Interpolation::Lower), Some(40.0));
+        assert_eq!(v.quantile(0.9, Interpolation::Higher), Some(50.0));
+        assert_eq!(v.quantile(0.9, Interpolation::Nearest), Some(50.0));
+        assert_eq!(v.quantile(0.9, Interpolation::Midpoint), Some(45.0));
+        assert_eq!(v.median(), Some(30.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn quantile_undefined() {
+        let v = Vector::from([1.0, f64::NAN]);
+        assert_eq!(v.median(), None);
+        let v = Vector::from([1.0, 2.0]);
+        assert_eq!(v.quantile(f64::NAN, Interpolation::Linear), None);
+        assert_eq!(v.quantile(-0.1, Interpolation::Linear), None);
+    }
+}
@@ -0,0 +1,142 @@
+use core::ops::{Sub, SubAssign};
+use crate::{AsVectorView, Dimension, LinearAlgebraError};
+use super::super::Vector;
+
+impl<A> Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Subtract the other vector from this vector.
+    ///
+    /// Given two vectors $\vec{v}$ and $\vec{w}$ in the n-dimensional space, their subtraction
+    /// is defined by the difference of the correspondent components.
+    ///
+    /// $$
+    /// \vec{v} - \vec{w}
+    /// \quad \equiv \quad
+    /// \begin{pmatrix}
+    /// v_{1} - w_{1} \\\\
+    /// v_{2} - w_{2} \\\\
+    /// \dots \\\\
+    /// v_{n} - w_{n}
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// The other vector can be a view as well (see [`AsVectorView`]), and it panics if the vectors
+    /// have different dimensions (as the `-` operator applied to vector references does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![4, 5, 6];
+    /// let w = vector![1, 2, 3];
+    /// assert_eq!(Vector::sub(&v, &w), vector![3, 3, 3]);
+    /// assert_eq!(&v - &w, vector![3, 3, 3]);
+    /// ```
+    pub fn sub(&self, rhs: &impl AsVectorView<A>) -> Self {
+        self.view().sub(rhs)
+    }
+
+    /// Same as the [`Vector::sub`] method, but it returns an error (instead of panicking) if the
+    /// vectors have different dimensions.
+    pub fn try_sub(&self, rhs: &impl AsVectorView<A>) -> Result<Self, LinearAlgebraError> {
+        self.check_dim(&rhs.as_view())?;
+        Ok(self.sub(rhs))
+    }
+}
+
+
+impl<A> Sub<&Vector<A>> for &Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+    type Output = Vector<A>;
+
+    /// Subtract the other vector from this vector (see [`Vector::sub`]), and it panics if they
+    /// have different dimensions.
+    fn sub(self, rhs: &Vector<A>) -> Self::Output {
+        self.view().sub(rhs)
+    }
+}
+
+impl<A> Sub<Vector<A>> for &Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+    type Output = Vector<A>;
+
+    fn sub(self, rhs: Vector<A>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<A> Sub<&Vector<A>> for Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+    type Output = Vector<A>;
+
+    fn sub(mut self, rhs: &Vector<A>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<A> Sub<Vector<A>> for Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+    type Output = Vector<A>;
+
+    fn sub(self, rhs: Vector<A>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+
+impl<A> SubAssign<&Vector<A>> for Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+
+    /// Subtract the other vector from this vector (in place), and it panics if they have
+    /// different dimensions.
+    fn sub_assign(&mut self, rhs: &Vector<A>) {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        for (x, y) in self.components.iter_mut().zip(&rhs.components) {
+            *x = *x - *y;
+        }
+    }
+}
+
+impl<A> SubAssign<Vector<A>> for Vector<A> where A: Copy + PartialEq + Sub<Output = A> {
+    fn sub_assign(&mut self, rhs: Vector<A>) {
+        *self -= &rhs;
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn sub_vectors_of_different_dimensions() {
+        let v1 = Vector { components: Box::new([1, 2, 3]) };
+        let v2 = Vector { components: Box::new([4]) };
+        let _ = &v1 - &v2;
+    }
+
+    #[test]
+    fn sub_operator_owned_and_borrowed() {
+        let v1 = Vector::from([4, 5, 6]);
+        let v2 = Vector::from([1, 2, 4]);
+        let expected = Vector::from([3, 3, 2]);
+        assert_eq!(&v1 - &v2, expected);
+        assert_eq!(&v1 - Vector::from([1, 2, 4]), expected);
+        assert_eq!(Vector::from([4, 5, 6]) - &v2, expected);
+        assert_eq!(v1 - v2, expected);
+    }
+
+    #[test]
+    fn try_sub() {
+        let v1 = Vector::from([4, 5, 6]);
+        assert_eq!(v1.try_sub(&Vector::from([1, 2, 3])), Ok(Vector::from([3, 3, 3])));
+        assert_eq!(v1.try_sub(&Vector::from([1, 2])), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut v = Vector::from([1.5, 2.5]);
+        v -= &Vector::from([1.0, -1.0]);
+        v -= Vector::from([0.5, 0.5]);
+        assert_eq!(v, Vector::from([0.0, 3.0]));
+    }
+}
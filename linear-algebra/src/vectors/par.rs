@@ -0,0 +1,270 @@
+use super::Vector;
+use crate::parallel::{chunked, PARALLEL_THRESHOLD};
+use crate::{Dimension, LinearAlgebraError};
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+use rayon::iter::Copied;
+use rayon::prelude::*;
+
+impl<T> Vector<T> where T: Copy + PartialEq + Send + Sync {
+
+    /// The parallel iterator over the components of this vector (by value, as [`Vector::iter`]
+    /// does), to be consumed by the [rayon](https://docs.rs/rayon) adapters.
+    ///
+    /// Unlike the other parallel operations, it doesn't fall back to serial code for small vectors
+    /// (see [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)), and the reductions built upon it
+    /// (such as `sum`) are not guaranteed to be deterministic for floating point numbers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v: Vector<u64> = (1..=100_000).collect();
+    /// assert_eq!(v.par_iter().filter(|x| x % 2 == 0).count(), 50_000);
+    /// ```
+    pub fn par_iter(&self) -> Copied<rayon::slice::Iter<'_, T>> {
+        self.components.par_iter().copied()
+    }
+
+    /// The parallel iterator over mutable references to the components of this vector, which
+    /// allows modifying them in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut v = vector![1, 2, 3];
+    /// v.par_iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(v, vector![10, 20, 30]);
+    /// ```
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        self.components.par_iter_mut()
+    }
+
+    /// Same as the [`Vector::map`] method, but applying the function to the components in
+    /// parallel (unless this vector has less components than the
+    /// [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let returns: Vector<f64> = (0..100_000).map(|i| (i % 4) as f64 / 4.0).collect();
+    /// let growths = returns.par_map(|r| 1.0 + r);
+    /// assert_eq!(growths[2], 1.25);
+    /// ```
+    pub fn par_map<U>(&self, f: impl Fn(T) -> U + Sync + Send) -> Vector<U>
+    where U: Copy + PartialEq + Send {
+        if self.components.len() < PARALLEL_THRESHOLD {
+            return self.map(f);
+        }
+        self.par_iter().map(f).collect()
+    }
+
+    /// Map each component of this vector by the given function, and reduce the results by the
+    /// other given function, in parallel (unless this vector has less components than the
+    /// [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// The `identity` is the value that the `reduce` function leaves unchanged (such as zero for
+    /// the sum, or the lowest value for the maximum), and it's the result for empty vectors.
+    /// The components are reduced by chunks of a fixed size, in a way that doesn't depend on the
+    /// number of threads, therefore the results of repeated reductions match bit for bit (even
+    /// for floating point numbers). However, as the results are grouped differently than serial
+    /// code does, the `reduce` function is expected to be associative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let payoffs: Vector<f64> = (0..100_000).map(|i| (i % 1_000) as f64 - 900.0).collect();
+    /// let positive = payoffs.par_map_reduce(|p| p.max(0.0), 0.0, |a, b| a + b);
+    /// assert_eq!(positive, 495_000.0);
+    ///
+    /// let worst = payoffs.par_map_reduce(|p| p, f64::INFINITY, f64::min);
+    /// assert_eq!(worst, -900.0);
+    /// ```
+    pub fn par_map_reduce<U>(
+        &self,
+        map: impl Fn(T) -> U + Sync + Send,
+        identity: U,
+        reduce: impl Fn(U, U) -> U + Sync + Send,
+    ) -> U where U: Clone + Send + Sync {
+        let partials = chunked(self.components.len(), |range| {
+            self.components[range].iter().fold(identity.clone(), |acc, x| reduce(acc, map(*x)))
+        });
+        partials.into_iter().fold(identity, &reduce)
+    }
+
+    /// Same as the [`Vector::scale_by`] method, but multiplying the components in parallel
+    /// (unless this vector has less components than the
+    /// [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v: Vector<i64> = (1..=100_000).collect();
+    /// assert_eq!(v.par_scale_by(3), v.scale_by(3));
+    /// ```
+    pub fn par_scale_by(&self, scalar: T) -> Self where T: Mul<Output = T> {
+        self.par_map(|x| x * scalar)
+    }
+
+    /// Same as the [`Vector::add`] method, but adding the components in parallel (unless the
+    /// vectors have less components than the [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v: Vector<i64> = (1..=100_000).collect();
+    /// let w = v.scale_by(-1);
+    /// assert!(v.par_add(&w).iter().all(|x| x == 0));
+    /// ```
+    pub fn par_add(&self, rhs: &Self) -> Self where T: Add<Output = T> {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        if self.components.len() < PARALLEL_THRESHOLD {
+            return self.add(rhs);
+        }
+        self.components.par_iter()
+            .zip(rhs.components.par_iter())
+            .map(|(x, y)| *x + *y)
+            .collect()
+    }
+
+    /// Same as the [`Vector::par_add`] method, but it returns an error (instead of panicking) if
+    /// the vectors have different dimensions.
+    pub fn try_par_add(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> where T: Add<Output = T> {
+        self.check_dim(rhs)?;
+        Ok(self.par_add(rhs))
+    }
+
+    /// Same as the [`Vector::dot`] method, but computing the dot product in parallel (unless the
+    /// vectors have less components than the [`PARALLEL_THRESHOLD`](crate::PARALLEL_THRESHOLD)).
+    ///
+    /// The products are summed by chunks of a fixed size, in a way that doesn't depend on the
+    /// number of threads, therefore the results of repeated computations match bit for bit (even
+    /// for floating point numbers). However, for large vectors of floating point numbers, they
+    /// may differ from the results of the [`Vector::dot`] method in the last digits, as the
+    /// products are summed in a different order.
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let weights: Vector<f64> = (0..200_000).map(|i| 1.0 / (1 + i % 10) as f64).collect();
+    /// let scores: Vector<f64> = (0..200_000).map(|i| (i % 3) as f64 - 1.0).collect();
+    /// let first = weights.par_dot(&scores);
+    /// assert!((first - weights.dot(&scores)).abs() < 1e-9);
+    /// assert_eq!(weights.par_dot(&scores).to_bits(), first.to_bits());
+    /// ```
+    pub fn par_dot(&self, other: &Self) -> T where T: Add<Output = T> + Mul<Output = T> + Sum<T> {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        let partials = chunked(self.components.len(), |range| {
+            self.components[range.clone()].iter()
+                .zip(other.components[range].iter())
+                .map(|(x, y)| *x * *y)
+                .sum::<T>()
+        });
+        partials.into_iter().sum()
+    }
+
+    /// Same as the [`Vector::par_dot`] method, but it returns an error (instead of panicking) if
+    /// the vectors have different dimensions.
+    pub fn try_par_dot(&self, other: &Self) -> Result<T, LinearAlgebraError>
+    where T: Add<Output = T> + Mul<Output = T> + Sum<T> {
+        self.check_dim(other)?;
+        Ok(self.par_dot(other))
+    }
+}
+
+
+/// Collect the items of a parallel iterator into a new vector (in their order)
+impl<T> FromParallelIterator<T> for Vector<T> where T: Copy + PartialEq + Send {
+    fn from_par_iter<I>(par_iter: I) -> Self where I: IntoParallelIterator<Item = T> {
+        let components: Vec<T> = par_iter.into_par_iter().collect();
+        Vector { components: components.into_boxed_slice() }
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parallel::CHUNK_SIZE;
+    use rayon::ThreadPoolBuilder;
+
+    fn floats(len: usize, seed: u64) -> Vector<f64> {
+        // Values of very different magnitudes, whose sums are sensitive to their order
+        (0..len as u64).map(|i| {
+            let x = (i.wrapping_mul(6364136223846793005).wrapping_add(seed) >> 33) as f64;
+            (x / 1e3).powi((i % 5) as i32) * if i % 2 == 0 { 1.0 } else { -1.0 }
+        }).collect()
+    }
+
+    #[test]
+    fn par_dot_deterministic() {
+        let v = floats(3 * PARALLEL_THRESHOLD + 17, 1);
+        let w = floats(3 * PARALLEL_THRESHOLD + 17, 2);
+        let expected = v.par_dot(&w);
+        for threads in [1, 2, 3, 8] {
+            let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            for _ in 0..3 {
+                assert_eq!(pool.install(|| v.par_dot(&w)).to_bits(), expected.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn par_dot_serial() {
+        // Below the size of a chunk, it's the same as the serial dot product
+        let v = floats(CHUNK_SIZE, 3);
+        let w = floats(CHUNK_SIZE, 4);
+        assert_eq!(v.par_dot(&w).to_bits(), v.dot(&w).to_bits());
+
+        let empty: Vector<f64> = Vector::from([]);
+        assert_eq!(empty.par_dot(&empty), 0.0);
+    }
+
+    #[test]
+    fn par_dot_integers() {
+        let v: Vector<i64> = (1..=100_000).collect();
+        assert_eq!(v.par_dot(&v), v.dot(&v));
+        assert_eq!(v.try_par_dot(&Vector::from([1, 2])), Err(LinearAlgebraError::DimensionMismatch { expected: 100_000, found: 2 }));
+    }
+
+    #[test]
+    fn par_map_reduce_deterministic() {
+        let v = floats(2 * PARALLEL_THRESHOLD, 5);
+        let expected = v.par_map_reduce(|x| x * x, 0.0, |a, b| a + b);
+        let pool = ThreadPoolBuilder::new().num_threads(5).build().unwrap();
+        assert_eq!(pool.install(|| v.par_map_reduce(|x| x * x, 0.0, |a, b| a + b)).to_bits(), expected.to_bits());
+        assert_eq!(Vector::<f64>::from([]).par_map_reduce(|x| x, -1.0, f64::max), -1.0);
+    }
+
+    #[test]
+    fn par_add_and_scale() {
+        let v: Vector<i32> = (0..PARALLEL_THRESHOLD as i32 + 5).collect();
+        let w = v.par_scale_by(2);
+        assert_eq!(w, v.scale_by(2));
+        assert_eq!(v.par_add(&w), v.scale_by(3));
+        assert_eq!(v.par_map(|x| x as i64), v.map(|x| x as i64));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn par_add_different_dimensions() {
+        let v: Vector<i32> = (0..PARALLEL_THRESHOLD as i32).collect();
+        let _ = v.par_add(&Vector::from([1, 2, 3]));
+    }
+}
@@ -0,0 +1,126 @@
+use super::Vector;
+use crate::{Dimension, LinearAlgebraError, SimdFloat};
+
+impl<T> Vector<T> where T: SimdFloat {
+
+    /// Same as the [`Vector::dot`] method, but computing the dot product by SIMD instructions
+    /// (see [`SimdFloat`] for its accuracy, which is usually better than the generic one).
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let weights: Vector<f64> = (0..10_000).map(|i| (i % 4) as f64 / 4.0).collect();
+    /// let returns: Vector<f64> = (0..10_000).map(|i| (i % 2) as f64).collect();
+    /// assert_eq!(weights.simd_dot(&returns), 5_000.0 * 0.5);
+    /// ```
+    pub fn simd_dot(&self, other: &Self) -> T {
+        assert_eq!(self.dim(), other.dim(), "must have the same dimension");
+        T::simd_dot(&self.components, &other.components)
+    }
+
+    /// Same as the [`Vector::simd_dot`] method, but it returns an error (instead of panicking)
+    /// if the vectors have different dimensions.
+    pub fn try_simd_dot(&self, other: &Self) -> Result<T, LinearAlgebraError> {
+        self.check_dim(other)?;
+        Ok(self.simd_dot(other))
+    }
+
+    /// Same as the [`Vector::add`] method, but adding the components by SIMD instructions.
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// assert_eq!(v.simd_add(&v), vector![2.0, 4.0, 6.0, 8.0, 10.0]);
+    /// ```
+    pub fn simd_add(&self, rhs: &Self) -> Self {
+        assert_eq!(self.dim(), rhs.dim(), "must have the same dimension");
+        Vector { components: T::simd_add(&self.components, &rhs.components).into_boxed_slice() }
+    }
+
+    /// Same as the [`Vector::simd_add`] method, but it returns an error (instead of panicking)
+    /// if the vectors have different dimensions.
+    pub fn try_simd_add(&self, rhs: &Self) -> Result<Self, LinearAlgebraError> {
+        self.check_dim(rhs)?;
+        Ok(self.simd_add(rhs))
+    }
+
+    /// Same as the [`Vector::scale_by`] method, but multiplying the components by SIMD
+    /// instructions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let v = vector![1.0f32, 2.0, 3.0];
+    /// assert_eq!(v.simd_scale_by(0.5), vector![0.5f32, 1.0, 1.5]);
+    /// ```
+    pub fn simd_scale_by(&self, scalar: T) -> Self {
+        Vector { components: T::simd_scale(&self.components, scalar).into_boxed_slice() }
+    }
+
+    #[cfg_attr(doc, beaumont_macros::allow_katex)]
+    /// Add the other vector, multiplied by the given scalar, to this vector in place (by SIMD
+    /// instructions), as the well-known `axpy` routine of the BLAS libraries:
+    ///
+    /// $$
+    /// \vec{y} \quad \leftarrow \quad \alpha \vec{x} + \vec{y}
+    /// $$
+    ///
+    /// It panics if the vectors have different dimensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_macros::*;
+    /// # use beaumont_linear_algebra::*;
+    /// let mut portfolio = vector![100.0, 50.0, 0.0];
+    /// let trade = vector![10.0, -5.0, 20.0];
+    /// portfolio.simd_axpy_in_place(2.0, &trade);
+    /// assert_eq!(portfolio, vector![120.0, 40.0, 40.0]);
+    /// ```
+    pub fn simd_axpy_in_place(&mut self, alpha: T, x: &Self) {
+        assert_eq!(self.dim(), x.dim(), "must have the same dimension");
+        T::simd_axpy(alpha, &x.components, &mut self.components);
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_as_generic() {
+        let v: Vector<f64> = (0..1_003).map(|i| (i % 17) as f64 - 8.5).collect();
+        let w: Vector<f64> = (0..1_003).map(|i| (i % 5) as f64 * 0.25).collect();
+        assert_eq!(v.simd_add(&w), v.add(&w));
+        assert_eq!(v.simd_scale_by(-1.5), v.scale_by(-1.5));
+
+        let mut u = w.scale_by(1.0);
+        u.simd_axpy_in_place(3.0, &v);
+        assert_eq!(u, &v.scale_by(3.0) + &w);
+    }
+
+    #[test]
+    fn try_simd() {
+        let v = Vector::from([1.0f32, 2.0, 3.0]);
+        let w = Vector::from([1.0f32, 2.0]);
+        assert_eq!(v.try_simd_dot(&v), Ok(14.0));
+        assert_eq!(v.try_simd_dot(&w), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 }));
+        assert_eq!(v.try_simd_add(&w), Err(LinearAlgebraError::DimensionMismatch { expected: 3, found: 2 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same dimension")]
+    fn simd_axpy_different_dimensions() {
+        let mut v = Vector::from([1.0, 2.0, 3.0]);
+        v.simd_axpy_in_place(2.0, &Vector::from([1.0]));
+    }
+}
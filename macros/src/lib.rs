@@ -89,16 +89,22 @@ mod vector;
 /// Moreover, this macro can also transform decimal literals (those ending with the `d` prefix)
 /// without the need to annotate your functions with the `#[allow_decimals]` attribute macro.
 ///
+/// By appending `; SVector` to the components, it creates a stack-allocated vector instead
+/// (and then it depends on the presence of the `SVector` local name).
+///
 /// # Examples
 /// ```ignore
 /// use beaumont_macros::vector;
 /// use beaumont_numbers::Decimal;
-/// use beaumont_linear_algebra::Vector;
+/// use beaumont_linear_algebra::{SVector, Vector};
 ///
 /// let v: Vector<Decimal> =
 ///   vector![ 1.05d, 2.23d, 3.789d ];
 ///
 /// assert_eq!(v.dim(), 3);
+///
+/// let s: SVector<Decimal, 3> =
+///   vector![ 1.05d, 2.23d, 3.789d; SVector ];
 /// ```
 #[proc_macro]
 pub fn vector(items: TokenStream) -> TokenStream {
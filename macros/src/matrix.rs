@@ -19,6 +19,7 @@ pub fn expand(tokens: TokenStream) -> Result<TokenStream> {
 
 
 // TODO inline the following function as it's only used once
+#[allow(clippy::collapsible_match)]
 fn scan(tokens: TokenStream, buf: &mut String) -> Result<(usize, usize)> {
     let mut result = Ok((0, 0));
     let mut previous = ' ';
@@ -6,9 +6,11 @@ pub fn expand(items: TokenStream) -> Result<TokenStream> {
     // dbg!(&items);
     let mut result = Ok(TokenStream::new());
 
-    let mut buf = "Vector::from(vec![".to_string();
+    let mut buf = String::new();
     let mut literal_encountered = false;
-    for token in items {
+    let mut stack_allocated = false;
+    let mut tokens = items.into_iter();
+    while let Some(token) = tokens.next() {
         match &token {
             TokenTree::Literal(literal) => {
                 if !literal_encountered {
@@ -41,12 +43,35 @@ pub fn expand(items: TokenStream) -> Result<TokenStream> {
                 }
             }
 
+            // the components may be followed by `; SVector` to create a stack-allocated vector
+            TokenTree::Punct(t) if t.as_char() == ';' => {
+                match (tokens.next(), tokens.next()) {
+                    (Some(TokenTree::Ident(ident)), None) if ident == "SVector" => {
+                        stack_allocated = true;
+                    }
+                    (Some(other), _) => {
+                        result = Err(Error::new(other.span(), "expected `SVector`"));
+                    }
+                    (None, _) => {
+                        result = Err(Error::new(token.span(), "expected `SVector` after ';'"));
+                    }
+                }
+                break;
+            }
+
             _ => {
                 // we may have encountered a variable identifier or an expression
                 buf.push_str(&token.to_string())
             }
         }
     }
-    buf.push_str("].into_boxed_slice())");
+
+    let buf =
+        if stack_allocated {
+            format!("SVector::from([{buf}])")
+        }
+        else {
+            format!("Vector::from(vec![{buf}].into_boxed_slice())")
+        };
     result.map(|_| buf.parse::<TokenStream>().unwrap())
 }
@@ -13,7 +13,12 @@ impl Matrix {
 }
 fn main() {
     Matrix::from((
-        <[_]>::into_vec(::alloc::boxed::box_new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]))
+        ::alloc::boxed::box_assume_init_into_vec_unsafe(
+                ::alloc::intrinsics::write_box_via_move(
+                    ::alloc::boxed::Box::new_uninit(),
+                    [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+                ),
+            )
             .into_boxed_slice(),
         4,
         3,
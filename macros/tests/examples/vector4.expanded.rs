@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate beaumont_macros;
+pub struct SVector<const N: usize> {
+    components: [f64; N],
+}
+impl<const N: usize> SVector<N> {
+    pub fn from(components: [f64; N]) -> Self {
+        Self { components }
+    }
+}
+fn main() {
+    SVector::from([1.0, 2.0, 3.0]);
+}
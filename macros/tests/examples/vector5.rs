@@ -0,0 +1,5 @@
+#[macro_use]
+extern crate beaumont_macros;
+fn main() {
+    vector![1.0, 2.0, 3.0; Matrix];
+}
@@ -11,6 +11,8 @@ fn good_decimals() {
 fn good_vectors() {
     macrotest::expand("tests/examples/vector1.rs");
     trybuild::TestCases::new().pass("tests/examples/vector1.rs");
+    macrotest::expand("tests/examples/vector4.rs");
+    trybuild::TestCases::new().pass("tests/examples/vector4.rs");
 
 }
 
@@ -19,6 +21,7 @@ fn good_vectors() {
 fn bad_vectors() {
     trybuild::TestCases::new().compile_fail("tests/examples/vector2.rs");
     trybuild::TestCases::new().compile_fail("tests/examples/vector3.rs");
+    trybuild::TestCases::new().compile_fail("tests/examples/vector5.rs");
 }
 
 
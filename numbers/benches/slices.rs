@@ -0,0 +1,59 @@
+use beaumont_numbers::Decimal;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const LEN: usize = 10_000;
+
+// Prices with 2 digits after the decimal point, and positions with none
+fn portfolio() -> (Vec<Decimal>, Vec<Decimal>) {
+    let prices = (0..LEN).map(|i| Decimal::new(10_000 + (i % 1000) as i32, 2)).collect();
+    let positions = (0..LEN).map(|i| Decimal::new((i % 200) as i32 - 100, 0)).collect();
+    (prices, positions)
+}
+
+fn add(c: &mut Criterion) {
+    let (prices, positions) = portfolio();
+    let mut group = c.benchmark_group("add");
+    group.bench_function("scalar loop", |b| b.iter(|| {
+        black_box(&prices).iter()
+            .zip(black_box(&positions))
+            .map(|(p, q)| p.checked_add(*q))
+            .collect::<Option<Vec<Decimal>>>()
+    }));
+    group.bench_function("add_slices", |b| b.iter(|| {
+        Decimal::add_slices(black_box(&prices), black_box(&positions))
+    }));
+    group.finish();
+}
+
+fn mul(c: &mut Criterion) {
+    let (prices, positions) = portfolio();
+    let mut group = c.benchmark_group("mul");
+    group.bench_function("scalar loop", |b| b.iter(|| {
+        black_box(&prices).iter()
+            .zip(black_box(&positions))
+            .map(|(p, q)| p.checked_mul(*q))
+            .collect::<Option<Vec<Decimal>>>()
+    }));
+    group.bench_function("mul_slices", |b| b.iter(|| {
+        Decimal::mul_slices(black_box(&prices), black_box(&positions))
+    }));
+    group.finish();
+}
+
+fn dot(c: &mut Criterion) {
+    let (prices, positions) = portfolio();
+    let mut group = c.benchmark_group("dot");
+    group.bench_function("scalar loop", |b| b.iter(|| {
+        black_box(&prices).iter()
+            .zip(black_box(&positions))
+            .try_fold(Decimal::new(0, 0), |acc, (p, q)| p.checked_mul(*q).and_then(|x| acc.checked_add(x)))
+    }));
+    group.bench_function("dot", |b| b.iter(|| {
+        Decimal::dot(black_box(&prices), black_box(&positions))
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, add, mul, dot);
+criterion_main!(benches);
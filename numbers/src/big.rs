@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// Representation of a decimal number with arbitrary precision
+///
+/// Similarly to [`Decimal`](crate::Decimal), big decimal numbers are internally represented by a
+/// signed coefficient integer and a positive scaling factor, as follows:
+///
+/// $$
+/// coefficient * 10 ^{-scaling}
+/// $$
+///
+/// but the coefficient is unbounded (it grows on the heap as needed), and therefore none of the
+/// arithmetic operations can ever overflow. That makes them suitable for aggregation and
+/// reconciliation, where exactness matters more than speed.
+///
+/// # Usage
+/// To create a big decimal number, you can:
+/// - either invoke the [`BigDecimal::from`] converter functions,
+/// - or invoke the [`BigDecimal::new`] factory function
+///
+/// ```rust
+/// use beaumont_numbers::*;
+///
+/// let b1 = BigDecimal::from(Decimal::new(12345, 2));   // decimal
+/// let b2 = BigDecimal::from("123456789012345678901.23"); // string
+/// let b3 = BigDecimal::new(1234567890123456789, 4);     // coefficient, scaling
+/// ```
+///
+/// Once created, you can apply operations such as negation, additions, multiplication, etc.
+/// and then convert the result down to a decimal number (if it fits).
+///
+/// ```rust
+/// # use beaumont_numbers::*;
+/// let notionals = [Decimal::new(MAX_COEFFICIENT, 2), Decimal::new(MAX_COEFFICIENT, 2)];
+/// let total: BigDecimal = notionals.iter().copied().sum();
+/// assert_eq!(total.to_string(), "42949672.94");
+///
+/// let half = &total * &BigDecimal::from("0.5");
+/// assert_eq!(Decimal::try_from(&half).unwrap().to_string(), "21474836.47");
+/// ```
+///
+/// # Memory
+/// Differently from decimal numbers, big decimal numbers are heap-allocated, and therefore they
+/// require move semantics (copy semantics could not be implemented). All of their operations are
+/// implemented for references as well, so to avoid needless cloning.
+#[derive(Debug, Clone)]
+pub struct BigDecimal {
+    // The sign of the coefficient (which is never negative when the coefficient is zero)
+    negative: bool,
+    // The absolute value of the coefficient
+    magnitude: Vec<u32>,
+    scaling: u32,
+}
+
+
+impl BigDecimal {
+    /// Create a new big decimal number from the given coefficient and scaling factor
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::BigDecimal;
+    /// let b = BigDecimal::new(-123456, 2);
+    /// assert_eq!(b.to_string(), "-1234.56");
+    /// ```
+    pub fn new(coefficient: i128, scaling: u32) -> Self {
+        Self::from_parts(coefficient < 0, magnitude::from_u128(coefficient.unsigned_abs()), scaling)
+    }
+
+    // Create a new big decimal number from its parts, ensuring zero is never negative
+    fn from_parts(negative: bool, magnitude: Vec<u32>, scaling: u32) -> Self {
+        let magnitude = magnitude::normalize(magnitude);
+        Self { negative: negative && !magnitude.is_empty(), magnitude, scaling }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let b = BigDecimal::new(-1_000_000_000_123, 2);
+        assert!(b.negative);
+        assert_eq!(b.magnitude, [123, 1000]);
+        assert_eq!(b.scaling, 2);
+    }
+
+    #[test]
+    fn new_zero_is_not_negative() {
+        let b = BigDecimal::from_parts(true, alloc::vec![0, 0], 2);
+        assert!(!b.negative);
+        assert!(b.magnitude.is_empty());
+    }
+}
+
+
+pub(crate) mod magnitude;
+mod from;
+mod display;
+mod scaling;
+mod ops;
@@ -0,0 +1,138 @@
+use super::{magnitude, BigDecimal};
+use crate::RoundingMode;
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Write;
+use core::iter;
+
+impl fmt::Display for BigDecimal {
+
+    // Differently from decimal numbers, the representation of big decimal numbers has no upper
+    // bound on its length, and therefore it's built in a heap-allocated string.
+    //
+    // Formatting flags are supported as for decimal numbers: the precision is the number of
+    // digits after the decimal point (rounding half up if fewer than the scaling factor, or
+    // appending trailing zeros if more), while the sign, width, fill and alignment flags are
+    // applied to the whole representation (such as "{:+08.2}" writing "+0001.50").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A precision lower than the scaling factor requires rounding, while a greater one
+        // requires trailing zeros
+        let rounded;
+        let big = match f.precision() {
+            Some(precision) if precision < self.scaling as usize => {
+                rounded = self.downscale_by(self.scaling - precision as u32, &RoundingMode::HalfUp);
+                &rounded
+            }
+            _ => self
+        };
+        let trailing_zeros = f.precision().map_or(0, |precision| precision - big.scaling as usize);
+
+        // The most significant limb is written as is, while the others are padded with zeros
+        let mut digits = String::with_capacity(magnitude::digits(&big.magnitude) as usize + 1);
+        match big.magnitude.split_last() {
+            None => digits.push('0'),
+            Some((top, rest)) => {
+                write!(digits, "{top}")?;
+                for limb in rest.iter().rev() {
+                    write!(digits, "{limb:09}")?;
+                }
+            }
+        }
+
+        // The representation of the absolute value (as the sign is written along with the padding)
+        let scaling = big.scaling as usize;
+        let mut representation = String::with_capacity(digits.len() + scaling + trailing_zeros + 2);
+        if scaling == 0 {
+            representation.push_str(&digits);
+        }
+        else if scaling < digits.len() {
+            let (integer, fraction) = digits.split_at(digits.len() - scaling);
+            representation.push_str(integer);
+            representation.push('.');
+            representation.push_str(fraction);
+        }
+        else {
+            representation.push_str("0.");
+            representation.extend(iter::repeat_n('0', scaling - digits.len()));
+            representation.push_str(&digits);
+        }
+        if trailing_zeros > 0 {
+            if scaling == 0 {
+                representation.push('.');
+            }
+            representation.extend(iter::repeat_n('0', trailing_zeros));
+        }
+
+        f.pad_integral(!big.negative, "", &representation)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Decimal;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display_zero() {
+        assert_eq!(BigDecimal::new(0, 0).to_string(), "0");
+        assert_eq!(BigDecimal::new(0, 1).to_string(), "0.0");
+        assert_eq!(BigDecimal::new(0, 3).to_string(), "0.000");
+    }
+
+    #[test]
+    fn display_unscaled() {
+        assert_eq!(BigDecimal::new(-123456, 0).to_string(), "-123456");
+        assert_eq!(BigDecimal::new(i128::MAX, 0).to_string(), i128::MAX.to_string());
+    }
+
+    #[test]
+    fn display_underscaled() {
+        assert_eq!(BigDecimal::new(-1000000000123, 2).to_string(), "-10000000001.23");
+    }
+
+    #[test]
+    fn display_fully_scaled() {
+        assert_eq!(BigDecimal::new(123456, 6).to_string(), "0.123456");
+    }
+
+    #[test]
+    fn display_over_scaled() {
+        assert_eq!(BigDecimal::new(-12, 20).to_string(), "-0.00000000000000000012");
+    }
+
+    #[test]
+    fn display_with_precision() {
+        let b = BigDecimal::new(-123456, 3);
+        assert_eq!(format!("{b:.2}"), "-123.46");
+        assert_eq!(format!("{b:.0}"), "-123");
+        assert_eq!(format!("{b:.5}"), "-123.45600");
+        assert_eq!(format!("{:.2}", BigDecimal::new(7, 0)), "7.00");
+        assert_eq!(format!("{:.1}", BigDecimal::new(-4, 2)), "0.0");
+        assert_eq!(format!("{:.30}", BigDecimal::new(1, 25)), "0.000000000000000000000000100000");
+    }
+
+    #[test]
+    fn display_with_sign_width_and_fill() {
+        let b = BigDecimal::new(150, 2);
+        assert_eq!(format!("{b:+}"), "+1.50");
+        assert_eq!(format!("{b:+08.2}"), "+0001.50");
+        assert_eq!(format!("{b:>7}"), "   1.50");
+        assert_eq!(format!("{b:*<7}"), "1.50***");
+        assert_eq!(format!("{:^9}", -b), "  -1.50  ");
+    }
+
+    #[test]
+    fn display_same_as_decimal() {
+        let decimals = [Decimal::new(-2125, 3), Decimal::new(15, 1), Decimal::new(0, 2), Decimal::new(i32::MIN, 8)];
+        for d in decimals {
+            let b = BigDecimal::from(d);
+            assert_eq!(b.to_string(), d.to_string());
+            assert_eq!(format!("{b:.2}"), format!("{d:.2}"));
+            assert_eq!(format!("{b:+012.10}"), format!("{d:+012.10}"));
+            assert_eq!(format!("{b:_^15}"), format!("{d:_^15}"));
+        }
+    }
+}
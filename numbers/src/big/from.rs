@@ -0,0 +1,215 @@
+use super::{magnitude, BigDecimal};
+use crate::{BadFormatKind, Decimal, DecimalError, MAX_SCALING};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+
+impl From<i32> for BigDecimal {
+    fn from(int: i32) -> Self {
+        Self::new(int as i128, 0)
+    }
+}
+
+impl From<i64> for BigDecimal {
+    fn from(int: i64) -> Self {
+        Self::new(int as i128, 0)
+    }
+}
+
+/// Create a new big decimal number from a decimal number (without any loss of precision)
+impl From<Decimal> for BigDecimal {
+    fn from(decimal: Decimal) -> Self {
+        Self::new(decimal.coefficient as i128, decimal.scaling as u32)
+    }
+}
+
+impl From<&str> for BigDecimal {
+    fn from(value: &str) -> Self {
+        value.parse::<BigDecimal>().unwrap()
+    }
+}
+
+
+
+impl FromStr for BigDecimal {
+    type Err = DecimalError;
+
+    /// Parse a big decimal number, accepting the same format as [`Decimal`] does, but for the
+    /// count of digits (which is unbounded).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() {
+            return Err(DecimalError::BadFormat { position: 0, kind: BadFormatKind::Empty });
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut scaling: u32 = 0;
+        let mut minus_encountered = false;
+        let mut dot_encountered = false;
+
+        for (i, &c) in bytes.iter().enumerate() {
+            if c == b'+' && i > 0 {
+                return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::MisplacedPlus });
+            }
+            else if c == b'-' {
+                if i > 0 {
+                    return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::MisplacedMinus });
+                }
+                minus_encountered = true;
+            }
+            else if c == b'.' {
+                if dot_encountered {
+                    return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::DoubleDot });
+                }
+                dot_encountered = true;
+            }
+            else if c.is_ascii_digit() {
+                digits.push(c - b'0');
+                if dot_encountered {
+                    scaling += 1;
+                }
+            }
+            else {
+                return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::InvalidCharacter });
+            }
+        }
+
+        // Group the digits into limbs of 9 digits each, starting from the least significant ones
+        let limbs =
+            digits
+                .rchunks(magnitude::LIMB_DIGITS as usize)
+                .map(|chunk| chunk.iter().fold(0u32, |acc, &d| acc * 10 + d as u32))
+                .collect();
+
+        Ok(BigDecimal::from_parts(minus_encountered, limbs, scaling))
+    }
+}
+
+
+
+/// Attempt to create a new decimal number from a big decimal number.
+///
+/// If either the scaling factor or the coefficient is out of range, trailing zeros are dropped
+/// (without any loss of precision) as long as possible. It returns one of the following errors:
+///
+/// - [`DecimalError::ScalingOverflow`]<br>
+///   If the scaling factor still exceeds [`MAX_SCALING`](crate::MAX_SCALING)
+///
+/// - [`DecimalError::CoefficientOverflow`]<br>
+///   If the coefficient still exceeds [`MAX_COEFFICIENT`](crate::MAX_COEFFICIENT)
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_numbers::{BigDecimal, Decimal, DecimalError};
+/// let b = BigDecimal::from("1234.5600000000000000000000000000000000000000000");
+/// assert_eq!(Decimal::try_from(&b).unwrap().to_string(), "1234.560000");
+///
+/// let b = BigDecimal::from("123456789012345678901234567890");
+/// assert!(matches!(Decimal::try_from(&b).unwrap_err(), DecimalError::CoefficientOverflow));
+/// ```
+impl TryFrom<&BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        // Drop the trailing zeros that wouldn't fit a 128 bits integer anyway
+        let excess = magnitude::digits(&value.magnitude).saturating_sub(38);
+        let droppable = magnitude::trailing_zeros(&value.magnitude, value.scaling);
+        if excess > droppable {
+            // As for decimal numbers, too many digits after the decimal point (which cannot be
+            // dropped) are reported as a scaling overflow, before the coefficient is considered
+            return if value.scaling - droppable > MAX_SCALING as u32 {
+                Err(DecimalError::ScalingOverflow)
+            }
+            else {
+                Err(DecimalError::CoefficientOverflow)
+            };
+        }
+        let (limbs, _) = magnitude::shift_down(&value.magnitude, excess);
+
+        let abs = magnitude::to_u128(&limbs).ok_or(DecimalError::CoefficientOverflow)? as i128;
+        let coefficient = if value.negative { -abs } else { abs };
+        Decimal::try_from_wide(coefficient, value.scaling - excess)
+    }
+}
+
+/// Same as the conversion from a reference to a big decimal number
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Decimal::try_from(&value)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MIN_COEFFICIENT};
+
+    #[test]
+    fn from_decimal() {
+        let b = BigDecimal::from(Decimal::new(MIN_COEFFICIENT, MAX_SCALING));
+        assert!(b.negative);
+        assert_eq!(magnitude::to_u128(&b.magnitude), Some(MIN_COEFFICIENT.unsigned_abs() as u128));
+        assert_eq!(b.scaling, MAX_SCALING as u32);
+    }
+
+    #[test]
+    fn from_str_many_digits() {
+        let b = BigDecimal::from_str("-1234567890123456789.0123456789").unwrap();
+        assert!(b.negative);
+        assert_eq!(b.magnitude, [123456789, 234567890, 345678901, 12]);
+        assert_eq!(b.scaling, 10);
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert_eq!(BigDecimal::from_str("").err().unwrap(), DecimalError::BadFormat { position: 0, kind: BadFormatKind::Empty });
+        assert_eq!(BigDecimal::from_str("1.?34").err().unwrap(), DecimalError::BadFormat { position: 2, kind: BadFormatKind::InvalidCharacter });
+        assert_eq!(BigDecimal::from_str("1.+34").err().unwrap(), DecimalError::BadFormat { position: 2, kind: BadFormatKind::MisplacedPlus });
+        assert_eq!(BigDecimal::from_str("1.34-").err().unwrap(), DecimalError::BadFormat { position: 4, kind: BadFormatKind::MisplacedMinus });
+        assert_eq!(BigDecimal::from_str("1.234.56").err().unwrap(), DecimalError::BadFormat { position: 5, kind: BadFormatKind::DoubleDot });
+    }
+
+    #[test]
+    fn try_into_decimal_boundaries() {
+        for (coefficient, scaling) in [(MIN_COEFFICIENT, 0), (MAX_COEFFICIENT, MAX_SCALING)] {
+            let b = BigDecimal::from(Decimal::new(coefficient, scaling));
+            let d = Decimal::try_from(&b).unwrap();
+            assert_eq!((d.coefficient, d.scaling), (coefficient, scaling));
+        }
+    }
+
+    #[test]
+    fn try_into_decimal_dropping_trailing_zeros() {
+        let b = BigDecimal::new(MAX_COEFFICIENT as i128, 2).upscale_by(100);
+        let d = Decimal::try_from(b).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (MAX_COEFFICIENT, 2));
+    }
+
+    #[test]
+    fn try_into_decimal_coefficient_overflow_err() {
+        let b = BigDecimal::new(MAX_COEFFICIENT as i128 + 1, 0);
+        assert!(matches!(Decimal::try_from(b).err().unwrap(), DecimalError::CoefficientOverflow));
+    }
+
+    #[test]
+    fn try_into_decimal_scaling_overflow_err() {
+        let b = BigDecimal::new(1, MAX_SCALING as u32 + 1);
+        assert!(matches!(Decimal::try_from(b).err().unwrap(), DecimalError::ScalingOverflow));
+    }
+
+    #[test]
+    fn try_into_decimal_scaling_overflow_err_with_too_many_digits() {
+        // The coefficient exceeds 128 bits because of the digits after the decimal point only
+        let b = BigDecimal::from("1.0000000000000000000000000000000000000000001");
+        assert!(matches!(Decimal::try_from(&b).err().unwrap(), DecimalError::ScalingOverflow));
+        assert!(matches!(Decimal::try_from(-b).err().unwrap(), DecimalError::ScalingOverflow));
+
+        // Unless they are trailing zeros, that can be dropped
+        let b = BigDecimal::from("1.0000000000000000000000000000000000000000000");
+        assert_eq!(Decimal::try_from(b), Ok(Decimal::new(1, 0)));
+    }
+}
@@ -0,0 +1,243 @@
+// Arithmetic on unsigned integers of arbitrary size, represented by their "digits" in base 10^9
+// (named limbs), with the least significant limb first. Using a power of ten as the base makes
+// scaling by powers of ten and printing of decimal digits cheap, while the product of any two
+// limbs (plus carries) still fits a 64 bits integer.
+//
+// All the functions expect normalized limbs (with no most significant zero limbs), and return
+// normalized limbs as well. Zero is represented by no limbs at all.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+pub(super) const BASE: u32 = 1_000_000_000;
+pub(super) const LIMB_DIGITS: u32 = 9;
+
+
+pub(super) fn normalize(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+pub(super) fn from_u128(mut n: u128) -> Vec<u32> {
+    let mut limbs = Vec::new();
+    while n > 0 {
+        limbs.push((n % BASE as u128) as u32);
+        n /= BASE as u128;
+    }
+    limbs
+}
+
+pub(super) fn to_u128(limbs: &[u32]) -> Option<u128> {
+    limbs
+        .iter()
+        .rev()
+        .try_fold(0u128, |acc, &limb| acc.checked_mul(BASE as u128)?.checked_add(limb as u128))
+}
+
+pub(super) fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub(super) fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+    for (i, &limb) in long.iter().enumerate() {
+        let s = limb + short.get(i).copied().unwrap_or(0) + carry;
+        carry = s / BASE;
+        sum.push(s % BASE);
+    }
+    if carry > 0 {
+        sum.push(carry);
+    }
+    sum
+}
+
+// It requires a to be greater than (or equal to) b
+pub(super) fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        let subtrahend = b.get(i).copied().unwrap_or(0) + borrow;
+        if limb >= subtrahend {
+            difference.push(limb - subtrahend);
+            borrow = 0;
+        }
+        else {
+            difference.push(limb + BASE - subtrahend);
+            borrow = 1;
+        }
+    }
+    normalize(difference)
+}
+
+pub(super) fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let p = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = (p % BASE as u64) as u32;
+            carry = p / BASE as u64;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    normalize(product)
+}
+
+fn mul_small(a: &[u32], m: u32) -> Vec<u32> {
+    mul(a, &from_u128(m as u128))
+}
+
+fn div_small(a: &[u32], d: u32) -> Vec<u32> {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for (i, &limb) in a.iter().enumerate().rev() {
+        let n = remainder * BASE as u64 + limb as u64;
+        quotient[i] = (n / d as u64) as u32;
+        remainder = n % d as u64;
+    }
+    normalize(quotient)
+}
+
+// Multiply by 10^amount (appending as many trailing zeros)
+pub(super) fn shift_up(a: &[u32], amount: u32) -> Vec<u32> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let mut shifted = vec![0u32; (amount / LIMB_DIGITS) as usize];
+    shifted.extend_from_slice(a);
+    mul_small(&shifted, 10u32.pow(amount % LIMB_DIGITS))
+}
+
+// Divide by 10^amount (dropping as many trailing digits), and also return the most significant
+// of the dropped digits (which is what rounding modes are based on)
+pub(super) fn shift_down(a: &[u32], amount: u32) -> (Vec<u32>, u32) {
+    if amount == 0 {
+        return (a.to_vec(), 0);
+    }
+    let position = amount - 1;
+    let dropped_digit =
+        a.get((position / LIMB_DIGITS) as usize)
+            .map_or(0, |limb| (limb / 10u32.pow(position % LIMB_DIGITS)) % 10);
+
+    let whole_limbs = (amount / LIMB_DIGITS) as usize;
+    if whole_limbs >= a.len() {
+        return (Vec::new(), dropped_digit);
+    }
+    (div_small(&a[whole_limbs..], 10u32.pow(amount % LIMB_DIGITS)), dropped_digit)
+}
+
+// Count the (decimal) digits
+pub(super) fn digits(a: &[u32]) -> u32 {
+    match a.last() {
+        None => 0,
+        Some(&top) => (a.len() as u32 - 1) * LIMB_DIGITS + top.ilog10() + 1
+    }
+}
+
+// Count the trailing (decimal) zeros, but not more than the given limit
+pub(super) fn trailing_zeros(a: &[u32], limit: u32) -> u32 {
+    let mut count = 0;
+    for &limb in a {
+        if limb == 0 {
+            count += LIMB_DIGITS;
+        }
+        else {
+            let mut limb = limb;
+            while limb % 10 == 0 {
+                limb /= 10;
+                count += 1;
+            }
+            break;
+        }
+        if count >= limit {
+            break;
+        }
+    }
+    count.min(limit)
+}
+
+
+// Divide the product of the numerator factors (multiplied by 10^exponent) by the product of the
+// denominator factors, truncating the quotient, unless it overflows 64 bits (or the denominator
+// is zero). The quotient is found bit by bit, as the greatest one whose product by the
+// denominator doesn't exceed the numerator.
+pub(crate) fn quotient_of_products(numerator: &[u128], exponent: u32, denominator: &[u128]) -> Option<u64> {
+    let product = |factors: &[u128]| factors.iter().fold(from_u128(1), |acc, &f| mul(&acc, &from_u128(f)));
+    let (numerator, denominator) = (shift_up(&product(numerator), exponent), product(denominator));
+    if denominator.is_empty() {
+        return None;
+    }
+
+    let fits = |q: u128| cmp(&mul(&denominator, &from_u128(q)), &numerator) != Ordering::Greater;
+    let quotient = (0..64).rev().fold(0u64, |q, bit| if fits((q | 1 << bit) as u128) { q | 1 << bit } else { q });
+    (!fits(quotient as u128 + 1)).then_some(quotient)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_and_to_u128() {
+        for n in [0, 1, 999_999_999, 1_000_000_000, u64::MAX as u128, u128::MAX] {
+            assert_eq!(to_u128(&from_u128(n)), Some(n));
+        }
+        assert_eq!(to_u128(&[0, 0, 0, 0, 0, 1]), None);
+    }
+
+    #[test]
+    fn add_and_sub_with_carry() {
+        let a = from_u128(999_999_999_999_999_999);
+        let b = from_u128(1);
+        let sum = add(&a, &b);
+        assert_eq!(sum, [0, 0, 1]);
+        assert_eq!(sub(&sum, &b), a);
+        assert_eq!(sub(&sum, &sum), []);
+    }
+
+    #[test]
+    fn mul_large() {
+        let a = from_u128(u64::MAX as u128);
+        assert_eq!(to_u128(&mul(&a, &a)), Some(u64::MAX as u128 * u64::MAX as u128));
+    }
+
+    #[test]
+    fn shift_up_and_down() {
+        let a = from_u128(123456789012);
+        let shifted = shift_up(&a, 20);
+        assert_eq!(trailing_zeros(&shifted, u32::MAX), 20);
+        assert_eq!(shift_down(&shifted, 20), (a.clone(), 0));
+        assert_eq!(shift_down(&a, 2), (from_u128(1234567890), 1));
+        assert_eq!(shift_down(&a, 11), (from_u128(1), 2));
+        assert_eq!(shift_down(&a, 12), (Vec::new(), 1));
+        assert_eq!(shift_down(&a, 30), (Vec::new(), 0));
+    }
+
+    #[test]
+    fn quotient_of_products_truncated() {
+        // 22 * 10^3 / (3 * 2) = 3666.66...
+        assert_eq!(quotient_of_products(&[2, 11], 3, &[3, 2]), Some(3666));
+        assert_eq!(quotient_of_products(&[u128::MAX, u128::MAX], 0, &[u128::MAX, u128::MAX]), Some(1));
+        assert_eq!(quotient_of_products(&[u128::MAX], 0, &[1]), None);
+        assert_eq!(quotient_of_products(&[1], 0, &[0]), None);
+    }
+
+    #[test]
+    fn digits_count() {
+        assert_eq!(digits(&[]), 0);
+        assert_eq!(digits(&from_u128(7)), 1);
+        assert_eq!(digits(&from_u128(1_000_000_000)), 10);
+        assert_eq!(digits(&from_u128(u128::MAX)), 39);
+    }
+}
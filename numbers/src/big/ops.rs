@@ -0,0 +1,208 @@
+use super::{magnitude, BigDecimal};
+use crate::Decimal;
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg};
+
+
+impl PartialEq for BigDecimal {
+
+    /// Tests for `self` and `other` values to be equal, and is used by `==`.
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = self.align(other);
+        a.negative == b.negative && a.magnitude == b.magnitude
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+
+    /// Compares the values of `self` and `other` (regardless of their scaling factors), and is
+    /// used by `<`, `<=`, `>` and `>=`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::BigDecimal;
+    /// assert!(BigDecimal::from("12.34") < BigDecimal::from("12.341"));
+    /// assert!(BigDecimal::from("-5") < BigDecimal::from("-4.9"));
+    /// assert_eq!(BigDecimal::new(15, 1).max(BigDecimal::new(150, 2)), BigDecimal::new(15, 1));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = self.align(other);
+        match (a.negative, b.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => magnitude::cmp(&a.magnitude, &b.magnitude),
+            (true, true) => magnitude::cmp(&b.magnitude, &a.magnitude),
+        }
+    }
+}
+
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// Negate this big decimal number (which can never overflow).
+    fn neg(self) -> Self::Output {
+        BigDecimal::from_parts(!self.negative, self.magnitude.clone(), self.scaling)
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    /// Negate this big decimal number (which can never overflow).
+    fn neg(self) -> Self::Output {
+        BigDecimal::from_parts(!self.negative, self.magnitude, self.scaling)
+    }
+}
+
+
+impl Add for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// Add this big decimal number to the other (which can never overflow).
+    ///
+    /// Both big decimal numbers are aligned to the greater of their scaling factors.
+    fn add(self, rhs: Self) -> Self::Output {
+        let (a, b) = self.align(rhs);
+        if a.negative == b.negative {
+            return BigDecimal::from_parts(a.negative, magnitude::add(&a.magnitude, &b.magnitude), a.scaling);
+        }
+        // Opposite signs: subtract the smaller magnitude from the greater one
+        match magnitude::cmp(&a.magnitude, &b.magnitude) {
+            Ordering::Less =>
+                BigDecimal::from_parts(b.negative, magnitude::sub(&b.magnitude, &a.magnitude), a.scaling),
+            _ =>
+                BigDecimal::from_parts(a.negative, magnitude::sub(&a.magnitude, &b.magnitude), a.scaling),
+        }
+    }
+}
+
+impl Add for BigDecimal {
+    type Output = BigDecimal;
+
+    /// Add this big decimal number to the other (which can never overflow).
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+
+impl Mul for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// Multiply this big decimal number by the other (which can never overflow).
+    ///
+    /// The result is exact, with its scaling factor being the sum of both scaling factors.
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigDecimal::from_parts(
+            self.negative != rhs.negative,
+            magnitude::mul(&self.magnitude, &rhs.magnitude),
+            self.scaling + rhs.scaling
+        )
+    }
+}
+
+impl Mul for BigDecimal {
+    type Output = BigDecimal;
+
+    /// Multiply this big decimal number by the other (which can never overflow).
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+
+impl Sum for BigDecimal {
+    fn sum<I: Iterator<Item = BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::new(0, 0), |acc, b| &acc + &b)
+    }
+}
+
+impl<'a> Sum<&'a BigDecimal> for BigDecimal {
+    fn sum<I: Iterator<Item = &'a BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::new(0, 0), |acc, b| &acc + b)
+    }
+}
+
+/// Sum decimal numbers exactly (without any overflow)
+impl Sum<Decimal> for BigDecimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::new(0, 0), |acc, d| &acc + &BigDecimal::from(d))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MIN_COEFFICIENT};
+
+    #[test]
+    fn eq() {
+        assert_eq!(BigDecimal::new(123456, 2), BigDecimal::new(1234560, 3));
+        assert_ne!(BigDecimal::new(1234560, 2), BigDecimal::new(1234560, 3));
+        assert_ne!(BigDecimal::new(10, 0), BigDecimal::new(1, 0));
+        assert_ne!(BigDecimal::new(1, 0), BigDecimal::new(-1, 0));
+    }
+
+    #[test]
+    fn cmp() {
+        assert!(BigDecimal::new(123456, 2) < BigDecimal::new(1234561, 3));
+        assert!(BigDecimal::new(-1, 30) < BigDecimal::new(0, 0));
+        assert!(BigDecimal::new(-10, 0) < BigDecimal::new(-1, 0));
+        assert!(BigDecimal::new(i128::MAX, 0) > BigDecimal::new(i128::MAX, 1));
+        assert_eq!(BigDecimal::new(0, 5).cmp(&-BigDecimal::new(0, 1)), Ordering::Equal);
+
+        // Same as for decimal numbers
+        let decimals = [Decimal::new(-2125, 3), Decimal::new(15, 1), Decimal::new(0, 2), Decimal::new(-2, 0)];
+        for d1 in decimals {
+            for d2 in decimals {
+                assert_eq!(BigDecimal::from(d1).cmp(&BigDecimal::from(d2)), d1.cmp(&d2));
+            }
+        }
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-BigDecimal::new(MIN_COEFFICIENT as i128, 2), BigDecimal::new(-(MIN_COEFFICIENT as i128), 2));
+        assert!(!(-BigDecimal::new(0, 2)).negative);
+    }
+
+    #[test]
+    fn add_same_signs() {
+        let b = BigDecimal::new(999_999_999, 0) + BigDecimal::new(1, 2);
+        assert_eq!(b, BigDecimal::new(99_999_999_901, 2));
+    }
+
+    #[test]
+    fn add_opposite_signs() {
+        let b = BigDecimal::new(1, 2) + BigDecimal::new(-1_000_000_000, 0);
+        assert_eq!(b, BigDecimal::new(-99_999_999_999, 2));
+
+        let b = BigDecimal::new(-5, 1) + BigDecimal::new(50, 2);
+        assert_eq!(b, BigDecimal::new(0, 0));
+        assert!(!b.negative);
+    }
+
+    #[test]
+    fn mul_exact() {
+        let b = BigDecimal::new(MAX_COEFFICIENT as i128, 8) * BigDecimal::new(-(MAX_COEFFICIENT as i128), 8);
+        assert_eq!(b, BigDecimal::new(-(MAX_COEFFICIENT as i128 * MAX_COEFFICIENT as i128), 16));
+    }
+
+    #[test]
+    fn sum_decimals_without_overflow() {
+        let total: BigDecimal = core::iter::repeat_n(Decimal::new(MAX_COEFFICIENT, 8), 1000).sum();
+        assert_eq!(total, BigDecimal::new(MAX_COEFFICIENT as i128 * 1000, 8));
+    }
+}
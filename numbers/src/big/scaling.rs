@@ -0,0 +1,103 @@
+use super::{magnitude, BigDecimal};
+use crate::RoundingMode;
+
+
+impl BigDecimal {
+
+    /// Return true if this big decimal number is aligned to another big decimal number.
+    ///
+    /// Two big decimal numbers are considered aligned if they have the same scaling factor.
+    pub fn is_aligned_to(&self, other: &BigDecimal) -> bool {
+        self.scaling == other.scaling
+    }
+
+    /// Increase the scaling factor of this big decimal number by the given amount.
+    ///
+    /// Differently from [`Decimal::try_upscale_by`](crate::Decimal::try_upscale_by), this method
+    /// never fails, as the coefficient is unbounded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::BigDecimal;
+    /// let b = BigDecimal::new(123591, 3);
+    /// assert_eq!(b.upscale_by(20).to_string(), "123.59100000000000000000000");
+    /// ```
+    pub fn upscale_by(&self, amount: u32) -> Self {
+        BigDecimal {
+            negative: self.negative,
+            magnitude: magnitude::shift_up(&self.magnitude, amount),
+            scaling: self.scaling + amount,
+        }
+    }
+
+    /// Decrease the scaling factor of this big decimal number by the given amount and by applying
+    /// the given rounding mode.
+    ///
+    /// <div class="warning">This method may incur into a loss of precision.</div>
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{BigDecimal, RoundingMode};
+    /// let b = BigDecimal::from("-123.591");
+    /// let rm = RoundingMode::HalfUp;
+    /// assert_eq!(b.downscale_by(2, &rm).to_string(), "-123.6");
+    /// ```
+    pub fn downscale_by(&self, amount: u32, rounding_mode: &RoundingMode) -> Self {
+        let amount = amount.min(self.scaling);
+        let (truncated, dropped_digit) = magnitude::shift_down(&self.magnitude, amount);
+        let rounded = match rounding_mode {
+            RoundingMode::HalfUp =>
+                if dropped_digit >= 5 { magnitude::add(&truncated, &[1]) } else { truncated }
+        };
+        BigDecimal::from_parts(self.negative, rounded, self.scaling - amount)
+    }
+
+    // Return both big decimal numbers aligned to the greater of their scaling factors
+    pub(super) fn align(&self, other: &BigDecimal) -> (BigDecimal, BigDecimal) {
+        let scaling = self.scaling.max(other.scaling);
+        (self.upscale_by(scaling - self.scaling), other.upscale_by(scaling - other.scaling))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    fn upscale_by_30() {
+        let b = BigDecimal::new(-123456, 2).upscale_by(30);
+        assert!(b.negative);
+        assert_eq!(b.scaling, 32);
+        assert_eq!(magnitude::trailing_zeros(&b.magnitude, u32::MAX), 30);
+    }
+
+    #[test]
+    fn downscale_by_rounding_half_up() {
+        let b = BigDecimal::new(123456789, 7).downscale_by(5, &RM);
+        assert_eq!(b.magnitude, [1235]);
+        assert_eq!(b.scaling, 2);
+
+        let b = BigDecimal::new(-121, 1).downscale_by(1, &RM);
+        assert!(b.negative);
+        assert_eq!(b.magnitude, [12]);
+        assert_eq!(b.scaling, 0);
+    }
+
+    #[test]
+    fn downscale_by_more_than_scaling() {
+        let b = BigDecimal::new(15, 1).downscale_by(5, &RM);
+        assert_eq!(b.magnitude, [2]);
+        assert_eq!(b.scaling, 0);
+    }
+
+    #[test]
+    fn downscale_to_zero() {
+        let b = BigDecimal::new(-4, 1).downscale_by(1, &RM);
+        assert!(!b.negative);
+        assert!(b.magnitude.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+use core::fmt;
+
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// Representation of a complex number in cartesian form
+///
+/// Complex numbers are internally represented by their real and imaginary parts, such that the
+/// actual number is given by:
+///
+/// $$
+/// re + im \cdot i \quad \text{where} \quad i^2 = -1
+/// $$
+///
+/// # Usage
+/// To create a complex number, you can:
+/// - either invoke the [`Complex::new`] factory function,
+/// - or invoke the [`Complex::from_polar`] factory function (given its magnitude and argument),
+/// - or parse a string such as `"1.5-2i"`
+///
+/// ```rust
+/// use beaumont_numbers::Complex;
+///
+/// let z1 = Complex::new(1.5, -2.0);                  // real and imaginary parts
+/// let z2: Complex<f64> = "1.5-2i".parse().unwrap();   // string
+/// assert_eq!(z1, z2);
+/// # #[cfg(feature = "std")] {
+/// let z3 = Complex::from_polar(2.0, 0.0);             // magnitude and argument
+/// assert_eq!(z3, Complex::new(2.0, 0.0));
+/// # }
+/// ```
+///
+/// Once created, you can apply arithmetic operations, as well as elementary functions (such as
+/// [`Complex::exp`], [`Complex::ln`] and [`Complex::powc`]). Complex numbers satisfy the bounds
+/// required by the operations of mathematical vectors (such as adding, scaling and the dot
+/// product) as well.
+///
+/// ```rust
+/// # use beaumont_numbers::Complex;
+/// let z = Complex::new(1.0, 2.0) * Complex::new(3.0, -1.0);
+/// assert_eq!(z.to_string(), "5+5i");
+/// assert_eq!(z.conj().to_string(), "5-5i");
+/// ```
+///
+/// # Memory
+/// Same as decimal numbers, complex numbers are stack-allocated, and provided with copy semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    re: T,
+    im: T,
+}
+
+
+impl<T> Complex<T> where T: Copy {
+
+    /// Create a new complex number from its real and imaginary parts
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the real part of this complex number
+    pub fn re(&self) -> T {
+        self.re
+    }
+
+    /// Returns the imaginary part of this complex number
+    pub fn im(&self) -> T {
+        self.im
+    }
+
+    /// Returns the complex conjugate of this complex number (having its imaginary part negated)
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Complex;
+    /// let z = Complex::new(1, 2);
+    /// assert_eq!(z.conj(), Complex::new(1, -2));
+    /// ```
+    pub fn conj(self) -> Self where T: core::ops::Neg<Output = T> {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// Returns the squared magnitude of this complex number (which doesn't require a square root)
+    pub fn norm_sqr(&self) -> T where T: core::ops::Add<Output = T> + core::ops::Mul<Output = T> {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+
+/// Create a new complex number having the given real part, and a zero imaginary part
+impl<T> From<T> for Complex<T> where T: Copy + Default {
+    fn from(re: T) -> Self {
+        Self { re, im: T::default() }
+    }
+}
+
+
+/// Error returned when a complex number cannot be parsed (see [`Complex`]), which wraps the error
+/// returned when parsing either of its parts.
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_numbers::{Complex, Decimal, DecimalError, BadFormatKind, ParseComplexError};
+/// let err = "1.5+2.x5i".parse::<Complex<Decimal>>().unwrap_err();
+/// let part_err = DecimalError::BadFormat { position: 2, kind: BadFormatKind::InvalidCharacter };
+/// assert_eq!(err, ParseComplexError::InvalidPart { position: 3, error: part_err });
+/// assert_eq!(err.to_string(), "Invalid part at position 3 (Invalid character at position 2)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseComplexError<E> {
+    /// Error due to an empty string
+    Empty,
+    /// Error due to a part that cannot be parsed
+    InvalidPart {
+        /// The position (offset in bytes) of the input where the part begins
+        position: usize,
+        /// The error returned when parsing the part
+        error: E
+    }
+}
+
+impl<E> fmt::Display for ParseComplexError<E> where E: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseComplexError::Empty => write!(f, "Empty string"),
+            ParseComplexError::InvalidPart { position, error } => write!(f, "Invalid part at position {position} ({error})"),
+        }
+    }
+}
+
+impl<E> core::error::Error for ParseComplexError<E> where E: core::error::Error + 'static {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseComplexError::Empty => None,
+            ParseComplexError::InvalidPart { error, .. } => Some(error),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let z = Complex::new(1.5, -2.0);
+        assert_eq!(z.re(), 1.5);
+        assert_eq!(z.im(), -2.0);
+    }
+
+    #[test]
+    fn from_real() {
+        assert_eq!(Complex::from(3), Complex::new(3, 0));
+    }
+
+    #[test]
+    fn conj_and_norm_sqr() {
+        let z = Complex::new(3, -4);
+        assert_eq!(z.conj(), Complex::new(3, 4));
+        assert_eq!(z.norm_sqr(), 25);
+    }
+}
+
+
+mod ops;
+mod from;
+mod display;
+#[cfg(feature = "std")]
+mod real;
@@ -0,0 +1,89 @@
+use super::Complex;
+use core::fmt;
+use core::fmt::Write;
+
+impl<T> fmt::Display for Complex<T> where T: Copy + fmt::Display {
+
+    // Complex numbers are written in cartesian form (such as "1.5-2i"), which can be parsed back.
+    // Formatting flags (such as the precision) are applied to both parts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.re, f)?;
+        // The imaginary part writes its own minus sign (and its own plus sign, given the flag)
+        if !f.sign_plus() && !is_written_negative(&self.im) {
+            f.write_char('+')?;
+        }
+        fmt::Display::fmt(&self.im, f)?;
+        f.write_char('i')
+    }
+}
+
+// Whether the given value is written with a leading minus sign. The sign is taken from the written
+// value (rather than from a comparison with zero), so that negative zeros are written as "-0".
+fn is_written_negative<T>(value: &T) -> bool where T: fmt::Display {
+    // Only the first character is written, and then writing is interrupted
+    struct FirstChar(Option<char>);
+    impl Write for FirstChar {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 = self.0.or(s.chars().next());
+            if self.0.is_some() { Err(fmt::Error) } else { Ok(()) }
+        }
+    }
+
+    let mut first = FirstChar(None);
+    let _ = write!(first, "{value}");
+    first.0 == Some('-')
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Decimal, MIN_COEFFICIENT};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+        assert_eq!(Complex::new(-1, 2).to_string(), "-1+2i");
+        assert_eq!(Complex::new(0, 0).to_string(), "0+0i");
+    }
+
+    #[test]
+    fn display_with_precision() {
+        assert_eq!(format!("{:.2}", Complex::new(1.0, -1.0 / 3.0)), "1.00-0.33i");
+        assert_eq!(format!("{:+}", Complex::new(1, 2)), "+1+2i");
+        assert_eq!(format!("{:+}", Complex::new(1, -2)), "+1-2i");
+    }
+
+    #[test]
+    fn display_minimal_imaginary_parts() {
+        // The imaginary parts cannot be negated, and they are written as they are
+        assert_eq!(Complex::new(0, i32::MIN).to_string(), "0-2147483648i");
+        let min = Decimal::new(MIN_COEFFICIENT, 2);
+        assert_eq!(Complex::new(Decimal::new(1, 0), min).to_string(), "1-21474836.48i");
+    }
+
+    #[test]
+    fn display_negative_zeros() {
+        assert_eq!(Complex::new(1.0, 0.0).conj().to_string(), "1-0i");
+        assert_eq!(Complex::new(-0.0, -0.0).to_string(), "-0-0i");
+        assert_eq!(format!("{:.1}", Complex::new(1.0, -0.01)), "1.0-0.0i");
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let values = [
+            Complex::new(-0.125, 1e-10),
+            Complex::new(1.0, 0.0).conj(),
+            Complex::new(-0.0, -0.0),
+            Complex::new(0.0, -2.5),
+        ];
+        for z in values {
+            let parsed: Complex<f64> = z.to_string().parse().unwrap();
+            assert_eq!(parsed, z);
+            assert_eq!(parsed.im().is_sign_negative(), z.im().is_sign_negative());
+            assert_eq!(parsed.re().is_sign_negative(), z.re().is_sign_negative());
+        }
+    }
+}
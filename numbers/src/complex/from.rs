@@ -0,0 +1,107 @@
+use super::{Complex, ParseComplexError};
+use core::str::FromStr;
+
+
+impl<T> FromStr for Complex<T> where T: Copy + FromStr {
+    type Err = ParseComplexError<T::Err>;
+
+    /// Parse a complex number written in cartesian form, such as `"1.5-2i"`, where either part
+    /// can be omitted (such as in `"1.5"`, `"-2i"` or `"i"`), and each part is parsed by `T`.
+    ///
+    /// If a part cannot be parsed, a [`ParseComplexError::InvalidPart`] is returned with the
+    /// position of where that part begins, wrapping the error returned by `T`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseComplexError::Empty);
+        }
+        let (re, im, im_position) = match s.strip_suffix('i') {
+            None => (s, "0", 0),
+            Some(body) => {
+                // Split at the last sign, unless it leads the whole body or an exponent
+                let bytes = body.as_bytes();
+                let split =
+                    (1..bytes.len())
+                        .rev()
+                        .find(|&i| matches!(bytes[i], b'+' | b'-') && !matches!(bytes[i - 1], b'e' | b'E'));
+                match split {
+                    Some(i) => (&body[..i], &body[i..], i),
+                    None => ("0", body, 0),
+                }
+            }
+        };
+
+        // The imaginary unit can be written without any coefficient (such as in "1-i")
+        let im = match im {
+            "" | "+" => "1",
+            "-" => "-1",
+            im => im.strip_prefix('+').unwrap_or(im)
+        };
+
+        let invalid_part = |position: usize| move |error| ParseComplexError::InvalidPart { position, error };
+        Ok(Complex {
+            re: re.parse().map_err(invalid_part(0))?,
+            im: im.parse().map_err(invalid_part(im_position))?
+        })
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BadFormatKind, Decimal, DecimalError};
+    use alloc::string::ToString;
+
+    fn parse(s: &str) -> Result<Complex<f64>, ParseComplexError<core::num::ParseFloatError>> {
+        s.parse()
+    }
+
+    #[test]
+    fn from_str_cartesian() {
+        assert_eq!(parse("1.5-2i").unwrap(), Complex::new(1.5, -2.0));
+        assert_eq!(parse("-1.5+2i").unwrap(), Complex::new(-1.5, 2.0));
+        assert_eq!(parse("1e-3+2E+1i").unwrap(), Complex::new(0.001, 20.0));
+    }
+
+    #[test]
+    fn from_str_either_part() {
+        assert_eq!(parse("1.5").unwrap(), Complex::new(1.5, 0.0));
+        assert_eq!(parse("-2i").unwrap(), Complex::new(0.0, -2.0));
+        assert_eq!(parse("+2i").unwrap(), Complex::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn from_str_imaginary_unit() {
+        assert_eq!(parse("i").unwrap(), Complex::new(0.0, 1.0));
+        assert_eq!(parse("-i").unwrap(), Complex::new(0.0, -1.0));
+        assert_eq!(parse("3+i").unwrap(), Complex::new(3.0, 1.0));
+        assert_eq!(parse("3-i").unwrap(), Complex::new(3.0, -1.0));
+    }
+
+    #[test]
+    fn from_str_decimals() {
+        let z: Complex<Decimal> = "1234.56-0.01i".parse().unwrap();
+        assert_eq!(z, Complex::new(Decimal::new(123456, 2), Decimal::new(-1, 2)));
+    }
+
+    #[test]
+    fn from_str_errors() {
+        let position = |s| match parse(s).unwrap_err() {
+            ParseComplexError::InvalidPart { position, .. } => Some(position),
+            ParseComplexError::Empty => None,
+        };
+        assert_eq!(parse("").unwrap_err(), ParseComplexError::Empty);
+        assert_eq!(position("1.x+2i"), Some(0));
+        assert_eq!(position("1+2xi"), Some(1));
+        assert_eq!(position("1+2"), Some(0));
+    }
+
+    #[test]
+    fn from_str_errors_wrap_the_errors_of_the_parts() {
+        let err = "1-2.5.0i".parse::<Complex<Decimal>>().unwrap_err();
+        let part_err = DecimalError::BadFormat { position: 4, kind: BadFormatKind::DoubleDot };
+        assert_eq!(err, ParseComplexError::InvalidPart { position: 1, error: part_err.clone() });
+        assert_eq!(core::error::Error::source(&err).unwrap().to_string(), part_err.to_string());
+    }
+}
@@ -0,0 +1,105 @@
+use super::Complex;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+
+impl<T> Neg for Complex<T> where T: Copy + Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+
+impl<T> Add for Complex<T> where T: Copy + Add<Output = T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+
+impl<T> Sub for Complex<T> where T: Copy + Sub<Output = T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+
+impl<T> Mul for Complex<T> where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> {
+    type Output = Self;
+
+    /// Multiply this complex number by the other: $(a + bi)(c + di) = (ac - bd) + (ad + bc)i$
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re
+        }
+    }
+}
+
+
+impl<T> Div for Complex<T> where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {
+    type Output = Self;
+
+    /// Divide this complex number by the other, by multiplying both by the conjugate of the other:
+    /// $(a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c^2 + d^2)$
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator = rhs.norm_sqr();
+        Complex {
+            re: (self.re * rhs.re + self.im * rhs.im) / denominator,
+            im: (self.im * rhs.re - self.re * rhs.im) / denominator
+        }
+    }
+}
+
+
+impl<T> Sum for Complex<T> where T: Copy + Default + Add<Output = T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Complex::default(), |acc, z| acc + z)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neg() {
+        assert_eq!(-Complex::new(1, -2), Complex::new(-1, 2));
+    }
+
+    #[test]
+    fn add_and_sub() {
+        let (z1, z2) = (Complex::new(1, 2), Complex::new(3, -5));
+        assert_eq!(z1 + z2, Complex::new(4, -3));
+        assert_eq!(z1 - z2, Complex::new(-2, 7));
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(Complex::new(1, 2) * Complex::new(3, -1), Complex::new(5, 5));
+        // i^2 = -1
+        assert_eq!(Complex::new(0, 1) * Complex::new(0, 1), Complex::new(-1, 0));
+    }
+
+    #[test]
+    fn div() {
+        let z = Complex::new(5.0, 5.0) / Complex::new(3.0, -1.0);
+        assert_eq!(z, Complex::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn sum() {
+        let z: Complex<i32> = [Complex::new(1, 2), Complex::new(3, 4)].into_iter().sum();
+        assert_eq!(z, Complex::new(4, 6));
+    }
+}
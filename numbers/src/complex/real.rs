@@ -0,0 +1,114 @@
+// Elementary functions of complex numbers over floats, which require the `std` feature (as the
+// underlying float functions are not available in `core`).
+
+use super::Complex;
+use crate::Real;
+
+
+impl<T> Complex<T> where T: Real {
+
+    /// Create a new complex number from its polar form: $r (\cos \theta + i \sin \theta)$
+    pub fn from_polar(r: T, theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self { re: r * cos, im: r * sin }
+    }
+
+    /// Returns the polar form of this complex number, as its magnitude and argument
+    pub fn to_polar(self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    /// Returns the magnitude (or modulus) of this complex number: $\sqrt{re^2 + im^2}$
+    pub fn abs(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the argument (or phase) of this complex number, in the range $(-\pi, \pi]$
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// Raise $e$ to the power of this complex number: $e^{re} (\cos im + i \sin im)$
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Compute the principal natural logarithm of this complex number: $\ln |z| + i \arg z$
+    pub fn ln(self) -> Self {
+        Self { re: self.abs().ln(), im: self.arg() }
+    }
+
+    /// Raise this complex number to a complex power: $z^w = e^{w \ln z}$
+    ///
+    /// By convention, zero raised to any power is zero (but for the zero power, which is one).
+    pub fn powc(self, exponent: Self) -> Self {
+        let zero = Self::default();
+        if self == zero {
+            return if exponent == zero { Self::from(T::from_f64(1.0)) } else { zero };
+        }
+        (exponent * self.ln()).exp()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+        assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn abs_and_arg() {
+        let z = Complex::new(3.0, -4.0);
+        assert_eq!(z.abs(), 5.0);
+        assert_eq!(Complex::new(0.0, 2.0).arg(), FRAC_PI_2);
+        assert_eq!(Complex::new(-1.0, 0.0).arg(), PI);
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let z = Complex::new(-1.5, 2.5);
+        let (r, theta) = z.to_polar();
+        assert_close(Complex::from_polar(r, theta), z);
+    }
+
+    #[test]
+    fn exp_euler_identity() {
+        // e^(iπ) + 1 = 0
+        let z = Complex::new(0.0, PI).exp() + Complex::new(1.0, 0.0);
+        assert_close(z, Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn ln_inverts_exp() {
+        let z = Complex::new(0.5, -1.25);
+        assert_close(z.exp().ln(), z);
+        assert_close(Complex::new(-1.0, 0.0).ln(), Complex::new(0.0, PI));
+    }
+
+    #[test]
+    fn powc() {
+        // i^i = e^(-π/2)
+        let i = Complex::new(0.0, 1.0);
+        assert_close(i.powc(i), Complex::new((-FRAC_PI_2).exp(), 0.0));
+        // (1 + i)^2 = 2i
+        assert_close(Complex::new(1.0, 1.0).powc(Complex::new(2.0, 0.0)), Complex::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn powc_of_zero() {
+        let zero = Complex::new(0.0, 0.0);
+        assert_eq!(zero.powc(Complex::new(2.0, 1.0)), zero);
+        assert_eq!(zero.powc(zero), Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn f32_functions() {
+        let z = Complex::new(1.0f32, 1.0f32);
+        assert!((z.exp().ln() - z).abs() < 1e-6);
+    }
+}
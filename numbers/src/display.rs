@@ -1,5 +1,7 @@
-use super::Decimal;
-use std::fmt;
+use super::{Decimal, RoundingMode};
+use alloc::string::String;
+use core::fmt;
+use core::iter;
 
 impl fmt::Display for Decimal {
 
@@ -21,6 +23,11 @@ impl fmt::Display for Decimal {
     // digits and the eventual '.' character, which are ASCII characters (no need to
     // use UTF-8 encoding which would require 4 bytes per character).
     //
+    // Formatting flags are supported as for floats: the precision is the number of digits after
+    // the decimal point (rounding half up if fewer than the scaling factor, or appending trailing
+    // zeros if more), while the sign, width, fill and alignment flags are applied to the whole
+    // representation (such as "{:+08.2}" writing "+0001.50").
+    //
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         /*
          * The components of the following array are the digit characters representing the ones for the first
@@ -160,28 +167,38 @@ impl fmt::Display for Decimal {
             }
         }
 
-        #[inline(always)]
-        fn apply_sign(buf: &mut[u8], coefficient: i32, i: usize) -> usize {
-            if coefficient < 0 {
-                buf[i-1] = b'-';
-                i - 1
-            }
-            else {
-                i
-            }
-        }
+        // A precision lower than the scaling factor requires rounding (which cannot overflow,
+        // as the rounded coefficient has fewer digits), while a greater one requires trailing zeros
+        let decimal = match f.precision() {
+            Some(precision) if precision < self.scaling as usize =>
+                self.downscale_by(self.scaling - precision as u8, &RoundingMode::HalfUp),
+            _ => *self
+        };
+        let trailing_zeros = f.precision().map_or(0, |precision| precision - decimal.scaling as usize);
 
-        // These are the 3 steps to easily build the decimal number textual representation:
-        let digits_count = insert_digits(&mut buffer, self.coefficient);
-        let first_index = apply_scaling(&mut buffer, self.scaling, digits_count);
-        let first_index = apply_sign(&mut buffer, self.coefficient, first_index);
+        // These are the 2 steps to easily build the textual representation of the absolute value
+        // of the decimal number (as the sign is written along with the padding, see below):
+        let digits_count = insert_digits(&mut buffer, decimal.coefficient);
+        let first_index = apply_scaling(&mut buffer, decimal.scaling, digits_count);
 
         // There's no concern with the following unsafe conversion because our buffer
         // certainly contains ASCII characters only (see above)
         let representation: &str = unsafe {
-            std::str::from_utf8_unchecked(&buffer[first_index..LEN])
+            core::str::from_utf8_unchecked(&buffer[first_index..LEN])
         };
-        f.write_str(representation)
+        let is_nonnegative = decimal.coefficient >= 0;
+        if trailing_zeros == 0 {
+            return f.pad_integral(is_nonnegative, "", representation);
+        }
+
+        // Only the (uncommon) trailing zeros exceeding the buffer require allocating
+        let mut padded = String::with_capacity(representation.len() + 1 + trailing_zeros);
+        padded.push_str(representation);
+        if decimal.scaling == 0 {
+            padded.push('.');
+        }
+        padded.extend(iter::repeat_n('0', trailing_zeros));
+        f.pad_integral(is_nonnegative, "", &padded)
     }
 }
 
@@ -189,6 +206,9 @@ impl fmt::Display for Decimal {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::MAX_COEFFICIENT;
+    use alloc::format;
+    use alloc::string::ToString;
 
     // ----------
     // UNSCALED scenarios
@@ -280,4 +300,34 @@ mod test {
         let n = Decimal::new(12, 8);
         assert_eq!(n.to_string(), "0.00000012");
     }
+
+    // ----------
+    // FLAGS scenarios
+    // When formatting flags are given, as for floats
+
+    #[test]
+    fn display_with_lower_precision() {
+        let n = Decimal::new(-123456, 3);
+        assert_eq!(format!("{n:.2}"), "-123.46");
+        assert_eq!(format!("{n:.0}"), "-123");
+        assert_eq!(format!("{:.1}", Decimal::new(-4, 2)), "0.0");
+        assert_eq!(format!("{:.2}", Decimal::new(MAX_COEFFICIENT, 3)), "2147483.65");
+    }
+
+    #[test]
+    fn display_with_greater_precision() {
+        assert_eq!(format!("{:.4}", Decimal::new(15, 1)), "1.5000");
+        assert_eq!(format!("{:.2}", Decimal::new(-7, 0)), "-7.00");
+        assert_eq!(format!("{:.1}", Decimal::new(5, 1)), "0.5");
+    }
+
+    #[test]
+    fn display_with_padding_and_sign() {
+        let n = Decimal::new(150, 2);
+        assert_eq!(format!("{n:>7}"), "   1.50");
+        assert_eq!(format!("{n:*<7}"), "1.50***");
+        assert_eq!(format!("{n:+}"), "+1.50");
+        assert_eq!(format!("{n:+08.1}"), "+00001.5");
+        assert_eq!(format!("{:08.3}", Decimal::new(-150, 2)), "-001.500");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,152 @@
+#[cfg_attr(doc, beaumont_macros::allow_katex)]
+/// Representation of a dual number for forward-mode automatic differentiation
+///
+/// Dual numbers extend real numbers with an infinitesimal part $\varepsilon$ such that
+/// $\varepsilon^2 = 0$, and therefore evaluating a function on a dual number yields both its value
+/// and its derivative at once (exactly, rather than by finite differences):
+///
+/// $$
+/// f(x + x' \varepsilon) = f(x) + f'(x) x' \varepsilon
+/// $$
+///
+/// Our dual numbers hold `N` derivatives (one per direction), so that the full gradient of a
+/// function of `N` variables can be computed in a single pass. By default, they hold just one.
+///
+/// # Usage
+/// To create a dual number, you can:
+/// - either invoke the [`Dual::variable`] factory function (to differentiate with respect to it),
+/// - or invoke the [`Dual::variables`] factory function (to compute a gradient),
+/// - or invoke the [`Dual::constant`] factory function
+///
+/// ```rust
+/// use beaumont_numbers::Dual;
+///
+/// // f(x) = x^2 + 3x whose derivative at x = 2 is 7
+/// let x = Dual::variable(2.0);
+/// let y = x * x + Dual::constant(3.0) * x;
+/// assert_eq!(y.value(), 10.0);
+/// assert_eq!(y.derivative(), 7.0);
+///
+/// // f(x, y) = x * y whose gradient at (2, 5) is (5, 2)
+/// let [x, y] = Dual::variables([2.0, 5.0]);
+/// assert_eq!((x * y).derivatives(), [5.0, 2.0]);
+/// ```
+///
+/// Once created, you can apply arithmetic operations as well as elementary functions (such as
+/// [`Dual::exp`], [`Dual::ln`], [`Dual::sqrt`], [`Dual::powf`] and [`Dual::norm_cdf`]) which
+/// make for computing the Greeks of pricing functions. Dual numbers satisfy the bounds required
+/// by the operations of mathematical vectors (such as the dot product) as well.
+///
+/// # Memory
+/// Same as decimal numbers, dual numbers are stack-allocated, and provided with copy semantics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<T, const N: usize = 1> {
+    value: T,
+    derivatives: [T; N],
+}
+
+
+impl<T, const N: usize> Dual<T, N> where T: Copy + Default {
+
+    /// Create a new dual number from the given value and derivatives
+    pub fn new(value: T, derivatives: [T; N]) -> Self {
+        Self { value, derivatives }
+    }
+
+    /// Create a new dual number that doesn't depend on any variable (all of its derivatives are zero)
+    pub fn constant(value: T) -> Self {
+        Self { value, derivatives: [T::default(); N] }
+    }
+
+    /// Create as many variables as directions, each of them seeded with a unit derivative in its
+    /// own direction, so that the derivatives of any function of them make for its gradient.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Dual;
+    /// // f(x, y, z) = x * y + z whose gradient at (2, 5, 7) is (5, 2, 1)
+    /// let [x, y, z] = Dual::variables([2.0, 5.0, 7.0]);
+    /// let f = x * y + z;
+    /// assert_eq!(f.value(), 17.0);
+    /// assert_eq!(f.derivatives(), [5.0, 2.0, 1.0]);
+    /// ```
+    pub fn variables(values: [T; N]) -> [Self; N] where T: From<u8> {
+        core::array::from_fn(|i| {
+            let mut derivatives = [T::default(); N];
+            derivatives[i] = T::from(1);
+            Self { value: values[i], derivatives }
+        })
+    }
+
+    /// Returns the value of this dual number
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the derivatives of this dual number (one per direction)
+    pub fn derivatives(&self) -> [T; N] {
+        self.derivatives
+    }
+}
+
+
+impl<T> Dual<T> where T: Copy + Default + From<u8> {
+
+    /// Create a new variable, seeded with a unit derivative
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Dual;
+    /// let x = Dual::variable(3.0);
+    /// assert_eq!((x * x).derivative(), 6.0);
+    /// ```
+    pub fn variable(value: T) -> Self {
+        Self { value, derivatives: [T::from(1)] }
+    }
+
+    /// Returns the derivative of this dual number
+    pub fn derivative(&self) -> T {
+        self.derivatives[0]
+    }
+}
+
+
+impl<T, const N: usize> Default for Dual<T, N> where T: Copy + Default {
+    fn default() -> Self {
+        Self::constant(T::default())
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constant() {
+        let d: Dual<f64, 2> = Dual::constant(1.5);
+        assert_eq!(d.value(), 1.5);
+        assert_eq!(d.derivatives(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn variable() {
+        let d = Dual::variable(1.5);
+        assert_eq!(d.value(), 1.5);
+        assert_eq!(d.derivative(), 1.0);
+    }
+
+    #[test]
+    fn variables() {
+        let [x, y, z] = Dual::variables([1.0, 2.0, 3.0]);
+        assert_eq!((x.value(), x.derivatives()), (1.0, [1.0, 0.0, 0.0]));
+        assert_eq!((y.value(), y.derivatives()), (2.0, [0.0, 1.0, 0.0]));
+        assert_eq!((z.value(), z.derivatives()), (3.0, [0.0, 0.0, 1.0]));
+    }
+}
+
+
+mod ops;
+#[cfg(feature = "std")]
+mod real;
@@ -0,0 +1,130 @@
+use super::Dual;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+
+impl<T, const N: usize> Neg for Dual<T, N> where T: Copy + Neg<Output = T> {
+    type Output = Self;
+
+    /// Negate this dual number: $-(u + u' \varepsilon) = -u - u' \varepsilon$
+    fn neg(self) -> Self::Output {
+        Dual {
+            value: -self.value,
+            derivatives: self.derivatives.map(|d| -d)
+        }
+    }
+}
+
+
+impl<T, const N: usize> Add for Dual<T, N> where T: Copy + Add<Output = T> {
+    type Output = Self;
+
+    /// Add this dual number to the other: $(u + v)' = u' + v'$
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual {
+            value: self.value + rhs.value,
+            derivatives: core::array::from_fn(|i| self.derivatives[i] + rhs.derivatives[i])
+        }
+    }
+}
+
+
+impl<T, const N: usize> Sub for Dual<T, N> where T: Copy + Sub<Output = T> {
+    type Output = Self;
+
+    /// Subtract the other dual number from this: $(u - v)' = u' - v'$
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual {
+            value: self.value - rhs.value,
+            derivatives: core::array::from_fn(|i| self.derivatives[i] - rhs.derivatives[i])
+        }
+    }
+}
+
+
+impl<T, const N: usize> Mul for Dual<T, N> where T: Copy + Add<Output = T> + Mul<Output = T> {
+    type Output = Self;
+
+    /// Multiply this dual number by the other: $(u v)' = u' v + u v'$
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual {
+            value: self.value * rhs.value,
+            derivatives: core::array::from_fn(|i|
+                self.derivatives[i] * rhs.value + self.value * rhs.derivatives[i]
+            )
+        }
+    }
+}
+
+
+impl<T, const N: usize> Div for Dual<T, N> where T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {
+    type Output = Self;
+
+    /// Divide this dual number by the other: $(u / v)' = (u' - (u / v) v') / v$
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+        Dual {
+            value,
+            derivatives: core::array::from_fn(|i|
+                (self.derivatives[i] - value * rhs.derivatives[i]) / rhs.value
+            )
+        }
+    }
+}
+
+
+impl<T, const N: usize> Sum for Dual<T, N> where T: Copy + Default + Add<Output = T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::default(), |acc, d| acc + d)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neg() {
+        let d = -Dual::new(2.0, [1.0, -3.0]);
+        assert_eq!(d, Dual::new(-2.0, [-1.0, 3.0]));
+    }
+
+    #[test]
+    fn add_and_sub() {
+        let [x, y] = Dual::variables([2, 5]);
+        assert_eq!(x + y, Dual::new(7, [1, 1]));
+        assert_eq!(x - y, Dual::new(-3, [1, -1]));
+    }
+
+    #[test]
+    fn mul() {
+        // f(x) = x^3 whose derivative at x = 2 is 12
+        let x = Dual::variable(2);
+        let f = x * x * x;
+        assert_eq!(f.value(), 8);
+        assert_eq!(f.derivative(), 12);
+    }
+
+    #[test]
+    fn div() {
+        // f(x, y) = x / y whose gradient at (1, 2) is (1/2, -1/4)
+        let [x, y] = Dual::variables([1.0, 2.0]);
+        let f = x / y;
+        assert_eq!(f.value(), 0.5);
+        assert_eq!(f.derivatives(), [0.5, -0.25]);
+    }
+
+    #[test]
+    fn sum() {
+        let [x, y] = Dual::variables([1.0, 2.0]);
+        let f: Dual<f64, 2> = [x, y, x * y].into_iter().sum();
+        assert_eq!(f, Dual::new(5.0, [3.0, 2.0]));
+
+        let empty: Dual<f64> = core::iter::empty().sum();
+        assert_eq!(empty, Dual::constant(0.0));
+    }
+}
@@ -0,0 +1,170 @@
+// Elementary functions of dual numbers over floats, which require the `std` feature (as the
+// underlying float functions are not available in `core`).
+
+use super::Dual;
+use crate::Real;
+
+// The square root of 2π
+const SQRT_2PI: f64 = 2.506_628_274_631_000_5;
+
+
+// Apply the chain rule: given the value of f(u) and of f'(u), return f(u) + f'(u) u' ε
+macro_rules! chain {
+    ($dual:expr, $value:expr, $derivative:expr) => {{
+        let derivative = $derivative;
+        Dual {
+            value: $value,
+            derivatives: $dual.derivatives.map(|d| derivative * d)
+        }
+    }};
+}
+
+
+impl<T, const N: usize> Dual<T, N> where T: Real {
+
+    /// Raise $e$ to the power of this dual number: $(e^u)' = e^u u'$
+    pub fn exp(self) -> Self {
+        let exp = self.value.exp();
+        chain!(self, exp, exp)
+    }
+
+    /// Compute the natural logarithm of this dual number: $(\ln u)' = u' / u$
+    pub fn ln(self) -> Self {
+        chain!(self, self.value.ln(), T::from_f64(1.0) / self.value)
+    }
+
+    /// Compute the square root of this dual number: $(\sqrt{u})' = u' / (2 \sqrt{u})$
+    pub fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        chain!(self, sqrt, T::from_f64(0.5) / sqrt)
+    }
+
+    /// Raise this dual number to a constant power: $(u^n)' = n u^{n-1} u'$
+    pub fn powf(self, n: T) -> Self {
+        chain!(self, self.value.powf(n), n * self.value.powf(n - T::from_f64(1.0)))
+    }
+
+    /// Compute the cumulative distribution function of the standard normal distribution:
+    /// $\Phi(u)' = \varphi(u) u'$
+    pub fn norm_cdf(self) -> Self {
+        let x = self.value.to_f64();
+        chain!(self, T::from_f64(norm_cdf(x)), T::from_f64(norm_pdf(x)))
+    }
+}
+
+
+// The probability density function of the standard normal distribution
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / SQRT_2PI
+}
+
+// The cumulative distribution function of the standard normal distribution, as approximated by
+// Hart (1968) in double precision, and published by West (2005) in "Better approximations to
+// cumulative normal functions"
+fn norm_cdf(x: f64) -> f64 {
+    let xabs = x.abs();
+    let tail =
+        if xabs > 37.0 {
+            0.0
+        }
+        else if xabs < 7.071_067_811_865_47 {
+            let numerator = [
+                3.526_249_659_989_11e-2, 0.700_383_064_443_688, 6.373_962_203_531_65,
+                33.912_866_078_383, 112.079_291_497_871, 221.213_596_169_931, 220.206_867_912_376,
+            ];
+            let denominator = [
+                8.838_834_764_831_84e-2, 1.755_667_163_182_64, 16.064_177_579_207,
+                86.780_732_202_946_1, 296.564_248_779_674, 637.333_633_378_831, 793.826_512_519_948,
+                440.413_735_824_752,
+            ];
+            let polynomial = |coefficients: &[f64]| coefficients.iter().fold(0.0, |acc, c| acc * xabs + c);
+            (-0.5 * xabs * xabs).exp() * polynomial(&numerator) / polynomial(&denominator)
+        }
+        else {
+            // Continued fraction
+            let fraction = xabs + 1.0 / (xabs + 2.0 / (xabs + 3.0 / (xabs + 4.0 / (xabs + 0.65))));
+            (-0.5 * xabs * xabs).exp() / fraction / SQRT_2PI
+        };
+
+    if x > 0.0 { 1.0 - tail } else { tail }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn exp() {
+        let f = Dual::variable(2.0f64).exp();
+        assert_close(f.value(), 2.0f64.exp());
+        assert_close(f.derivative(), 2.0f64.exp());
+    }
+
+    #[test]
+    fn ln() {
+        let f = Dual::variable(4.0f64).ln();
+        assert_close(f.value(), 4.0f64.ln());
+        assert_close(f.derivative(), 0.25);
+    }
+
+    #[test]
+    fn sqrt() {
+        let f = Dual::variable(4.0f64).sqrt();
+        assert_close(f.value(), 2.0);
+        assert_close(f.derivative(), 0.25);
+    }
+
+    #[test]
+    fn powf() {
+        let f = Dual::variable(2.0f64).powf(3.0);
+        assert_close(f.value(), 8.0);
+        assert_close(f.derivative(), 12.0);
+    }
+
+    #[test]
+    fn norm_cdf_known_values() {
+        assert_close(norm_cdf(0.0), 0.5);
+        assert_close(norm_cdf(1.0), 0.841_344_746_068_542_9);
+        assert_close(norm_cdf(-1.96), 0.024_997_895_148_220_435);
+        assert_close(norm_cdf(8.0), 1.0 - 6.220_960_574_271_785e-16);
+        assert_eq!(norm_cdf(-40.0), 0.0);
+    }
+
+    #[test]
+    fn norm_cdf_derivative() {
+        let f = Dual::variable(0.0f64).norm_cdf();
+        assert_close(f.value(), 0.5);
+        assert_close(f.derivative(), 1.0 / SQRT_2PI);
+    }
+
+    #[test]
+    fn f32_functions() {
+        let f = Dual::variable(1.0f32).exp().ln().powf(2.0).sqrt();
+        assert!((f.value() - 1.0).abs() < 1e-6);
+        assert!((f.derivative() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn black_scholes_greeks_in_a_single_pass() {
+        // Call price as a function of spot and volatility
+        let constant = Dual::<f64, 2>::constant;
+        let (rate, time, strike) = (constant(0.05), constant(0.5), constant(100.0));
+        let [spot, vol] = Dual::variables([105.0, 0.2]);
+
+        let sqrt_time = time.sqrt();
+        let d1 = ((spot / strike).ln() + (rate + vol * vol * constant(0.5)) * time) / (vol * sqrt_time);
+        let d2 = d1 - vol * sqrt_time;
+        let call = spot * d1.norm_cdf() - strike * (-rate * time).exp() * d2.norm_cdf();
+
+        // Delta = N(d1) and Vega = S φ(d1) √T
+        let d1 = d1.value();
+        assert_close(call.derivatives()[0], norm_cdf(d1));
+        assert_close(call.derivatives()[1], 105.0 * norm_pdf(d1) * 0.5f64.sqrt());
+    }
+}
@@ -1,5 +1,6 @@
-use super::{Decimal, DecimalError};
-use std::str::FromStr;
+use super::{BadFormatKind, Decimal, DecimalError, RoundingMode, MAX_SCALING};
+use super::parsing::accumulate;
+use core::str::FromStr;
 
 
 impl From<i32> for Decimal {
@@ -29,87 +30,56 @@ impl FromStr for Decimal {
     // TODO Implement the ability to create a decimal number from scientific notation (such as 1.234e5)
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let len = s.len();
-        if len == 0 {
-            return Err(DecimalError::BadFormat("Empty string"));
+        // The whole given string must represent a decimal number, and any unconsumed
+        // byte is an error that takes precedence over the scaling overflow
+        let bytes = s.as_bytes();
+        let (coefficient, scaling, consumed) = accumulate(bytes)?;
+        if consumed < bytes.len() {
+            return Err(DecimalError::BadFormat { position: consumed, kind: BadFormatKind::InvalidCharacter });
         }
+        Self::try_new(coefficient, scaling)
+    }
+}
 
-        let chars = s.as_bytes();
-        let mut accumulated_coefficient: i32 = 0;
-        let mut accumulated_scaling: u8 = 0;
-        let mut minus_encountered = false;
-        let mut dot_encountered = false;
-        let mut maybe_error: Option<DecimalError> = None;
-
-        for (i, c) in chars.iter().enumerate().take(len) {
-
-            // 43 is the ASCII code for the character '+'
-            // and it is allowed only once (at the beginning of the given string)
-            if *c == 43 && i > 0 {
-                maybe_error = Some(DecimalError::BadFormat("Misplaced + (plus)")); break;
-            }
-
-            // 45 is the ASCII code for the character '-'
-            else if *c == 45 {
-                if i > 0 {
-                    maybe_error = Some(DecimalError::BadFormat("Misplaced - (minus)")); break;
-                }
-                minus_encountered = true;
-            }
-
-            // 46 is the ASCII code for the character '.'
-            else if *c == 46 {
-                if dot_encountered {
-                    // double dot encountered!
-                    maybe_error = Some(DecimalError::BadFormat("Double . (dot)"));
-                    break;
-                }
-                dot_encountered = true;
-            }
-
-            // 48 is the ASCII code for the character '0'
-            else if (48..=59).contains(c) {
-                //
-                // Update the coefficient by multiplying it by 10 and then adding (or subtracting)
-                // the current character digit
-                //
-                //      coefficient = coefficient * 10 + (c - 48)
-                //
-                // If the result overflows, break the loop and set an error
-                //
-                let checked_coefficient =
-                    accumulated_coefficient
-                        .checked_mul(10)
-                        .and_then(|x|
-                            if !minus_encountered { x.checked_add((c - 48) as i32) }
-                            else                  { x.checked_sub((c - 48) as i32) }
-                        );
-
-                if let Some(c) = checked_coefficient {
-                    accumulated_coefficient = c;
-                    if dot_encountered {
-                        accumulated_scaling += 1;
-                    }
-                }
-                else {
-                    maybe_error = Some(DecimalError::CoefficientOverflow);
-                    break;
-                }
-            }
-
-            else {
-                maybe_error = Some(DecimalError::BadFormat("Invalid character"));
-                break;
-            }
-        }
 
-        // Finally, check if we encountered an error and return the result accordingly
-        if let Some(error) = maybe_error {
-            Err(error)
-        }
-        else {
-            Self::try_new(accumulated_coefficient, accumulated_scaling)
+
+impl Decimal {
+
+    /// Convert this decimal number into the nearest `f64` (possibly losing precision)
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// assert_eq!(Decimal::new(-123456, 2).to_f64(), -1234.56);
+    /// ```
+    pub fn to_f64(self) -> f64 {
+        // Powers of ten up to the maximum scaling factor are exact floats, so that the quotient
+        // is correctly rounded
+        self.coefficient as f64 / 10u32.pow(self.scaling as u32) as f64
+    }
+
+    /// Convert the given `f64` into a decimal number, rounding it according to the given rounding
+    /// mode (to [`MAX_SCALING`](crate::MAX_SCALING) digits after the decimal point at most).
+    ///
+    /// It returns `None` if the float is not finite, or too large to be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// assert_eq!(Decimal::rounding_from_f64(-1234.56, &rm), Some(Decimal::new(-123456, 2)));
+    /// assert_eq!(Decimal::rounding_from_f64(1.0 / 3.0, &rm), Some(Decimal::new(33333333, 8)));
+    /// assert!(Decimal::rounding_from_f64(f64::NAN, &rm).is_none());
+    /// ```
+    pub fn rounding_from_f64(float: f64, rounding_mode: &RoundingMode) -> Option<Self> {
+        // The float is truncated with one more digit than the maximum scaling factor, which the
+        // rounding mode is then applied to (as for the quotients of decimal numbers)
+        let scaling = MAX_SCALING as u32 + 1;
+        let wide = float * 10u64.pow(scaling) as f64;
+        if !wide.is_finite() || wide.abs() >= i128::MAX as f64 {
+            return None;
         }
+        Self::rounding_from_wide(wide as i128, scaling, rounding_mode).ok()
     }
 }
 
@@ -142,6 +112,7 @@ mod test {
 
     #[test]
     #[ignore]
+    #[allow(clippy::excessive_precision)]
     fn from_f32() {
         let decimal = Decimal::from(123456.789);
         assert_eq!(decimal.coefficient, 123456789);
@@ -218,35 +189,43 @@ mod test {
     fn from_str_empty_err() {
         let res = Decimal::from_str("");
         assert!(res.is_err());
-        assert!(matches!(res.err().unwrap(), DecimalError::BadFormat("Empty string")));
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 0, kind: BadFormatKind::Empty });
     }
 
     #[test]
     fn from_str_invalid_char_err() {
         let res = Decimal::from_str("1.?34");
         assert!(res.is_err());
-        assert!(matches!(res.err().unwrap(), DecimalError::BadFormat("Invalid character")));
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 2, kind: BadFormatKind::InvalidCharacter });
+    }
+
+    #[test]
+    fn from_str_colon_err() {
+        // The ':' and ';' characters follow '9' in the ASCII table, but they are not digits
+        let res = Decimal::from_str("1:");
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 1, kind: BadFormatKind::InvalidCharacter });
     }
 
     #[test]
     fn from_str_misplaced_plus_sign_err() {
         let res = Decimal::from_str("1.+34");
         assert!(res.is_err());
-        assert!(matches!(res.err().unwrap(), DecimalError::BadFormat("Misplaced + (plus)")));
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 2, kind: BadFormatKind::MisplacedPlus });
     }
 
     #[test]
     fn from_str_misplaced_minus_sign_err() {
         let res = Decimal::from_str("1.34-");
         assert!(res.is_err());
-        assert!(matches!(res.err().unwrap(), DecimalError::BadFormat("Misplaced - (minus)")));
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 4, kind: BadFormatKind::MisplacedMinus });
     }
 
     #[test]
     fn from_str_double_dot_err() {
         let res = Decimal::from_str("1.234.56");
         assert!(res.is_err());
-        assert!(matches!(res.err().unwrap(), DecimalError::BadFormat("Double . (dot)")));
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 5, kind: BadFormatKind::DoubleDot });
     }
 
     #[test]
@@ -264,4 +243,32 @@ mod test {
         assert!(res.is_err());
         assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Decimal::new(25, 2).to_f64(), 0.25);
+        assert_eq!(Decimal::new(MIN_COEFFICIENT, MAX_SCALING).to_f64(), -21.47483648);
+    }
+
+    #[test]
+    fn rounding_from_f64() {
+        let rm = RoundingMode::HalfUp;
+        let d = Decimal::rounding_from_f64(0.1 + 0.2, &rm).unwrap();
+        assert_eq!(d, Decimal::new(3, 1));
+
+        // Rounding half up to the maximum scaling factor
+        let d = Decimal::rounding_from_f64(2.0 / 3.0, &rm).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (66666667, 8));
+
+        // Downscaling as long as the coefficient overflows
+        let d = Decimal::rounding_from_f64(98765432.1, &rm).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (987654321, 1));
+    }
+
+    #[test]
+    fn rounding_from_f64_overflow() {
+        let rm = RoundingMode::HalfUp;
+        assert!(Decimal::rounding_from_f64(f64::INFINITY, &rm).is_none());
+        assert!(Decimal::rounding_from_f64(1e30, &rm).is_none());
+    }
+}
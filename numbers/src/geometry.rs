@@ -0,0 +1,171 @@
+use crate::{Decimal, RoundingMode};
+use crate::slices::wide_dot;
+use alloc::vec::Vec;
+
+
+impl Decimal {
+
+    /// Compute the Euclidean norm of the decimal numbers of a slice (that is, the square root of
+    /// the sum of their squares).
+    ///
+    /// The sum of the squares is accumulated exactly (as in [`Decimal::dot`]), so that only its
+    /// square root is rounded according to the given rounding mode (as in [`Decimal::rounding_sqrt`]).
+    /// It returns `None` if the norm cannot be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = [Decimal::new(3, 2), Decimal::new(-4, 2)];
+    /// assert_eq!(Decimal::rounding_norm(&returns, &rm).unwrap().to_string(), "0.05000000");
+    ///
+    /// // The squares alone would overflow, but not their norm
+    /// let large = [Decimal::new(60000, 0), Decimal::new(80000, 0)];
+    /// assert_eq!(Decimal::rounding_norm(&large, &rm), Some(Decimal::new(100000, 0)));
+    /// ```
+    pub fn rounding_norm(values: &[Decimal], rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let (sum, scaling) = wide_dot(values, values)?;
+        Self::rounding_sqrt_from_wide(sum, scaling, rounding_mode)
+    }
+
+
+    /// Divide each of the decimal numbers of a slice by their Euclidean norm (see
+    /// [`Decimal::rounding_norm`]), so that the norm of the quotients is (about) one.
+    ///
+    /// The norm is not rounded, so that each quotient is affected by a single rounding (according
+    /// to the given rounding mode), however small the decimal numbers are. It returns `None` if
+    /// they are all zero, or if the sum of their squares overflows 128 bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let weights = [Decimal::new(1, 8), Decimal::new(2, 8)];
+    /// let unit = Decimal::rounding_normalize(&weights, &rm).unwrap();
+    /// assert_eq!(unit, [Decimal::new(4472136, 7), Decimal::new(89442719, 8)]);
+    /// ```
+    pub fn rounding_normalize(values: &[Decimal], rounding_mode: &RoundingMode) -> Option<Vec<Decimal>> {
+        let squares = wide_dot(values, values)?;
+        values
+            .iter()
+            .map(|value| Self::rounding_root_quotient_from_wide((value.coefficient as i128, value.scaling as u32), &[squares], rounding_mode))
+            .collect()
+    }
+
+
+    /// Compute the cosine similarity of the decimal numbers of two slices (of the same length),
+    /// that is their dot product divided by the product of their Euclidean norms.
+    ///
+    /// The dot product and the sums of the squares are accumulated exactly (as in
+    /// [`Decimal::dot`]), and neither norm is rounded, so that the cosine is affected by a single
+    /// rounding (according to the given rounding mode), however small the decimal numbers are.
+    /// It returns `None` if either slice is all zeros, or if any of those sums overflows 128 bits.
+    ///
+    /// # Panics
+    /// If the given slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let lhs = [Decimal::new(1, 4), Decimal::new(1, 4)];
+    /// let rhs = [Decimal::new(1, 4), Decimal::new(0, 4)];
+    /// let cosine = Decimal::rounding_cosine_similarity(&lhs, &rhs, &rm).unwrap();
+    /// assert_eq!(cosine.to_string(), "0.70710678");
+    /// ```
+    pub fn rounding_cosine_similarity(lhs: &[Decimal], rhs: &[Decimal], rounding_mode: &RoundingMode) -> Option<Decimal> {
+        assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+        let dot = wide_dot(lhs, rhs)?;
+        Self::rounding_root_quotient_from_wide(dot, &[wide_dot(lhs, lhs)?, wide_dot(rhs, rhs)?], rounding_mode)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MAX_COEFFICIENT;
+
+    #[test]
+    fn rounding_norm() {
+        let rm = RoundingMode::HalfUp;
+        let n = Decimal::rounding_norm(&[Decimal::new(3, 0), Decimal::new(-4, 0)], &rm).unwrap();
+        assert_eq!(n, Decimal::new(5, 0));
+
+        // √(1.5² + 0.25²) = 1.52069063...
+        let n = Decimal::rounding_norm(&[Decimal::new(15, 1), Decimal::new(25, 2)], &rm).unwrap();
+        assert_eq!((n.coefficient, n.scaling), (152069063, 8));
+    }
+
+    #[test]
+    fn rounding_norm_exact_intermediate() {
+        // The squares overflow, but not the norm
+        let rm = RoundingMode::HalfUp;
+        let n = Decimal::rounding_norm(&[Decimal::new(60000, 0), Decimal::new(-80000, 0)], &rm).unwrap();
+        assert_eq!(n, Decimal::new(100000, 0));
+
+        // √2 * 2147483647 cannot be represented
+        assert!(Decimal::rounding_norm(&[Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(MAX_COEFFICIENT, 0)], &rm).is_none());
+    }
+
+    #[test]
+    fn rounding_norm_empty() {
+        let n = Decimal::rounding_norm(&[], &RoundingMode::HalfUp).unwrap();
+        assert_eq!(n, Decimal::new(0, 0));
+    }
+
+    #[test]
+    fn rounding_normalize() {
+        let rm = RoundingMode::HalfUp;
+        let unit = Decimal::rounding_normalize(&[Decimal::new(3, 0), Decimal::new(-4, 0)], &rm).unwrap();
+        assert_eq!(unit, [Decimal::new(6, 1), Decimal::new(-8, 1)]);
+        assert!(unit.iter().all(|d| d.scaling == 8));
+
+        // The norm 0.00000002236... would be rounded to 0.00000002
+        let unit = Decimal::rounding_normalize(&[Decimal::new(1, 8), Decimal::new(2, 8)], &rm).unwrap();
+        assert_eq!(unit, [Decimal::new(44721360, 8), Decimal::new(89442719, 8)]);
+
+        let unit = Decimal::rounding_normalize(&[Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(0, 8)], &rm).unwrap();
+        assert_eq!(unit, [Decimal::new(1, 0), Decimal::new(0, 0)]);
+    }
+
+    #[test]
+    fn rounding_normalize_zeros() {
+        let rm = RoundingMode::HalfUp;
+        assert!(Decimal::rounding_normalize(&[Decimal::new(0, 2), Decimal::new(0, 0)], &rm).is_none());
+        assert_eq!(Decimal::rounding_normalize(&[], &rm), Some(Vec::new()));
+    }
+
+    #[test]
+    fn rounding_cosine_similarity() {
+        let rm = RoundingMode::HalfUp;
+        let lhs = [Decimal::new(5, 1), Decimal::new(3, 1), Decimal::new(-2, 1)];
+        let rhs = [Decimal::new(4, 1), Decimal::new(4, 1), Decimal::new(-1, 1)];
+        let cosine = Decimal::rounding_cosine_similarity(&lhs, &rhs, &rm).unwrap();
+        assert_eq!((cosine.coefficient, cosine.scaling), (96013024, 8));
+
+        let opposite = [Decimal::new(-5, 1), Decimal::new(-3, 1), Decimal::new(2, 1)];
+        assert_eq!(Decimal::rounding_cosine_similarity(&lhs, &opposite, &rm), Some(Decimal::new(-1, 0)));
+        assert!(Decimal::rounding_cosine_similarity(&lhs, &[Decimal::new(0, 0); 3], &rm).is_none());
+    }
+
+    #[test]
+    fn rounding_cosine_similarity_small_magnitudes() {
+        // The norms 0.00014142... and 0.0001 would be rounded, and so would be their product
+        let rm = RoundingMode::HalfUp;
+        let lhs = [Decimal::new(1, 4), Decimal::new(1, 4)];
+        let rhs = [Decimal::new(1, 4), Decimal::new(0, 4)];
+        let cosine = Decimal::rounding_cosine_similarity(&lhs, &rhs, &rm).unwrap();
+        assert_eq!((cosine.coefficient, cosine.scaling), (70710678, 8));
+
+        // The product of the norms would be rounded to zero
+        let tiny = [Decimal::new(1, 8), Decimal::new(2, 8)];
+        assert_eq!(Decimal::rounding_cosine_similarity(&tiny, &tiny, &rm), Some(Decimal::new(1, 0)));
+
+        // Misaligned and large magnitudes alike
+        let large = [Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(MAX_COEFFICIENT, 8)];
+        let cosine = Decimal::rounding_cosine_similarity(&large, &tiny, &rm).unwrap();
+        assert_eq!((cosine.coefficient, cosine.scaling), (44721360, 8));
+    }
+}
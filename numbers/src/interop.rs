@@ -0,0 +1,8 @@
+// Conversions between our decimal numbers and those of other popular crates,
+// each one enabled by the cargo feature named after the crate.
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
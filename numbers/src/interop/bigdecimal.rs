@@ -0,0 +1,148 @@
+use crate::{Decimal, DecimalError};
+use ::bigdecimal::BigDecimal;
+use ::bigdecimal::num_bigint::BigInt;
+
+
+/// Create a new [`bigdecimal::BigDecimal`](::bigdecimal::BigDecimal) from a decimal number
+///
+/// This conversion never fails, as it supports arbitrary precision.
+impl From<Decimal> for BigDecimal {
+    fn from(decimal: Decimal) -> Self {
+        BigDecimal::new(BigInt::from(decimal.coefficient), decimal.scaling as i64)
+    }
+}
+
+
+/// Attempt to create a new decimal number from a [`bigdecimal::BigDecimal`](::bigdecimal::BigDecimal)
+///
+/// If either the scaling factor or the coefficient is out of range, trailing zeros are dropped
+/// (without any loss of precision) as long as possible. A negative scale (such as in `1E+3`) is
+/// turned into trailing zeros of the coefficient. It returns one of the following errors:
+///
+/// - [`DecimalError::ScalingOverflow`]<br>
+///   If the scaling factor still exceeds [`MAX_SCALING`](crate::MAX_SCALING)
+///
+/// - [`DecimalError::CoefficientOverflow`]<br>
+///   If the coefficient still exceeds [`MAX_COEFFICIENT`](crate::MAX_COEFFICIENT)
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_numbers::{Decimal, DecimalError};
+/// use bigdecimal::BigDecimal;
+/// use std::str::FromStr;
+///
+/// let d = Decimal::try_from(BigDecimal::from_str("1234.56").unwrap()).unwrap();
+/// assert_eq!(d.to_string(), "1234.56");
+///
+/// let err = Decimal::try_from(BigDecimal::from_str("1234567890123").unwrap()).unwrap_err();
+/// assert!(matches!(err, DecimalError::CoefficientOverflow));
+/// ```
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        let (coefficient, scale) = value.into_bigint_and_exponent();
+        try_from_big(coefficient, scale)
+    }
+}
+
+
+/// Same as the conversion from an owned [`bigdecimal::BigDecimal`](::bigdecimal::BigDecimal)
+impl TryFrom<&BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        let (coefficient, scale) = value.as_bigint_and_exponent();
+        try_from_big(coefficient.clone(), scale)
+    }
+}
+
+
+fn try_from_big(coefficient: BigInt, scale: i64) -> Result<Decimal, DecimalError> {
+    let (mut coefficient, mut scale) = (coefficient, scale);
+    if scale < 0 {
+        // Negative scales stand for trailing zeros, such as in 1E+3 (which is 1000),
+        // and no more than 38 of them fit a 128 bits integer (unless it's zero)
+        let exponent = u32::try_from(-scale).map_err(|_| DecimalError::CoefficientOverflow)?;
+        if exponent > 38 && coefficient != BigInt::ZERO {
+            return Err(DecimalError::CoefficientOverflow);
+        }
+        coefficient *= BigInt::from(10).pow(exponent.min(38));
+        scale = 0;
+    }
+
+    // Drop the trailing zeros that wouldn't fit anyway
+    let ten = BigInt::from(10);
+    while scale > 0 && i128::try_from(&coefficient).is_err() && (&coefficient % &ten) == BigInt::ZERO {
+        coefficient /= &ten;
+        scale -= 1;
+    }
+
+    let coefficient = i128::try_from(&coefficient).map_err(|_| DecimalError::CoefficientOverflow)?;
+    let scale = u32::try_from(scale).map_err(|_| DecimalError::ScalingOverflow)?;
+    Decimal::try_from_wide(coefficient, scale)
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MAX_SCALING, MIN_COEFFICIENT};
+    use alloc::format;
+    use core::str::FromStr;
+
+    fn round_trip(coefficient: i32, scaling: u8) {
+        let decimal = Decimal::new(coefficient, scaling);
+        let big_decimal = BigDecimal::from(decimal);
+        assert_eq!(big_decimal, BigDecimal::new(BigInt::from(coefficient), scaling as i64));
+
+        let back = Decimal::try_from(&big_decimal).unwrap();
+        assert_eq!(back.coefficient, coefficient);
+        assert_eq!(back.scaling, scaling);
+    }
+
+    #[test]
+    fn round_trip_boundaries() {
+        round_trip(MIN_COEFFICIENT, 0);
+        round_trip(MAX_COEFFICIENT, 0);
+        round_trip(MIN_COEFFICIENT, MAX_SCALING);
+        round_trip(MAX_COEFFICIENT, MAX_SCALING);
+        round_trip(0, MAX_SCALING);
+    }
+
+    #[test]
+    fn try_from_negative_scale() {
+        let big_decimal = BigDecimal::from_str("-1.5E+3").unwrap();
+        let decimal = Decimal::try_from(big_decimal).unwrap();
+        assert_eq!(decimal.coefficient, -1500);
+        assert_eq!(decimal.scaling, 0);
+    }
+
+    #[test]
+    fn try_from_dropping_trailing_zeros() {
+        // Far more trailing zeros than a 128 bits integer could hold
+        let big_decimal = BigDecimal::from_str(&format!("21474836.47{}", "0".repeat(60))).unwrap();
+        let decimal = Decimal::try_from(big_decimal).unwrap();
+        assert_eq!(decimal.coefficient, MAX_COEFFICIENT);
+        assert_eq!(decimal.scaling, 2);
+    }
+
+    #[test]
+    fn try_from_scaling_overflow_err() {
+        let big_decimal = BigDecimal::new(BigInt::from(1), MAX_SCALING as i64 + 1);
+        let res = Decimal::try_from(big_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::ScalingOverflow));
+    }
+
+    #[test]
+    fn try_from_coefficient_overflow_err() {
+        let big_decimal = BigDecimal::new(BigInt::from(MAX_COEFFICIENT as i64 + 1), 0);
+        let res = Decimal::try_from(big_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+
+        let big_decimal = BigDecimal::from_str("-1E+40").unwrap();
+        let res = Decimal::try_from(big_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{Decimal, DecimalError};
+use ::rust_decimal::Decimal as RustDecimal;
+
+
+/// Create a new [`rust_decimal::Decimal`](::rust_decimal::Decimal) from a decimal number
+///
+/// This conversion never fails, as it supports both wider coefficients and wider scaling factors.
+impl From<Decimal> for RustDecimal {
+    fn from(decimal: Decimal) -> Self {
+        RustDecimal::new(decimal.coefficient as i64, decimal.scaling as u32)
+    }
+}
+
+
+/// Attempt to create a new decimal number from a [`rust_decimal::Decimal`](::rust_decimal::Decimal)
+///
+/// If either the scaling factor or the coefficient is out of range, trailing zeros are dropped
+/// (without any loss of precision) as long as possible. It returns one of the following errors:
+///
+/// - [`DecimalError::ScalingOverflow`]<br>
+///   If the scaling factor still exceeds [`MAX_SCALING`](crate::MAX_SCALING)
+///
+/// - [`DecimalError::CoefficientOverflow`]<br>
+///   If the coefficient still exceeds [`MAX_COEFFICIENT`](crate::MAX_COEFFICIENT)
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_numbers::{Decimal, DecimalError};
+/// use rust_decimal::Decimal as RustDecimal;
+///
+/// let d = Decimal::try_from(RustDecimal::new(123456, 2)).unwrap();
+/// assert_eq!(d.to_string(), "1234.56");
+///
+/// let err = Decimal::try_from(RustDecimal::new(123456, 9)).unwrap_err();
+/// assert!(matches!(err, DecimalError::ScalingOverflow));
+/// ```
+impl TryFrom<RustDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: RustDecimal) -> Result<Self, Self::Error> {
+        Decimal::try_from_wide(value.mantissa(), value.scale())
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MAX_SCALING, MIN_COEFFICIENT};
+
+    fn round_trip(coefficient: i32, scaling: u8) {
+        let decimal = Decimal::new(coefficient, scaling);
+        let rust_decimal = RustDecimal::from(decimal);
+        assert_eq!(rust_decimal.mantissa(), coefficient as i128);
+        assert_eq!(rust_decimal.scale(), scaling as u32);
+
+        let back = Decimal::try_from(rust_decimal).unwrap();
+        assert_eq!(back.coefficient, coefficient);
+        assert_eq!(back.scaling, scaling);
+    }
+
+    #[test]
+    fn round_trip_boundaries() {
+        round_trip(MIN_COEFFICIENT, 0);
+        round_trip(MAX_COEFFICIENT, 0);
+        round_trip(MIN_COEFFICIENT, MAX_SCALING);
+        round_trip(MAX_COEFFICIENT, MAX_SCALING);
+        round_trip(0, MAX_SCALING);
+    }
+
+    #[test]
+    fn try_from_dropping_trailing_zeros() {
+        // 21474836.4700000000 can be represented as 21474836.47
+        let rust_decimal = RustDecimal::from_i128_with_scale(214748364700000000, 10);
+        let decimal = Decimal::try_from(rust_decimal).unwrap();
+        assert_eq!(decimal.coefficient, MAX_COEFFICIENT);
+        assert_eq!(decimal.scaling, 2);
+    }
+
+    #[test]
+    fn try_from_scaling_overflow_err() {
+        let rust_decimal = RustDecimal::new(1, MAX_SCALING as u32 + 1);
+        let res = Decimal::try_from(rust_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::ScalingOverflow));
+    }
+
+    #[test]
+    fn try_from_coefficient_overflow_err() {
+        let rust_decimal = RustDecimal::new(MAX_COEFFICIENT as i64 + 1, 0);
+        let res = Decimal::try_from(rust_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+
+        let rust_decimal = RustDecimal::new(MIN_COEFFICIENT as i64 - 1, MAX_SCALING as u32);
+        let res = Decimal::try_from(rust_decimal);
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+    }
+}
@@ -1,12 +1,20 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! A basic implementation of decimal numbers in fixed-point arithmetic.
 //!
 //! <div class="warning">This project is in an early stage of development, and
 //! <strong>not</strong> production ready yet. Use with caution!</div>
 //!
+//! # Features
+//! - `std` (enabled by default)<br>
+//!   Disable it to build for `no_std` targets (still requiring the `alloc` crate), although
+//!   losing the elementary functions of [`Dual`] and [`Complex`] numbers (such as [`Dual::exp`])
+//!
+
+extern crate alloc;
 
-use std::fmt;
+use core::fmt;
 
 #[cfg_attr(doc, beaumont_macros::allow_katex)]
 /// Representation of a decimal number in fixed-point arithmetic
@@ -101,26 +109,139 @@ pub const MAX_PRECISION: u8 = 10;
 
 
 /// Error type for decimal numbers
-#[derive(Debug)]
+///
+/// Errors can be matched (and compared) in tests, and logged usefully as they carry the context
+/// they occurred in, such as the byte position of a badly formatted input, or the operands of an
+/// overflowing arithmetic operation.
+///
+/// # Examples
+/// ```rust
+/// # use beaumont_numbers::{BadFormatKind, Decimal, DecimalError};
+/// let err = "1234.5x6".parse::<Decimal>().unwrap_err();
+/// assert_eq!(err, DecimalError::BadFormat { position: 6, kind: BadFormatKind::InvalidCharacter });
+/// assert_eq!(err.to_string(), "Invalid character at position 6");
+///
+/// // Decimal errors can be propagated as boxed errors (as well as into other error crates)
+/// fn total(prices: &[&str]) -> Result<Decimal, Box<dyn std::error::Error>> {
+///     let mut total = Decimal::new(0, 0);
+///     for price in prices {
+///         total = total.try_add(price.parse()?)?;
+///     }
+///     Ok(total)
+/// }
+/// assert_eq!(total(&["1.5", "2.25"]).unwrap().to_string(), "3.75");
+/// assert_eq!(total(&["1.5", "-2..25"]).unwrap_err().to_string(), "Double . (dot) at position 3");
+/// assert_eq!(total(&["21474836.47", "0.01"]).unwrap_err().to_string(), "Overflow while adding 21474836.47 and 0.01");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DecimalError {
     /// Error due to bad format
-    BadFormat(&'static str),
+    BadFormat {
+        /// The position (offset in bytes) of the input where the bad format was detected
+        position: usize,
+        /// The kind of bad format
+        kind: BadFormatKind
+    },
     /// Error due to the scaling factor exceeding [`MAX_SCALING`]
     ScalingOverflow,
     /// Error due to the coefficient exceeding [`MAX_COEFFICIENT`]
-    CoefficientOverflow
+    CoefficientOverflow,
+    /// Error due to the result of an arithmetic operation not being representable
+    OperationOverflow {
+        /// The overflowing operation
+        operation: Operation,
+        /// The left-hand side operand
+        lhs: Decimal,
+        /// The right-hand side operand
+        rhs: Decimal
+    }
+}
+
+impl DecimalError {
+    // Shift the position of a bad format error by the given offset, such as when the error was
+    // detected in a field of a wider input
+    pub(crate) fn offset_by(self, offset: usize) -> Self {
+        match self {
+            DecimalError::BadFormat { position, kind } =>
+                DecimalError::BadFormat { position: position + offset, kind },
+            err => err
+        }
+    }
 }
 
 impl fmt::Display for DecimalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {
-            DecimalError::BadFormat(msg) => write!(f, "{msg}"),
+        match self {
+            DecimalError::BadFormat { position, kind } => write!(f, "{kind} at position {position}"),
             DecimalError::ScalingOverflow => write!(f, "Scaling overflow"),
-            DecimalError::CoefficientOverflow => write!(f, "Coefficient overflow")
+            DecimalError::CoefficientOverflow => write!(f, "Coefficient overflow"),
+            DecimalError::OperationOverflow { operation: Operation::Addition, lhs, rhs } =>
+                write!(f, "Overflow while adding {lhs} and {rhs}"),
+            DecimalError::OperationOverflow { operation: Operation::Multiplication, lhs, rhs } =>
+                write!(f, "Overflow while multiplying {lhs} and {rhs}"),
         }
     }
 }
 
+impl core::error::Error for DecimalError {}
+
+
+/// Kind of bad format detected while parsing (or decoding) decimal numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadFormatKind {
+    /// The input is empty
+    Empty,
+    /// A character not allowed in decimal numbers
+    InvalidCharacter,
+    /// A '+' (plus) character not at the beginning of the input
+    MisplacedPlus,
+    /// A '-' (minus) character not at the beginning of the input
+    MisplacedMinus,
+    /// A second '.' (dot) character
+    DoubleDot,
+    /// The input ends before the expected count of bytes
+    Truncated,
+    /// The input encodes a not-a-number value
+    NotANumber,
+    /// The input encodes an infinite value
+    Infinity,
+    /// The input encodes an unknown sign
+    InvalidSign,
+    /// The input encodes a digit out of range
+    InvalidDigit,
+    /// The input encodes non-zero digits beyond its display scale
+    DigitsBeyondScale
+}
+
+impl fmt::Display for BadFormatKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            BadFormatKind::Empty => "Empty string",
+            BadFormatKind::InvalidCharacter => "Invalid character",
+            BadFormatKind::MisplacedPlus => "Misplaced + (plus)",
+            BadFormatKind::MisplacedMinus => "Misplaced - (minus)",
+            BadFormatKind::DoubleDot => "Double . (dot)",
+            BadFormatKind::Truncated => "Truncated input",
+            BadFormatKind::NotANumber => "NaN is not supported",
+            BadFormatKind::Infinity => "Infinity is not supported",
+            BadFormatKind::InvalidSign => "Invalid sign",
+            BadFormatKind::InvalidDigit => "Invalid digit",
+            BadFormatKind::DigitsBeyondScale => "Digits beyond the display scale"
+        };
+        f.write_str(msg)
+    }
+}
+
+
+/// Arithmetic operation on decimal numbers (as reported by [`DecimalError::OperationOverflow`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Addition of two decimal numbers
+    Addition,
+    /// Multiplication of two decimal numbers
+    Multiplication
+}
+
 
 /// Rounding mode to be used in decimal number operations requiring rounding
 pub enum RoundingMode {
@@ -174,10 +295,35 @@ impl Decimal {
     // TODO Avoid .to_string() wherever possible (event in docs and tests)
 }
 
+/// The default decimal number is zero (with no digits after the decimal point)
+impl Default for Decimal {
+    fn default() -> Self {
+        Self { coefficient: 0, scaling: 0 }
+    }
+}
+
 
 
 mod from;
+mod parsing;
 mod display;
 mod scaling;
 mod ops;
+mod slices;
+mod geometry;
+mod statistics;
+mod interop;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod big;
+mod dual;
+mod complex;
+#[cfg(feature = "std")]
+mod real;
+
+pub use big::*;
+pub use dual::*;
+pub use complex::*;
+#[cfg(feature = "std")]
+pub use real::*;
 
@@ -1,7 +1,9 @@
 
 mod eq;
 mod neg;
-// mod add;
-// mod mul;
-// mod div;
+mod add;
+mod mul;
+mod mul_add;
+mod div;
+mod sqrt;
 
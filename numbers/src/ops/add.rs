@@ -0,0 +1,110 @@
+use core::ops::Add;
+use crate::{Decimal, DecimalError, Operation};
+
+
+impl Decimal {
+    /// Add this decimal number to the other decimal number while checking for eventual overflows.
+    ///
+    /// Both decimal numbers are aligned to the greater of their scaling factors, and the result is
+    /// computed exactly. If the resulting coefficient overflows, trailing zeros are dropped (when
+    /// possible) as that doesn't incur into any loss of precision.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, MAX_COEFFICIENT};
+    /// let d1 = Decimal::new(123, 2);
+    /// let d2 = Decimal::new(4567, 3);
+    /// let sum = d1.checked_add(d2);
+    /// assert!(sum.is_some());
+    /// assert_eq!(sum.unwrap().to_string(), "5.797");
+    ///
+    /// // Overflow!
+    /// let d3 = Decimal::new(MAX_COEFFICIENT, 2);
+    /// let d4 = Decimal::new(1, 2);
+    /// assert!(d3.checked_add(d4).is_none());
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.try_add(other).ok()
+    }
+
+    /// Same as the [`Decimal::checked_add`] method, but it returns an error telling which
+    /// operation overflowed (and its operands) instead of `None`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, DecimalError, Operation, MAX_COEFFICIENT};
+    /// let d1 = Decimal::new(123, 2);
+    /// let d2 = Decimal::new(4567, 3);
+    /// assert_eq!(d1.try_add(d2), Ok(Decimal::new(5797, 3)));
+    ///
+    /// // Overflow!
+    /// let d3 = Decimal::new(MAX_COEFFICIENT, 2);
+    /// let d4 = Decimal::new(1, 2);
+    /// let err = d3.try_add(d4).unwrap_err();
+    /// assert_eq!(err, DecimalError::OperationOverflow { operation: Operation::Addition, lhs: d3, rhs: d4 });
+    /// assert_eq!(err.to_string(), "Overflow while adding 21474836.47 and 0.01");
+    /// ```
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalError> {
+        let scaling = self.scaling.max(other.scaling);
+        let c1 = self.coefficient as i128 * 10i128.pow((scaling - self.scaling) as u32);
+        let c2 = other.coefficient as i128 * 10i128.pow((scaling - other.scaling) as u32);
+        Self::try_from_wide(c1 + c2, scaling as u32)
+            .map_err(|_| DecimalError::OperationOverflow { operation: Operation::Addition, lhs: self, rhs: other })
+    }
+}
+
+
+impl Add for Decimal {
+    type Output = Self;
+
+    /// Add this decimal number to the other, and it panics in case of coefficient overflow.
+    fn add(self, rhs: Self) -> Self::Output {
+        self
+            .try_add(rhs)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::MAX_COEFFICIENT;
+    use super::*;
+
+    #[test]
+    fn add_aligned() {
+        let d1 = Decimal::new(123456, 2);
+        let d2 = Decimal::new(-654, 2);
+        let d3 = d1 + d2;
+        assert_eq!(d3.coefficient, 122802);
+        assert_eq!(d3.scaling, 2);
+    }
+
+    #[test]
+    fn add_misaligned() {
+        let d1 = Decimal::new(123456, 2);
+        let d2 = Decimal::new(1, 4);
+        let d3 = d1 + d2;
+        assert_eq!(d3.coefficient, 12345601);
+        assert_eq!(d3.scaling, 4);
+    }
+
+    #[test]
+    fn add_dropping_trailing_zeros() {
+        // 21474836.40 + 0.10 = 21474836.50 whose coefficient overflows, unless written as 21474836.5
+        let d1 = Decimal::new(2147483640, 2);
+        let d2 = Decimal::new(10, 2);
+        let d3 = d1 + d2;
+        assert_eq!(d3.coefficient, 214748365);
+        assert_eq!(d3.scaling, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow while adding 21474836.47 and 0.01")]
+    fn add_overflow() {
+        let d1 = Decimal::new(MAX_COEFFICIENT, 2);
+        let d2 = Decimal::new(1, 2);
+        let _ = d1 + d2;
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Decimal, RoundingMode, MAX_SCALING};
+
+
+impl Decimal {
+    /// Divide this decimal number by the other decimal number, while rounding the quotient
+    /// according to the given rounding mode.
+    ///
+    /// As the quotient of two decimal numbers is not exact in general (such as $1/3$), it is
+    /// computed with [`MAX_SCALING`] digits after the decimal point at most, which the rounding
+    /// mode is applied just once to. The quotient is downscaled further (losing precision) only
+    /// if its coefficient would overflow otherwise. It returns `None` if dividing by zero, or if
+    /// the quotient cannot be represented even with no digits after the decimal point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let d1 = Decimal::new(2, 0);
+    /// let d2 = Decimal::new(3, 0);
+    /// assert_eq!(d1.rounding_div(d2, &rm).unwrap().to_string(), "0.66666667");
+    ///
+    /// let price = Decimal::new(10050, 2);
+    /// let quantity = Decimal::new(4, 0);
+    /// assert_eq!(price.rounding_div(quantity, &rm), Some(Decimal::new(25125, 3)));
+    ///
+    /// // Division by zero!
+    /// assert!(price.rounding_div(Decimal::new(0, 2), &rm).is_none());
+    /// ```
+    pub fn rounding_div(self, other: Self, rounding_mode: &RoundingMode) -> Option<Self> {
+        if other.coefficient == 0 {
+            return None;
+        }
+
+        // The quotient is computed (truncated) with one more digit than the maximum scaling
+        // factor, which is enough for rounding it correctly.
+        let scaling = MAX_SCALING as u32 + 1;
+        let numerator = self.coefficient as i128 * 10i128.pow(scaling + other.scaling as u32 - self.scaling as u32);
+        Self::rounding_from_wide(numerator / other.coefficient as i128, scaling, rounding_mode).ok()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MIN_COEFFICIENT};
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    fn div_exact() {
+        let q = Decimal::new(10, 0).rounding_div(Decimal::new(4, 0), &RM).unwrap();
+        assert_eq!(q, Decimal::new(25, 1));
+    }
+
+    #[test]
+    fn div_rounding_half_up() {
+        let q = Decimal::new(1, 0).rounding_div(Decimal::new(3, 0), &RM).unwrap();
+        assert_eq!((q.coefficient, q.scaling), (33333333, 8));
+
+        let q = Decimal::new(-2, 0).rounding_div(Decimal::new(3, 0), &RM).unwrap();
+        assert_eq!((q.coefficient, q.scaling), (-66666667, 8));
+
+        // 0.000000005 is rounded away from zero
+        let q = Decimal::new(1, 8).rounding_div(Decimal::new(2, 0), &RM).unwrap();
+        assert_eq!((q.coefficient, q.scaling), (1, 8));
+    }
+
+    #[test]
+    fn div_misaligned() {
+        // 1.5 / 0.0003 = 5000
+        let q = Decimal::new(15, 1).rounding_div(Decimal::new(3, 4), &RM).unwrap();
+        assert_eq!(q, Decimal::new(5000, 0));
+    }
+
+    #[test]
+    fn div_downscaled() {
+        // 21474836.47 / 0.5 = 42949672.94, which needs downscaling
+        let q = Decimal::new(MAX_COEFFICIENT, 2).rounding_div(Decimal::new(5, 1), &RM).unwrap();
+        assert_eq!((q.coefficient, q.scaling), (429496729, 1));
+    }
+
+    #[test]
+    fn div_overflow() {
+        let q = Decimal::new(MIN_COEFFICIENT, 0).rounding_div(Decimal::new(1, 8), &RM);
+        assert!(q.is_none());
+    }
+
+    #[test]
+    fn div_by_zero() {
+        assert!(Decimal::new(1, 0).rounding_div(Decimal::new(0, 0), &RM).is_none());
+    }
+}
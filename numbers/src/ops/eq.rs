@@ -1,16 +1,52 @@
+use core::cmp::Ordering;
 use crate::Decimal;
 
+impl Decimal {
+    // Align both coefficients to the greater scaling factor, which can never overflow
+    // a 64 bits integer
+    fn aligned_coefficients(&self, other: &Self) -> (i64, i64) {
+        let scaling = self.scaling.max(other.scaling);
+        let c1 = self.coefficient as i64 * 10i64.pow((scaling - self.scaling) as u32);
+        let c2 = other.coefficient as i64 * 10i64.pow((scaling - other.scaling) as u32);
+        (c1, c2)
+    }
+}
+
 impl PartialEq for Decimal {
 
     /// Tests for `self` and `other` values to be equal, and is used by `==`.
     fn eq(&self, other: &Self) -> bool {
-        // TODO Avoid having to convert to_string. We may be able to leverage coefficient and precision
-        self.to_string().trim_end_matches("0") ==  other.to_string().trim_end_matches("0")
+        let (c1, c2) = self.aligned_coefficients(other);
+        c1 == c2
     }
 }
 
 impl Eq for Decimal {}
 
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+
+    /// Compares the values of `self` and `other` (regardless of their scaling factors), and is
+    /// used by `<`, `<=`, `>` and `>=`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// assert!(Decimal::new(1234, 2) < Decimal::new(12341, 3));
+    /// assert!(Decimal::new(-5, 0) < Decimal::new(-49, 1));
+    /// assert_eq!(Decimal::new(15, 1).max(Decimal::new(150, 2)), Decimal::new(15, 1));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (c1, c2) = self.aligned_coefficients(other);
+        c1.cmp(&c2)
+    }
+}
+
 
 #[cfg(test)]
 mod test {
@@ -29,4 +65,20 @@ mod test {
         let d2 = Decimal::new(1234560, 3); //  "1234.560"
         assert_ne!(d1, d2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn neq_integers_with_trailing_zeros() {
+        let d1 = Decimal::new(10, 0); // "10"
+        let d2 = Decimal::new(1, 0);  // "1"
+        assert_ne!(d1, d2);
+    }
+
+    #[test]
+    fn cmp() {
+        let d1 = Decimal::new(123456, 2);  // "1234.56"
+        let d2 = Decimal::new(1234561, 3); // "1234.561"
+        assert!(d1 < d2);
+        assert!(-d1 > -d2);
+        assert_eq!(d1.cmp(&Decimal::new(1234560, 3)), Ordering::Equal);
+    }
+}
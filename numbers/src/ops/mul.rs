@@ -0,0 +1,155 @@
+use core::ops::Mul;
+use crate::{Decimal, DecimalError, Operation, RoundingMode};
+
+
+impl Decimal {
+    /// Multiply this decimal number by the other decimal number while checking for eventual overflows.
+    ///
+    /// The result is computed exactly, with its scaling factor being the sum of the scaling factors
+    /// of both decimal numbers. If either the resulting scaling factor or coefficient overflows,
+    /// trailing zeros are dropped (when possible) as that doesn't incur into any loss of precision.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let d1 = Decimal::new(150, 2);
+    /// let d2 = Decimal::new(25, 1);
+    /// let product = d1.checked_mul(d2);
+    /// assert!(product.is_some());
+    /// assert_eq!(product.unwrap().to_string(), "3.750");
+    ///
+    /// // Overflow! (the product would need 9 digits after the decimal point)
+    /// let d3 = Decimal::new(12345, 4);
+    /// let d4 = Decimal::new(12345, 5);
+    /// assert!(d3.checked_mul(d4).is_none());
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.try_mul(other).ok()
+    }
+
+    /// Same as the [`Decimal::checked_mul`] method, but it returns an error telling which
+    /// operation overflowed (and its operands) instead of `None`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, DecimalError, Operation};
+    /// let d1 = Decimal::new(150, 2);
+    /// let d2 = Decimal::new(25, 1);
+    /// assert_eq!(d1.try_mul(d2), Ok(Decimal::new(3750, 3)));
+    ///
+    /// // Overflow! (the product would need 9 digits after the decimal point)
+    /// let d3 = Decimal::new(12345, 4);
+    /// let d4 = Decimal::new(12345, 5);
+    /// let err = d3.try_mul(d4).unwrap_err();
+    /// assert_eq!(err, DecimalError::OperationOverflow { operation: Operation::Multiplication, lhs: d3, rhs: d4 });
+    /// assert_eq!(err.to_string(), "Overflow while multiplying 1.2345 and 0.12345");
+    /// ```
+    pub fn try_mul(self, other: Self) -> Result<Self, DecimalError> {
+        let coefficient = self.coefficient as i128 * other.coefficient as i128;
+        let scaling = self.scaling as u32 + other.scaling as u32;
+        Self::try_from_wide(coefficient, scaling)
+            .map_err(|_| DecimalError::OperationOverflow { operation: Operation::Multiplication, lhs: self, rhs: other })
+    }
+
+    /// Multiply this decimal number by the other decimal number while downscaling the result if
+    /// an overflow occurs during the operation.
+    ///
+    /// If an overflow occurs, the exact product is downscaled (by applying the given rounding mode
+    /// just once) to the greatest scaling factor that makes it representable, although making for
+    /// a loss of precision. It returns `None` only if the product cannot be represented even with
+    /// no digits after the decimal point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let d1 = Decimal::new(12345, 4);
+    /// let d2 = Decimal::new(12345, 5);
+    /// assert!(d1.checked_mul(d2).is_none()); // Overflowed
+    ///
+    /// let rm = RoundingMode::HalfUp;
+    /// let product = d1.rounding_mul(d2, &rm);
+    /// assert!(product.is_some());
+    /// assert_eq!(product.unwrap().to_string(), "0.15239903"); // Rounded
+    /// ```
+    pub fn rounding_mul(self, other: Self, rounding_mode: &RoundingMode) -> Option<Self> {
+        let coefficient = self.coefficient as i128 * other.coefficient as i128;
+        let scaling = self.scaling as u32 + other.scaling as u32;
+        Self::rounding_from_wide(coefficient, scaling, rounding_mode).ok()
+    }
+}
+
+
+impl Mul for Decimal {
+    type Output = Self;
+
+    /// Multiply this decimal number by the other, and it panics in case of overflow.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self
+            .try_mul(rhs)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::MAX_COEFFICIENT;
+    use super::*;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    fn mul_exact() {
+        let d1 = Decimal::new(-123456, 2);
+        let d2 = Decimal::new(2, 1);
+        let d3 = d1 * d2;
+        assert_eq!(d3.coefficient, -246912);
+        assert_eq!(d3.scaling, 3);
+    }
+
+    #[test]
+    fn mul_dropping_trailing_zeros() {
+        // 1000.00 * 1000.00 = 1000000.0000 whose coefficient overflows, unless written as 1000000.000
+        let d1 = Decimal::new(100000, 2);
+        let d2 = Decimal::new(100000, 2);
+        let d3 = d1 * d2;
+        assert_eq!(d3.coefficient, 1000000000);
+        assert_eq!(d3.scaling, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow while multiplying 21474836.47 and 2")]
+    fn mul_overflow() {
+        let d1 = Decimal::new(MAX_COEFFICIENT, 2);
+        let d2 = Decimal::new(2, 0);
+        let _ = d1 * d2;
+    }
+
+    #[test]
+    fn rounding_mul_scaling_overflow() {
+        // 1.2345 * 0.12345 = 0.152399025 (9 digits after the decimal point)
+        let d1 = Decimal::new(12345, 4);
+        let d2 = Decimal::new(12345, 5);
+        let d3 = d1.rounding_mul(d2, &RM).unwrap();
+        assert_eq!(d3.coefficient, 15239903);
+        assert_eq!(d3.scaling, 8);
+    }
+
+    #[test]
+    fn rounding_mul_coefficient_overflow() {
+        // 21474836.47 * -2 = -42949672.94 which can only be represented as -42949672.9
+        let d1 = Decimal::new(MAX_COEFFICIENT, 2);
+        let d2 = Decimal::new(-2, 0);
+        let d3 = d1.rounding_mul(d2, &RM).unwrap();
+        assert_eq!(d3.coefficient, -429496729);
+        assert_eq!(d3.scaling, 1);
+    }
+
+    #[test]
+    fn rounding_mul_overflow() {
+        let d1 = Decimal::new(MAX_COEFFICIENT, 0);
+        let d2 = Decimal::new(2, 0);
+        assert!(d1.rounding_mul(d2, &RM).is_none());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Decimal, RoundingMode};
+
+
+impl Decimal {
+    /// Multiply this decimal number by `a` and then add `b`, with a single rounding at the end.
+    ///
+    /// Same as the [`Decimal::checked_mul_add`] method, but it panics if the result cannot be
+    /// represented even with no digits after the decimal point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let principal = Decimal::new(123456789, 2);
+    /// let rate = Decimal::new(100012347, 8);
+    /// let interest = principal.mul_add(rate, -principal, &RoundingMode::HalfUp);
+    /// assert_eq!(interest.to_string(), "152.4320974");
+    /// ```
+    pub fn mul_add(self, a: Self, b: Self, rounding_mode: &RoundingMode) -> Self {
+        self
+            .checked_mul_add(a, b, rounding_mode)
+            .unwrap_or_else(|| panic!("Overflow while multiplying {} by {} and adding {}", self, a, b))
+    }
+
+    /// Multiply this decimal number by `a` and then add `b`, with a single rounding at the end.
+    ///
+    /// Differently from a [`Decimal::rounding_mul`] followed by an addition, the exact product is
+    /// added to `b` in a wider integer, and only then the sum is downscaled (by applying the given
+    /// rounding mode just once) if it cannot be represented otherwise. That avoids the error of
+    /// rounding the product first, which the addition could make visible (for example, when most
+    /// of the product is cancelled out by `b`). It returns `None` only if the result cannot be
+    /// represented even with no digits after the decimal point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let principal = Decimal::new(123456789, 2);
+    /// let rate = Decimal::new(100012347, 8);
+    ///
+    /// // Rounding twice: 1234720.322 - 1234567.89
+    /// let interest = principal.rounding_mul(rate, &rm).unwrap() + (-principal);
+    /// assert_eq!(interest.to_string(), "152.432");
+    ///
+    /// // Rounding once: 1234720.3220973783 - 1234567.89
+    /// let interest = principal.checked_mul_add(rate, -principal, &rm);
+    /// assert!(interest.is_some());
+    /// assert_eq!(interest.unwrap().to_string(), "152.4320974");
+    /// ```
+    pub fn checked_mul_add(self, a: Self, b: Self, rounding_mode: &RoundingMode) -> Option<Self> {
+        // The product has 63 bits at most, and aligning either operand multiplies it by 10^16 at
+        // most, therefore the exact sum always fits 128 bits.
+        let product = self.coefficient as i128 * a.coefficient as i128;
+        let product_scaling = self.scaling as u32 + a.scaling as u32;
+        let scaling = product_scaling.max(b.scaling as u32);
+        let coefficient =
+            product * 10i128.pow(scaling - product_scaling) +
+            b.coefficient as i128 * 10i128.pow(scaling - b.scaling as u32);
+        Self::rounding_from_wide(coefficient, scaling, rounding_mode).ok()
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use crate::{MAX_COEFFICIENT, MIN_COEFFICIENT};
+    use super::*;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    // Multiply and add in two steps (rounding twice)
+    fn two_steps(d: Decimal, a: Decimal, b: Decimal) -> Option<Decimal> {
+        d.rounding_mul(a, &RM)?.checked_add(b)
+    }
+
+    #[test]
+    fn mul_add_exact() {
+        // 1234.56 * 0.2 + 0.001 = 246.913
+        let d = Decimal::new(123456, 2).mul_add(Decimal::new(2, 1), Decimal::new(1, 3), &RM);
+        assert_eq!(d.coefficient, 246913);
+        assert_eq!(d.scaling, 3);
+    }
+
+    #[test]
+    fn mul_add_same_as_two_steps_when_exact() {
+        let (d, a, b) = (Decimal::new(10050, 2), Decimal::new(-3, 0), Decimal::new(12345, 4));
+        let d1 = d.checked_mul_add(a, b, &RM).unwrap();
+        let d2 = two_steps(d, a, b).unwrap();
+        assert_eq!((d1.coefficient, d1.scaling), (d2.coefficient, d2.scaling));
+    }
+
+    #[test]
+    fn mul_add_disagrees_with_two_steps_on_accrual() {
+        // 1234567.89 * 1.00012347 - 1234567.89 = 152.4320973783
+        let (d, a) = (Decimal::new(123456789, 2), Decimal::new(100012347, 8));
+        let d1 = d.checked_mul_add(a, -d, &RM).unwrap();
+        assert_eq!((d1.coefficient, d1.scaling), (1524320974, 7));
+
+        // The product is rounded to 1234720.322 first
+        let d2 = two_steps(d, a, -d).unwrap();
+        assert_eq!((d2.coefficient, d2.scaling), (152432, 3));
+    }
+
+    #[test]
+    fn mul_add_disagrees_with_two_steps_on_cancellation() {
+        // 21474836.47 * 2 - 21474836.47 = 21474836.47
+        let (d, a) = (Decimal::new(MAX_COEFFICIENT, 2), Decimal::new(2, 0));
+        let d1 = d.checked_mul_add(a, -d, &RM).unwrap();
+        assert_eq!((d1.coefficient, d1.scaling), (MAX_COEFFICIENT, 2));
+
+        // The product is rounded to 42949672.9 first
+        let d2 = two_steps(d, a, -d).unwrap();
+        assert_eq!((d2.coefficient, d2.scaling), (2147483643, 2));
+    }
+
+    #[test]
+    fn mul_add_rounding_once() {
+        // 1.23456789 * 1.00000005 + 30 = 31.2345679517283945 which can only be represented as 31.2345680
+        let d = Decimal::new(123456789, 8).mul_add(Decimal::new(100000005, 8), Decimal::new(30, 0), &RM);
+        assert_eq!(d.coefficient, 312345680);
+        assert_eq!(d.scaling, 7);
+    }
+
+    #[test]
+    fn mul_add_aligning_addend() {
+        // 2 * 3 + 0.00000001 = 6.00000001
+        let d = Decimal::new(2, 0).mul_add(Decimal::new(3, 0), Decimal::new(1, 8), &RM);
+        assert_eq!(d.coefficient, 600000001);
+        assert_eq!(d.scaling, 8);
+    }
+
+    #[test]
+    fn checked_mul_add_overflow() {
+        let d = Decimal::new(MIN_COEFFICIENT, 0);
+        assert!(d.checked_mul_add(d, d, &RM).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow while multiplying 2147483647 by 2 and adding 1")]
+    fn mul_add_overflow() {
+        let _ = Decimal::new(MAX_COEFFICIENT, 0).mul_add(Decimal::new(2, 0), Decimal::new(1, 0), &RM);
+    }
+}
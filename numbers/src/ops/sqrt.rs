@@ -0,0 +1,122 @@
+use crate::{Decimal, RoundingMode, MAX_SCALING};
+use crate::big::magnitude;
+use alloc::vec::Vec;
+
+
+impl Decimal {
+    /// Compute the square root of this decimal number, while rounding it according to the given
+    /// rounding mode.
+    ///
+    /// As square roots are not exact in general (such as $\sqrt{2}$), it is computed with
+    /// [`MAX_SCALING`] digits after the decimal point at most, which the rounding mode is applied
+    /// just once to. It returns `None` if this decimal number is negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// assert_eq!(Decimal::new(2, 0).rounding_sqrt(&rm).unwrap().to_string(), "1.41421356");
+    /// assert_eq!(Decimal::new(225, 2).rounding_sqrt(&rm), Some(Decimal::new(15, 1)));
+    /// assert!(Decimal::new(-1, 0).rounding_sqrt(&rm).is_none());
+    /// ```
+    pub fn rounding_sqrt(self, rounding_mode: &RoundingMode) -> Option<Self> {
+        Self::rounding_sqrt_from_wide(self.coefficient as i128, self.scaling as u32, rounding_mode)
+    }
+
+    // Same as the above method, but for a number given as a wide coefficient and its scaling
+    // factor (such as an exactly accumulated sum of squares).
+    pub(crate) fn rounding_sqrt_from_wide(coefficient: i128, scaling: u32, rounding_mode: &RoundingMode) -> Option<Self> {
+        if coefficient < 0 {
+            return None;
+        }
+
+        // The root is computed (truncated) with one more digit than the maximum scaling factor,
+        // which is enough for rounding it correctly, unless the radicand overflows (and then the
+        // root would be too large for those digits to be represented anyway).
+        let mut root_scaling = MAX_SCALING as u32 + 1;
+        let radicand = loop {
+            let radicand =
+                if 2 * root_scaling >= scaling {
+                    coefficient.checked_mul(10i128.pow(2 * root_scaling - scaling))
+                }
+                else {
+                    Some(coefficient / 10i128.pow(scaling - 2 * root_scaling))
+                };
+            match radicand {
+                Some(radicand) => break radicand,
+                None => root_scaling -= 1,
+            }
+        };
+
+        Self::rounding_from_wide(radicand.isqrt(), root_scaling, rounding_mode).ok()
+    }
+
+    // Divide a wide coefficient by the square root of the product of other (non negative) wide
+    // coefficients, all with their scaling factors, while rounding the quotient just once
+    // according to the given rounding mode (such as a dot product divided by the norms). The
+    // quotient is expected not to exceed one (in absolute value), so that its square can be
+    // computed exactly (truncated with twice as many digits as the root requires), given that
+    //   (n 10^-s)² / Π(r 10^-t) = n² 10^(Σt - 2s) / Πr
+    pub(crate) fn rounding_root_quotient_from_wide(numerator: (i128, u32), radicands: &[(i128, u32)], rounding_mode: &RoundingMode) -> Option<Self> {
+        let (coefficient, scaling) = numerator;
+        let root_scaling = MAX_SCALING as u32 + 1;
+        let exponent = (2 * root_scaling + radicands.iter().map(|(_, t)| t).sum::<u32>()).checked_sub(2 * scaling)?;
+        let radicands: Vec<u128> = radicands.iter().map(|(r, _)| u128::try_from(*r).ok()).collect::<Option<_>>()?;
+
+        let magnitude = coefficient.unsigned_abs();
+        let square = magnitude::quotient_of_products(&[magnitude, magnitude], exponent, &radicands)?;
+        let root = Self::rounding_sqrt_from_wide(square as i128, 2 * root_scaling, rounding_mode)?;
+        Some(if coefficient < 0 { -root } else { root })
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MAX_COEFFICIENT;
+
+    const RM: RoundingMode = RoundingMode::HalfUp;
+
+    #[test]
+    fn sqrt_exact() {
+        let r = Decimal::new(144, 0).rounding_sqrt(&RM).unwrap();
+        assert_eq!(r, Decimal::new(12, 0));
+
+        let r = Decimal::new(0, 3).rounding_sqrt(&RM).unwrap();
+        assert_eq!(r, Decimal::new(0, 0));
+    }
+
+    #[test]
+    fn sqrt_rounding_half_up() {
+        // √3 = 1.7320508075...
+        let r = Decimal::new(3, 0).rounding_sqrt(&RM).unwrap();
+        assert_eq!((r.coefficient, r.scaling), (173205081, 8));
+
+        // √0.00000002 = 0.000141421356...
+        let r = Decimal::new(2, 8).rounding_sqrt(&RM).unwrap();
+        assert_eq!((r.coefficient, r.scaling), (14142, 8));
+    }
+
+    #[test]
+    fn sqrt_large() {
+        // √21474836.47 = 4634.0950001...
+        let r = Decimal::new(MAX_COEFFICIENT, 2).rounding_sqrt(&RM).unwrap();
+        assert_eq!((r.coefficient, r.scaling), (463409500, 5));
+    }
+
+    #[test]
+    fn sqrt_of_wide_overflowing_radicand() {
+        // √(10^36) = 10^18, which cannot be represented
+        assert!(Decimal::rounding_sqrt_from_wide(10i128.pow(36), 0, &RM).is_none());
+        // √(10^34 * 10^-16) = 10^9
+        let r = Decimal::rounding_sqrt_from_wide(10i128.pow(34), 16, &RM).unwrap();
+        assert_eq!(r, Decimal::new(1_000_000_000, 0));
+    }
+
+    #[test]
+    fn sqrt_of_negative() {
+        assert!(Decimal::new(-4, 0).rounding_sqrt(&RM).is_none());
+    }
+}
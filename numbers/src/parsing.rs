@@ -0,0 +1,343 @@
+use crate::{BadFormatKind, Decimal, DecimalError};
+use alloc::vec::Vec;
+
+
+impl Decimal {
+
+    /// Attempt to parse a decimal number from the beginning of the given byte buffer.
+    ///
+    /// Differently from the [`std::str::FromStr`] implementation, this function does not require
+    /// valid UTF-8 input, and it does not require the whole buffer to represent a decimal number.
+    /// It stops at the first byte that cannot be part of a decimal number (such as a delimiter),
+    /// and it returns ok by wrapping the parsed decimal number together with the count of consumed
+    /// bytes, so that it can be easily used inside a tokenizer. Otherwise, it returns one of the
+    /// following errors:
+    ///
+    /// - [`DecimalError::BadFormat`]<br>
+    ///   If the buffer is empty, or if it doesn't start with a decimal number
+    ///
+    /// - [`DecimalError::CoefficientOverflow`]<br>
+    ///   If the coefficient exceeds [`MAX_COEFFICIENT`]
+    ///
+    /// - [`DecimalError::ScalingOverflow`]<br>
+    ///   If the scaling factor exceeds [`MAX_SCALING`]
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let (d, consumed) = Decimal::parse_bytes(b"1234.56,789").unwrap();
+    /// assert_eq!(d.to_string(), "1234.56");
+    /// assert_eq!(consumed, 7);
+    /// ```
+    ///
+    /// [`MAX_COEFFICIENT`]: constant.MAX_COEFFICIENT.html
+    /// [`MAX_SCALING`]: constant.MAX_SCALING.html
+    pub fn parse_bytes(bytes: &[u8]) -> Result<(Self, usize), DecimalError> {
+        let (coefficient, scaling, consumed) = accumulate(bytes)?;
+        if consumed == 0 {
+            return Err(DecimalError::BadFormat { position: 0, kind: BadFormatKind::InvalidCharacter });
+        }
+        Self::try_new(coefficient, scaling).map(|decimal| (decimal, consumed))
+    }
+
+
+    /// Attempt to parse a whole column of decimal numbers separated by the given delimiter.
+    ///
+    /// This function is meant for bulk loading of large buffers (such as the price column of a
+    /// tick file), and it produces exactly the same results and errors that parsing each field
+    /// on its own would produce. A trailing delimiter at the very end of the buffer is allowed,
+    /// while an empty field results in [`DecimalError::BadFormat`]. The position of any bad format
+    /// is reported as an offset from the beginning of the whole buffer (rather than of its field).
+    ///
+    /// Both the counting of the delimiters (to allocate the resulting vector just once) and the
+    /// accumulation of runs of 8 digits are performed 8 bytes at a time (SWAR), rather than one
+    /// byte at a time.
+    ///
+    /// <div class="warning">The delimiter must not be a character allowed in decimal numbers,
+    /// such as digits, '+', '-' or '.'</div>
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let column = Decimal::parse_column(b"1234.56\n-0.5\n12\n", b'\n').unwrap();
+    /// assert_eq!(column.len(), 3);
+    /// assert_eq!(column[0].to_string(), "1234.56");
+    /// assert_eq!(column[1].to_string(), "-0.5");
+    /// assert_eq!(column[2].to_string(), "12");
+    /// ```
+    pub fn parse_column(bytes: &[u8], delimiter: u8) -> Result<Vec<Self>, DecimalError> {
+        let mut column = Vec::with_capacity(count_delimiters(bytes, delimiter) + 1);
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if bytes[pos] == delimiter {
+                return Err(DecimalError::BadFormat { position: pos, kind: BadFormatKind::Empty });
+            }
+            let (decimal, consumed) = Self::parse_bytes(&bytes[pos..]).map_err(|err| err.offset_by(pos))?;
+            pos += consumed;
+            match bytes.get(pos) {
+                Some(&b) if b == delimiter => pos += 1,
+                Some(_) => return Err(DecimalError::BadFormat { position: pos, kind: BadFormatKind::InvalidCharacter }),
+                None => {}
+            }
+            column.push(decimal);
+        }
+        Ok(column)
+    }
+}
+
+
+
+// Accumulate the coefficient and the scaling factor of the decimal number found at the beginning
+// of the given bytes, and return them together with the count of consumed bytes. The scaling
+// factor is NOT checked against its range, so that callers can decide which error comes first.
+pub(crate) fn accumulate(bytes: &[u8]) -> Result<(i32, u8, usize), DecimalError> {
+    let len = bytes.len();
+    if len == 0 {
+        return Err(DecimalError::BadFormat { position: 0, kind: BadFormatKind::Empty });
+    }
+
+    let mut accumulated_coefficient: i32 = 0;
+    let mut accumulated_scaling: u8 = 0;
+    let mut minus_encountered = false;
+    let mut dot_encountered = false;
+
+    let mut i = 0;
+    while i < len {
+        let c = bytes[i];
+
+        // Fast path: if the next 8 bytes are all digits, then accumulate them at once as if
+        // they were a single "digit" in base 10^8. Note that if the final coefficient fits, then
+        // all the intermediate ones fit too, therefore overflows are still detected correctly.
+        if i + 8 <= len {
+            let chunk = u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap());
+            if is_eight_digits(chunk) {
+                let digits = eight_digits_value(chunk) as i32;
+                let checked_coefficient =
+                    accumulated_coefficient
+                        .checked_mul(100_000_000)
+                        .and_then(|x|
+                            if !minus_encountered { x.checked_add(digits) }
+                            else                  { x.checked_sub(digits) }
+                        );
+
+                match checked_coefficient {
+                    Some(c) => accumulated_coefficient = c,
+                    None => return Err(DecimalError::CoefficientOverflow)
+                }
+                if dot_encountered {
+                    accumulated_scaling = accumulated_scaling.saturating_add(8);
+                }
+                i += 8;
+                continue;
+            }
+        }
+
+        // The '+' character is allowed only once (at the beginning of the given bytes)
+        if c == b'+' && i > 0 {
+            return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::MisplacedPlus });
+        }
+
+        // The '-' character is allowed only once (at the beginning of the given bytes)
+        else if c == b'-' {
+            if i > 0 {
+                return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::MisplacedMinus });
+            }
+            minus_encountered = true;
+        }
+
+        else if c == b'.' {
+            if dot_encountered {
+                // double dot encountered!
+                return Err(DecimalError::BadFormat { position: i, kind: BadFormatKind::DoubleDot });
+            }
+            dot_encountered = true;
+        }
+
+        else if c.is_ascii_digit() {
+            //
+            // Update the coefficient by multiplying it by 10 and then adding (or subtracting)
+            // the current character digit
+            //
+            //      coefficient = coefficient * 10 + (c - 48)
+            //
+            // If the result overflows, return an error
+            //
+            let checked_coefficient =
+                accumulated_coefficient
+                    .checked_mul(10)
+                    .and_then(|x|
+                        if !minus_encountered { x.checked_add((c - b'0') as i32) }
+                        else                  { x.checked_sub((c - b'0') as i32) }
+                    );
+
+            match checked_coefficient {
+                Some(c) => accumulated_coefficient = c,
+                None => return Err(DecimalError::CoefficientOverflow)
+            }
+            if dot_encountered {
+                accumulated_scaling = accumulated_scaling.saturating_add(1);
+            }
+        }
+
+        else {
+            // Any other byte (including a leading '+') terminates the decimal number
+            break;
+        }
+
+        i += 1;
+    }
+
+    Ok((accumulated_coefficient, accumulated_scaling, i))
+}
+
+
+
+// Tell whether all the 8 bytes packed (in little-endian order) into the given word are
+// ASCII digits. The high nibble of each byte must be 0x3, and adding 6 to each byte must
+// not carry into its high nibble (which would happen for bytes from ':' to '?')
+#[inline(always)]
+fn is_eight_digits(chunk: u64) -> bool {
+    let high_nibbles = chunk & 0xF0F0_F0F0_F0F0_F0F0;
+    let carried = (chunk.wrapping_add(0x0606_0606_0606_0606) & 0xF0F0_F0F0_F0F0_F0F0) >> 4;
+    (high_nibbles | carried) == 0x3333_3333_3333_3333
+}
+
+
+// Compute the value of the 8 ASCII digits packed (in little-endian order) into the given word,
+// by combining adjacent digits into pairs, then pairs into quartets, and finally quartets into
+// the resulting octet, with just 3 multiplications.
+#[inline(always)]
+fn eight_digits_value(chunk: u64) -> u32 {
+    let mut value = chunk & 0x0F0F_0F0F_0F0F_0F0F;
+    value = (value.wrapping_mul(1 + (10 << 8)) >> 8) & 0x00FF_00FF_00FF_00FF;
+    value = (value.wrapping_mul(1 + (100 << 16)) >> 16) & 0x0000_FFFF_0000_FFFF;
+    (value.wrapping_mul(1 + (10000 << 32)) >> 32) as u32
+}
+
+
+// Count the occurrences of the given delimiter, 8 bytes at a time
+fn count_delimiters(bytes: &[u8], delimiter: u8) -> usize {
+    const LOW_BITS: u64 = 0x7F7F_7F7F_7F7F_7F7F;
+    const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+    let pattern = u64::from_ne_bytes([delimiter; 8]);
+
+    let chunks = bytes.chunks_exact(8);
+    let remainder = chunks.remainder();
+    let mut count = 0;
+    for chunk in chunks {
+        // Bytes equal to the delimiter become zero, and only zero bytes end up with their
+        // high bit set after the following (carry-free) computation
+        let x = u64::from_ne_bytes(chunk.try_into().unwrap()) ^ pattern;
+        let zeros = !(((x & LOW_BITS).wrapping_add(LOW_BITS)) | x) & HIGH_BITS;
+        count += zeros.count_ones() as usize;
+    }
+    count + remainder.iter().filter(|&&b| b == delimiter).count()
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn eight_digits() {
+        let chunk = u64::from_le_bytes(*b"12345678");
+        assert!(is_eight_digits(chunk));
+        assert_eq!(eight_digits_value(chunk), 12345678);
+
+        assert!(!is_eight_digits(u64::from_le_bytes(*b"1234.678")));
+        assert!(!is_eight_digits(u64::from_le_bytes(*b"1234567:")));
+        assert!(!is_eight_digits(u64::from_le_bytes(*b"/1234567")));
+    }
+
+    #[test]
+    fn count_delimiters_in_long_buffer() {
+        let bytes = b"1,2,3,4,5,6,7,8,9,10,11,12,13,14,15";
+        assert_eq!(count_delimiters(bytes, b','), 14);
+        assert_eq!(count_delimiters(bytes, b';'), 0);
+    }
+
+    #[test]
+    fn parse_bytes_stops_at_delimiter() {
+        let (decimal, consumed) = Decimal::parse_bytes(b"-1234.56;99").unwrap();
+        assert_eq!(decimal.coefficient, -123456);
+        assert_eq!(decimal.scaling, 2);
+        assert_eq!(consumed, 8);
+    }
+
+    #[test]
+    fn parse_bytes_long_digit_runs() {
+        let (decimal, consumed) = Decimal::parse_bytes(b"21474836.47").unwrap();
+        assert_eq!(decimal.coefficient, i32::MAX);
+        assert_eq!(decimal.scaling, 2);
+        assert_eq!(consumed, 11);
+
+        let (decimal, _) = Decimal::parse_bytes(b"-0.00045678").unwrap();
+        assert_eq!(decimal.coefficient, -45678);
+        assert_eq!(decimal.scaling, 8);
+    }
+
+    #[test]
+    fn parse_bytes_invalid_start_err() {
+        let res = Decimal::parse_bytes(b"?12");
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 0, kind: BadFormatKind::InvalidCharacter });
+    }
+
+    #[test]
+    fn parse_bytes_coefficient_overflow_err() {
+        let res = Decimal::parse_bytes(b"12345678901,1");
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+    }
+
+    #[test]
+    fn parse_column_of_prices() {
+        let column = Decimal::parse_column(b"1234.56,-0.00045678,21474836.47,12", b',').unwrap();
+        assert_eq!(column.len(), 4);
+        assert_eq!((column[0].coefficient, column[0].scaling), (123456, 2));
+        assert_eq!((column[1].coefficient, column[1].scaling), (-45678, 8));
+        assert_eq!((column[2].coefficient, column[2].scaling), (i32::MAX, 2));
+        assert_eq!((column[3].coefficient, column[3].scaling), (12, 0));
+    }
+
+    #[test]
+    fn parse_column_semicolon_delimited() {
+        // The ';' character must be treated as a delimiter, not as a digit
+        let column = Decimal::parse_column(b"1;2;", b';').unwrap();
+        assert_eq!(column.len(), 2);
+        assert_eq!(column[0].coefficient, 1);
+        assert_eq!(column[1].coefficient, 2);
+    }
+
+    #[test]
+    fn parse_column_empty_field_err() {
+        let res = Decimal::parse_column(b"1,,2", b',');
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 2, kind: BadFormatKind::Empty });
+    }
+
+    #[test]
+    fn parse_column_invalid_char_err() {
+        let res = Decimal::parse_column(b"1,2?,3", b',');
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 3, kind: BadFormatKind::InvalidCharacter });
+    }
+
+    #[test]
+    fn parse_column_same_as_from_str() {
+        let fields = [
+            "0", "-0", ".5", "-.5", "1234.56", "-21474836.48", "21474836.48", "1.234567890",
+            "1.23456789", "12345678", "123456789", "1234567890", "12345678901", "00000000000001",
+            "1.2.3", "1-2", "+1", "1+", "-", ".", "12345678.?",
+        ];
+        for field in fields {
+            let expected = Decimal::from_str(field);
+            let actual = Decimal::parse_column(field.as_bytes(), b',').map(|column| column[0]);
+            match (expected, actual) {
+                (Ok(e), Ok(a)) => assert_eq!((e.coefficient, e.scaling), (a.coefficient, a.scaling)),
+                (Err(e), Err(a)) => assert_eq!(e.to_string(), a.to_string()),
+                (e, a) => panic!("{field}: {e:?} != {a:?}")
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+// Codec of decimal numbers to and from the binary wire format of the PostgreSQL NUMERIC type,
+// which is made of the following sequence of 16 bits integers (in network byte order):
+//
+//      ndigits | weight | sign | dscale | digits[0] | digits[1] | ... | digits[ndigits - 1]
+//
+// where the digits are in base 10000 (each one standing for 4 decimal digits), the weight is the
+// power of 10000 of the first digit, and the display scale is the count of decimal digits after
+// the decimal point. For example, "1234.56" is encoded as:
+//
+//      ndigits = 2, weight = 0, sign = 0x0000, dscale = 2, digits = [1234, 5600]
+//
+// Leading and trailing zero digits are omitted, therefore zero is encoded with no digits at all.
+
+use crate::{BadFormatKind, Decimal, DecimalError, MAX_SCALING};
+use alloc::vec::Vec;
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000;
+const SIGN_POSITIVE_INFINITY: u16 = 0xD000;
+const SIGN_NEGATIVE_INFINITY: u16 = 0xF000;
+
+// The count of decimal digits stood for by each base 10000 digit
+const DIGIT_WIDTH: u32 = 4;
+const BASE: u64 = 10000;
+
+
+impl Decimal {
+
+    /// Encode this decimal number in the binary wire format of the PostgreSQL `NUMERIC` type.
+    ///
+    /// The display scale of the encoded number is the same as the scaling factor of this decimal
+    /// number, so that `1234.50` is stored (and displayed back by PostgreSQL) as `1234.50`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let d = Decimal::new(123456, 2);
+    /// let bytes = d.to_pg_numeric();
+    /// assert_eq!(bytes, [0, 2, 0, 0, 0, 0, 0, 2, 0x04, 0xD2, 0x15, 0xE0]);
+    /// ```
+    pub fn to_pg_numeric(self) -> Vec<u8> {
+        // Align the coefficient to a multiple of 4 digits after the decimal point, so that
+        // it can be split into base 10000 digits straight away
+        let fractional_digits = (self.scaling as u32).div_ceil(DIGIT_WIDTH);
+        let mut c = self.coefficient.unsigned_abs() as u64
+            * 10u64.pow(fractional_digits * DIGIT_WIDTH - self.scaling as u32);
+
+        // Split the coefficient, starting from the least significant base 10000 digit
+        let mut digits: Vec<u16> = Vec::with_capacity(5);
+        while c > 0 {
+            digits.push((c % BASE) as u16);
+            c /= BASE;
+        }
+        let weight = digits.len() as i16 - fractional_digits as i16 - 1;
+
+        // Omit the trailing zero digits (which are now the first ones)
+        let trailing_zeros = digits.iter().take_while(|&&d| d == 0).count();
+        let digits = &digits[trailing_zeros..];
+
+        let sign = if self.coefficient < 0 { SIGN_NEGATIVE } else { SIGN_POSITIVE };
+        let weight = if digits.is_empty() { 0 } else { weight };
+
+        let mut bytes = Vec::with_capacity(8 + 2 * digits.len());
+        bytes.extend_from_slice(&(digits.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(&weight.to_be_bytes());
+        bytes.extend_from_slice(&sign.to_be_bytes());
+        bytes.extend_from_slice(&(self.scaling as u16).to_be_bytes());
+        for digit in digits.iter().rev() {
+            bytes.extend_from_slice(&digit.to_be_bytes());
+        }
+        bytes
+    }
+
+
+    /// Attempt to decode a decimal number from the binary wire format of the PostgreSQL `NUMERIC`
+    /// type.
+    ///
+    /// The scaling factor of the decoded decimal number is the same as the display scale of the
+    /// encoded number, unless trailing zeros have to be dropped to make it fit. It returns ok by
+    /// wrapping the decoded decimal number, or it returns one of the following errors:
+    ///
+    /// - [`DecimalError::BadFormat`]<br>
+    ///   If the given bytes are truncated or malformed, or if they encode `NaN` or infinity
+    ///   (with the position of the offending word, or the length of the truncated bytes)
+    ///
+    /// - [`DecimalError::ScalingOverflow`]<br>
+    ///   If there are non-zero digits beyond [`MAX_SCALING`](crate::MAX_SCALING)
+    ///
+    /// - [`DecimalError::CoefficientOverflow`]<br>
+    ///   If the coefficient exceeds [`MAX_COEFFICIENT`](crate::MAX_COEFFICIENT)
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{BadFormatKind, Decimal, DecimalError};
+    /// let bytes = [0, 2, 0, 0, 0x40, 0, 0, 2, 0x04, 0xD2, 0x15, 0xE0];
+    /// let d = Decimal::from_pg_numeric(&bytes).unwrap();
+    /// assert_eq!(d.to_string(), "-1234.56");
+    ///
+    /// let nan = [0, 0, 0, 0, 0xC0, 0, 0, 0];
+    /// assert!(matches!(Decimal::from_pg_numeric(&nan), Err(DecimalError::BadFormat { kind: BadFormatKind::NotANumber, .. })));
+    /// ```
+    pub fn from_pg_numeric(bytes: &[u8]) -> Result<Self, DecimalError> {
+        let bad_format = |position: usize, kind: BadFormatKind| DecimalError::BadFormat { position, kind };
+        let word = |i: usize| -> Result<u16, DecimalError> {
+            bytes
+                .get(2 * i .. 2 * i + 2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]))
+                .ok_or(bad_format(bytes.len(), BadFormatKind::Truncated))
+        };
+
+        let ndigits = word(0)? as usize;
+        let weight = word(1)? as i16 as i32;
+        let sign = word(2)?;
+        let dscale = word(3)? as u32;
+
+        let negative = match sign {
+            SIGN_POSITIVE => false,
+            SIGN_NEGATIVE => true,
+            SIGN_NAN => return Err(bad_format(4, BadFormatKind::NotANumber)),
+            SIGN_POSITIVE_INFINITY | SIGN_NEGATIVE_INFINITY =>
+                return Err(bad_format(4, BadFormatKind::Infinity)),
+            _ => return Err(bad_format(4, BadFormatKind::InvalidSign))
+        };
+        if bytes.len() != 8 + 2 * ndigits {
+            return Err(bad_format(bytes.len(), BadFormatKind::Truncated));
+        }
+
+        // Accumulate the base 10000 digits into a coefficient whose scaling factor is that of the
+        // least significant digit (which might be either less or greater than the display scale)
+        let mut coefficient: i128 = 0;
+        for i in 0..ndigits {
+            let digit = word(4 + i)?;
+            if digit as u64 >= BASE {
+                return Err(bad_format(8 + 2 * i, BadFormatKind::InvalidDigit));
+            }
+            coefficient = coefficient
+                .checked_mul(BASE as i128)
+                .and_then(|c| c.checked_add(digit as i128))
+                .ok_or(DecimalError::CoefficientOverflow)?;
+        }
+        let mut scaling = (ndigits as i32 - weight - 1) * DIGIT_WIDTH as i32;
+
+        // Negative scaling factors (such as for 1E+8, whose only digit is 1 with weight 2) stand
+        // for trailing zeros that must be appended to the coefficient
+        if scaling < 0 {
+            if coefficient != 0 {
+                coefficient = 10i128
+                    .checked_pow(-scaling as u32)
+                    .and_then(|p| coefficient.checked_mul(p))
+                    .ok_or(DecimalError::CoefficientOverflow)?;
+            }
+            scaling = 0;
+        }
+
+        // Align the coefficient to the display scale, but don't bother appending more trailing
+        // zeros than the maximum scaling allows (as they would be dropped anyway)
+        let scaling = scaling as u32;
+        let dscale =
+            if dscale > scaling { dscale.min(scaling.max(MAX_SCALING as u32)) }
+            else { dscale };
+
+        if dscale > scaling {
+            coefficient = 10i128
+                .checked_pow(dscale - scaling)
+                .and_then(|p| coefficient.checked_mul(p))
+                .ok_or(DecimalError::CoefficientOverflow)?;
+        }
+        else if dscale < scaling && coefficient != 0 {
+            // Digits beyond the display scale are expected to be zeros (the last digit is reported)
+            coefficient = 10i128
+                .checked_pow(scaling - dscale)
+                .filter(|p| coefficient % p == 0)
+                .map(|p| coefficient / p)
+                .ok_or(bad_format(bytes.len() - 2, BadFormatKind::DigitsBeyondScale))?;
+        }
+
+        if negative {
+            coefficient = -coefficient;
+        }
+        Self::try_from_wide(coefficient, dscale)
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MAX_COEFFICIENT, MAX_SCALING, MIN_COEFFICIENT};
+
+    // The following fixtures are the bytes sent by PostgreSQL for the given NUMERIC literals,
+    // as captured from the binary results of `SELECT '<literal>'::numeric`
+
+    // '1234.56'
+    const FIXTURE_1234_56: [u8; 12] = [0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x04, 0xD2, 0x15, 0xE0];
+
+    // '-0.00045678'
+    const FIXTURE_MINUS_0_00045678: [u8; 12] = [0x00, 0x02, 0xFF, 0xFF, 0x40, 0x00, 0x00, 0x08, 0x00, 0x04, 0x16, 0x2E];
+
+    // '21474836.47'
+    const FIXTURE_21474836_47: [u8; 14] = [0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x08, 0x63, 0x12, 0xE4, 0x12, 0x5C];
+
+    // '1000000'
+    const FIXTURE_1000000: [u8; 10] = [0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64];
+
+    // '0.00'
+    const FIXTURE_0_00: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02];
+
+    // '12345678901'
+    const FIXTURE_12345678901: [u8; 14] = [0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7B, 0x11, 0xD7, 0x22, 0xC5];
+
+    // '0.123456789'
+    const FIXTURE_0_123456789: [u8; 14] = [0x00, 0x03, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x09, 0x04, 0xD2, 0x16, 0x2E, 0x23, 0x28];
+
+    // '1.2300000000' (the display scale exceeds the maximum scaling, but only with trailing zeros)
+    const FIXTURE_1_2300000000: [u8; 12] = [0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x01, 0x08, 0xFC];
+
+    // 'NaN'
+    const FIXTURE_NAN: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00];
+
+    // 'Infinity'
+    const FIXTURE_INFINITY: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0xD0, 0x00, 0x00, 0x00];
+
+    #[test]
+    fn encode_fixtures() {
+        assert_eq!(Decimal::new(123456, 2).to_pg_numeric(), FIXTURE_1234_56);
+        assert_eq!(Decimal::new(-45678, 8).to_pg_numeric(), FIXTURE_MINUS_0_00045678);
+        assert_eq!(Decimal::new(MAX_COEFFICIENT, 2).to_pg_numeric(), FIXTURE_21474836_47);
+        assert_eq!(Decimal::new(1000000, 0).to_pg_numeric(), FIXTURE_1000000);
+        assert_eq!(Decimal::new(0, 2).to_pg_numeric(), FIXTURE_0_00);
+    }
+
+    #[test]
+    fn decode_fixtures() {
+        let d = Decimal::from_pg_numeric(&FIXTURE_1234_56).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (123456, 2));
+
+        let d = Decimal::from_pg_numeric(&FIXTURE_MINUS_0_00045678).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (-45678, 8));
+
+        let d = Decimal::from_pg_numeric(&FIXTURE_21474836_47).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (MAX_COEFFICIENT, 2));
+
+        let d = Decimal::from_pg_numeric(&FIXTURE_1000000).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (1000000, 0));
+
+        let d = Decimal::from_pg_numeric(&FIXTURE_0_00).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (0, 2));
+
+        let d = Decimal::from_pg_numeric(&FIXTURE_1_2300000000).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (123000000, 8));
+    }
+
+    #[test]
+    fn round_trip_boundaries() {
+        for (coefficient, scaling) in [
+            (MIN_COEFFICIENT, 0), (MAX_COEFFICIENT, 0),
+            (MIN_COEFFICIENT, MAX_SCALING), (MAX_COEFFICIENT, MAX_SCALING),
+            (1, 1), (-10, 3), (99990000, 5)
+        ] {
+            let bytes = Decimal::new(coefficient, scaling).to_pg_numeric();
+            let d = Decimal::from_pg_numeric(&bytes).unwrap();
+            assert_eq!((d.coefficient, d.scaling), (coefficient, scaling));
+        }
+    }
+
+    #[test]
+    fn decode_nan_err() {
+        let res = Decimal::from_pg_numeric(&FIXTURE_NAN);
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 4, kind: BadFormatKind::NotANumber });
+    }
+
+    #[test]
+    fn decode_infinity_err() {
+        let res = Decimal::from_pg_numeric(&FIXTURE_INFINITY);
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 4, kind: BadFormatKind::Infinity });
+    }
+
+    #[test]
+    fn decode_truncated_err() {
+        let res = Decimal::from_pg_numeric(&FIXTURE_1234_56[..10]);
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 10, kind: BadFormatKind::Truncated });
+
+        let res = Decimal::from_pg_numeric(&FIXTURE_1234_56[..6]);
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 6, kind: BadFormatKind::Truncated });
+    }
+
+    #[test]
+    fn decode_invalid_digit_err() {
+        // The second digit is 10000, which is out of the base 10000 range
+        let bytes = [0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x27, 0x10];
+        let res = Decimal::from_pg_numeric(&bytes);
+        assert_eq!(res.err().unwrap(), DecimalError::BadFormat { position: 10, kind: BadFormatKind::InvalidDigit });
+    }
+
+    #[test]
+    fn decode_far_display_scale() {
+        // '1.5' with a display scale of 1000
+        let bytes = [0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0xE8, 0x00, 0x01, 0x13, 0x88];
+        let d = Decimal::from_pg_numeric(&bytes).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (150000000, 8));
+    }
+
+    #[test]
+    fn decode_coefficient_overflow_err() {
+        let res = Decimal::from_pg_numeric(&FIXTURE_12345678901);
+        assert!(matches!(res.err().unwrap(), DecimalError::CoefficientOverflow));
+    }
+
+    #[test]
+    fn decode_scaling_overflow_err() {
+        let res = Decimal::from_pg_numeric(&FIXTURE_0_123456789);
+        assert!(matches!(res.err().unwrap(), DecimalError::ScalingOverflow));
+    }
+}
@@ -0,0 +1,63 @@
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A trait for floating point numbers (such as `f32` and `f64`) providing the elementary
+/// functions required by [`Dual`](crate::Dual) and [`Complex`](crate::Complex) numbers (as well
+/// as by the norms and angles of vectors in linear algebra).
+///
+/// It requires the `std` feature, as the elementary functions of floats are not available in `core`.
+pub trait Real:
+    Copy + Default + PartialOrd + Sum +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// Convert the given `f64` into this type (possibly losing precision)
+    fn from_f64(x: f64) -> Self;
+
+    /// Convert this number into an `f64`
+    fn to_f64(self) -> f64;
+
+    /// Raise $e$ to the power of this number
+    fn exp(self) -> Self;
+
+    /// Compute the natural logarithm of this number
+    fn ln(self) -> Self;
+
+    /// Compute the square root of this number
+    fn sqrt(self) -> Self;
+
+    /// Raise this number to a floating point power
+    fn powf(self, n: Self) -> Self;
+
+    /// Compute the sine and the cosine of this number (in radians) at once
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Compute the arccosine of this number (in the range $[-1, 1]$), in radians
+    fn acos(self) -> Self;
+
+    /// Compute the four quadrant arctangent of this number (as the `y` coordinate) and `x`
+    fn atan2(self, x: Self) -> Self;
+
+    /// Compute the length of the hypotenuse of a right-angle triangle given its legs' lengths
+    fn hypot(self, other: Self) -> Self;
+}
+
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl Real for $t {
+            fn from_f64(x: f64) -> Self { x as $t }
+            fn to_f64(self) -> f64 { self as f64 }
+            fn exp(self) -> Self { <$t>::exp(self) }
+            fn ln(self) -> Self { <$t>::ln(self) }
+            fn sqrt(self) -> Self { <$t>::sqrt(self) }
+            fn powf(self, n: Self) -> Self { <$t>::powf(self, n) }
+            fn sin_cos(self) -> (Self, Self) { <$t>::sin_cos(self) }
+            fn acos(self) -> Self { <$t>::acos(self) }
+            fn atan2(self, x: Self) -> Self { <$t>::atan2(self, x) }
+            fn hypot(self, other: Self) -> Self { <$t>::hypot(self, other) }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
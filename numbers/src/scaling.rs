@@ -1,4 +1,4 @@
-use crate::{Decimal, DecimalError, RoundingMode};
+use crate::{Decimal, DecimalError, RoundingMode, MAX_SCALING};
 
 impl Decimal {
 
@@ -155,6 +155,51 @@ impl Decimal {
     }
 
 
+    // Attempt to create a decimal number from the exact (wide) representation of the result of
+    // an operation. If either the scaling factor or the coefficient is out of range, trailing
+    // zeros are dropped (without any loss of precision) as long as possible.
+    pub(crate) fn try_from_wide(coefficient: i128, scaling: u32) -> Result<Self, DecimalError> {
+        let mut coefficient = coefficient;
+        let mut scaling = if coefficient == 0 { scaling.min(MAX_SCALING as u32) } else { scaling };
+        while scaling > 0 && coefficient % 10 == 0
+            && (scaling > MAX_SCALING as u32 || i32::try_from(coefficient).is_err()) {
+            coefficient /= 10;
+            scaling -= 1;
+        }
+
+        if scaling > MAX_SCALING as u32 {
+            return Err(DecimalError::ScalingOverflow);
+        }
+        i32::try_from(coefficient)
+            .map(|coefficient| Decimal { coefficient, scaling: scaling as u8 })
+            .map_err(|_| DecimalError::CoefficientOverflow)
+    }
+
+    // Same as the above function, but it decreases the scaling factor (by rounding just once,
+    // according to the given rounding mode) until the decimal number can be represented.
+    pub(crate) fn rounding_from_wide(coefficient: i128, scaling: u32, rounding_mode: &RoundingMode) -> Result<Self, DecimalError> {
+        if let Ok(decimal) = Self::try_from_wide(coefficient, scaling) {
+            return Ok(decimal);
+        }
+
+        let mut amount = scaling.saturating_sub(MAX_SCALING as u32).max(1);
+        while amount <= scaling {
+            let p = 10i128.pow(amount);
+            let rounded = match rounding_mode {
+                RoundingMode::HalfUp => {
+                    let (q, r) = (coefficient / p, coefficient % p);
+                    if r.abs() * 2 >= p { q + coefficient.signum() } else { q }
+                }
+            };
+            if let Ok(coefficient) = i32::try_from(rounded) {
+                return Ok(Decimal { coefficient, scaling: (scaling - amount) as u8 });
+            }
+            amount += 1;
+        }
+        Err(DecimalError::CoefficientOverflow)
+    }
+
+
     fn round_half_up(c1: u32, c2: u32, p: u32) -> u32 {
         if c2 / p >= 5 {
             c1 + 1
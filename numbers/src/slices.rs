@@ -0,0 +1,328 @@
+use crate::{Decimal, DecimalError, MAX_SCALING};
+use alloc::vec::Vec;
+
+
+impl Decimal {
+
+    /// Add the decimal numbers of two slices, component by component.
+    ///
+    /// The results are exactly the same as invoking [`Decimal::checked_add`] on each pair of
+    /// decimal numbers but, when all the decimal numbers of each slice share the same scaling
+    /// factor, the aligned coefficients are added as plain integers in a tight loop that the
+    /// compiler vectorizes (SIMD). Otherwise, it falls back to aligning each pair on its own.
+    ///
+    /// Overflows are reported per batch: the whole operation returns the error of the first
+    /// overflowing pair of decimal numbers (carrying the pair as its operands).
+    ///
+    /// # Panics
+    /// If the given slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let prices = [Decimal::new(10050, 2), Decimal::new(9975, 2)];
+    /// let deltas = [Decimal::new(25, 2), Decimal::new(-100, 2)];
+    /// let updated = Decimal::add_slices(&prices, &deltas).unwrap();
+    /// assert_eq!(updated[0].to_string(), "100.75");
+    /// assert_eq!(updated[1].to_string(), "98.75");
+    /// ```
+    pub fn add_slices(lhs: &[Decimal], rhs: &[Decimal]) -> Result<Vec<Decimal>, DecimalError> {
+        assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+
+        if let (Some(ls), Some(rs)) = (uniform_scaling(lhs), uniform_scaling(rhs)) {
+            let scaling = ls.max(rs);
+            let lm = 10i64.pow((scaling - ls) as u32);
+            let rm = 10i64.pow((scaling - rs) as u32);
+            let coefficients =
+                narrow(lhs.iter().zip(rhs).map(|(l, r)| l.coefficient as i64 * lm + r.coefficient as i64 * rm));
+            if let Some(coefficients) = coefficients {
+                return Ok(coefficients.into_iter().map(|coefficient| Decimal { coefficient, scaling }).collect());
+            }
+        }
+
+        lhs.iter()
+            .zip(rhs)
+            .map(|(l, r)| l.try_add(*r))
+            .collect()
+    }
+
+
+    /// Multiply the decimal numbers of two slices, component by component.
+    ///
+    /// The results are exactly the same as invoking [`Decimal::checked_mul`] on each pair of
+    /// decimal numbers but, when all the decimal numbers of each slice share the same scaling
+    /// factor (such as prices with 2 digits after the decimal point, and positions with none),
+    /// the coefficients are multiplied as plain integers in a tight loop that the compiler
+    /// vectorizes (SIMD). Otherwise, it falls back to multiplying each pair on its own.
+    ///
+    /// Overflows are reported per batch: the whole operation returns the error of the first
+    /// overflowing pair of decimal numbers (carrying the pair as its operands).
+    ///
+    /// # Panics
+    /// If the given slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let prices = [Decimal::new(10050, 2), Decimal::new(9975, 2)];
+    /// let positions = [Decimal::new(10, 0), Decimal::new(-3, 0)];
+    /// let values = Decimal::mul_slices(&prices, &positions).unwrap();
+    /// assert_eq!(values[0].to_string(), "1005.00");
+    /// assert_eq!(values[1].to_string(), "-299.25");
+    /// ```
+    pub fn mul_slices(lhs: &[Decimal], rhs: &[Decimal]) -> Result<Vec<Decimal>, DecimalError> {
+        assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+
+        if let (Some(ls), Some(rs)) = (uniform_scaling(lhs), uniform_scaling(rhs))
+            && ls + rs <= MAX_SCALING {
+            let scaling = ls + rs;
+            let coefficients =
+                narrow(lhs.iter().zip(rhs).map(|(l, r)| l.coefficient as i64 * r.coefficient as i64));
+            if let Some(coefficients) = coefficients {
+                return Ok(coefficients.into_iter().map(|coefficient| Decimal { coefficient, scaling }).collect());
+            }
+        }
+
+        lhs.iter()
+            .zip(rhs)
+            .map(|(l, r)| l.try_mul(*r))
+            .collect()
+    }
+
+
+    /// Compute the dot product of the decimal numbers of two slices.
+    ///
+    /// Differently from a loop of [`Decimal::checked_mul`] and [`Decimal::checked_add`], all the
+    /// products are accumulated exactly (in a wider integer), and the overflow checks are applied
+    /// to the final result only. When all the decimal numbers of each slice share the same scaling
+    /// factor, the coefficients are multiplied and accumulated as plain integers in a tight loop
+    /// that the compiler vectorizes (SIMD). Otherwise, it falls back to aligning each product.
+    ///
+    /// If the result overflows, the error is the one of the first overflowing operation of the
+    /// equivalent loop (carrying its operands, such as the partial sum and the next product).
+    ///
+    /// # Panics
+    /// If the given slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::Decimal;
+    /// let prices = [Decimal::new(10050, 2), Decimal::new(9975, 2)];
+    /// let positions = [Decimal::new(10, 0), Decimal::new(-3, 0)];
+    /// let value = Decimal::dot(&prices, &positions).unwrap();
+    /// assert_eq!(value.to_string(), "705.75");
+    /// ```
+    pub fn dot(lhs: &[Decimal], rhs: &[Decimal]) -> Result<Decimal, DecimalError> {
+        assert_eq!(lhs.len(), rhs.len(), "must have the same length");
+        wide_dot(lhs, rhs)
+            .and_then(|(sum, scaling)| Self::try_from_wide(sum, scaling).ok())
+            .ok_or_else(|| dot_overflow(lhs, rhs))
+    }
+}
+
+
+
+// Accumulate the products of the decimal numbers of two slices (of the same length) exactly, as
+// a wide coefficient and its scaling factor, unless the accumulated sum overflows 128 bits.
+pub(crate) fn wide_dot(lhs: &[Decimal], rhs: &[Decimal]) -> Option<(i128, u32)> {
+    if let (Some(ls), Some(rs)) = (uniform_scaling(lhs), uniform_scaling(rhs)) {
+        // Each product fits 63 bits, therefore their sum cannot overflow 128 bits
+        let sum: i128 =
+            lhs.iter()
+                .zip(rhs)
+                .map(|(l, r)| (l.coefficient as i64 * r.coefficient as i64) as i128)
+                .sum();
+        return Some((sum, ls as u32 + rs as u32));
+    }
+
+    // Products are aligned to the greatest of their scaling factors before being accumulated
+    let scaling =
+        lhs.iter()
+            .zip(rhs)
+            .map(|(l, r)| l.scaling as u32 + r.scaling as u32)
+            .max()
+            .unwrap_or(0);
+
+    lhs.iter()
+        .zip(rhs)
+        .try_fold(0i128, |acc, (l, r)| {
+            let p = 10i128.pow(scaling - l.scaling as u32 - r.scaling as u32);
+            acc.checked_add(l.coefficient as i128 * r.coefficient as i128 * p)
+        })
+        .map(|sum| (sum, scaling))
+}
+
+
+// The error of the first overflowing operation of the dot product of the given slices, when
+// computed as a loop of (checked) multiplications and additions. Since the loop computes the
+// exact result whenever no operation overflows, one always does for unrepresentable results.
+fn dot_overflow(lhs: &[Decimal], rhs: &[Decimal]) -> DecimalError {
+    let mut sum = Decimal { coefficient: 0, scaling: 0 };
+    for (l, r) in lhs.iter().zip(rhs) {
+        match l.try_mul(*r).and_then(|product| sum.try_add(product)) {
+            Ok(partial) => sum = partial,
+            Err(err) => return err,
+        }
+    }
+    DecimalError::CoefficientOverflow
+}
+
+
+// Return the scaling factor shared by all the given decimal numbers (if any)
+fn uniform_scaling(decimals: &[Decimal]) -> Option<u8> {
+    let first = decimals.first()?.scaling;
+    decimals
+        .iter()
+        .all(|d| d.scaling == first)
+        .then_some(first)
+}
+
+
+// Narrow the given wide coefficients, unless any of them overflows. The overflow flag is
+// accumulated without branching, so to keep the loop vectorizable.
+fn narrow(wide: impl Iterator<Item = i64>) -> Option<Vec<i32>> {
+    let mut overflow = false;
+    let narrowed: Vec<i32> =
+        wide
+            .map(|c| {
+                overflow |= c != (c as i32) as i64;
+                c as i32
+            })
+            .collect();
+
+    if overflow { None } else { Some(narrowed) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Operation, MAX_COEFFICIENT};
+
+    fn pairs(decimals: &[Decimal]) -> Vec<(i32, u8)> {
+        decimals.iter().map(|d| (d.coefficient, d.scaling)).collect()
+    }
+
+    #[test]
+    fn add_slices_uniform() {
+        let lhs = [Decimal::new(10050, 2), Decimal::new(9975, 2), Decimal::new(-1, 2)];
+        let rhs = [Decimal::new(1, 0), Decimal::new(-2, 0), Decimal::new(3, 0)];
+        let sum = Decimal::add_slices(&lhs, &rhs).unwrap();
+        assert_eq!(pairs(&sum), [(10150, 2), (9775, 2), (299, 2)]);
+    }
+
+    #[test]
+    fn add_slices_misaligned() {
+        let lhs = [Decimal::new(10050, 2), Decimal::new(9975, 3)];
+        let rhs = [Decimal::new(1, 0), Decimal::new(-2, 1)];
+        let sum = Decimal::add_slices(&lhs, &rhs).unwrap();
+        assert_eq!(pairs(&sum), [(10150, 2), (9775, 3)]);
+    }
+
+    #[test]
+    fn add_slices_same_as_checked_add() {
+        // The uniform path overflows, and the fallback drops trailing zeros
+        let lhs = [Decimal::new(2147483640, 2), Decimal::new(1, 2)];
+        let rhs = [Decimal::new(10, 2), Decimal::new(1, 2)];
+        let sum = Decimal::add_slices(&lhs, &rhs).unwrap();
+        let expected: Vec<Decimal> = lhs.iter().zip(&rhs).map(|(l, r)| l.checked_add(*r).unwrap()).collect();
+        assert_eq!(pairs(&sum), pairs(&expected));
+    }
+
+    #[test]
+    fn add_slices_overflow_err() {
+        let lhs = [Decimal::new(1, 2), Decimal::new(MAX_COEFFICIENT, 2)];
+        let rhs = [Decimal::new(1, 2), Decimal::new(1, 2)];
+        let res = Decimal::add_slices(&lhs, &rhs);
+        let expected = DecimalError::OperationOverflow { operation: Operation::Addition, lhs: lhs[1], rhs: rhs[1] };
+        assert_eq!(res.err().unwrap(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn add_slices_of_different_lengths() {
+        let lhs = [Decimal::new(1, 2), Decimal::new(2, 2)];
+        let rhs = [Decimal::new(1, 2)];
+        let _ = Decimal::add_slices(&lhs, &rhs);
+    }
+
+    #[test]
+    fn mul_slices_uniform() {
+        let lhs = [Decimal::new(10050, 2), Decimal::new(9975, 2)];
+        let rhs = [Decimal::new(10, 0), Decimal::new(-3, 0)];
+        let product = Decimal::mul_slices(&lhs, &rhs).unwrap();
+        assert_eq!(pairs(&product), [(100500, 2), (-29925, 2)]);
+    }
+
+    #[test]
+    fn mul_slices_misaligned() {
+        let lhs = [Decimal::new(12345, 4), Decimal::new(9975, 2)];
+        let rhs = [Decimal::new(10, 5), Decimal::new(-3, 0)];
+        let product = Decimal::mul_slices(&lhs, &rhs).unwrap();
+        assert_eq!(pairs(&product), [(12345, 8), (-29925, 2)]);
+    }
+
+    #[test]
+    fn mul_slices_same_as_checked_mul() {
+        // The uniform scaling factor overflows, and the fallback drops trailing zeros
+        let lhs = [Decimal::new(12340, 4), Decimal::new(5, 4)];
+        let rhs = [Decimal::new(10, 5), Decimal::new(2, 5)];
+        let product = Decimal::mul_slices(&lhs, &rhs).unwrap();
+        let expected: Vec<Decimal> = lhs.iter().zip(&rhs).map(|(l, r)| l.checked_mul(*r).unwrap()).collect();
+        assert_eq!(pairs(&product), pairs(&expected));
+    }
+
+    #[test]
+    fn mul_slices_overflow_err() {
+        let lhs = [Decimal::new(12345, 4)];
+        let rhs = [Decimal::new(12345, 5)];
+        let res = Decimal::mul_slices(&lhs, &rhs);
+        let expected = DecimalError::OperationOverflow { operation: Operation::Multiplication, lhs: lhs[0], rhs: rhs[0] };
+        assert_eq!(res.err().unwrap(), expected);
+    }
+
+    #[test]
+    fn dot_uniform() {
+        let lhs = [Decimal::new(10050, 2), Decimal::new(9975, 2)];
+        let rhs = [Decimal::new(10, 0), Decimal::new(-3, 0)];
+        let d = Decimal::dot(&lhs, &rhs).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (70575, 2));
+    }
+
+    #[test]
+    fn dot_misaligned() {
+        let lhs = [Decimal::new(10050, 2), Decimal::new(9975, 3)];
+        let rhs = [Decimal::new(10, 0), Decimal::new(-3, 1)];
+        let d = Decimal::dot(&lhs, &rhs).unwrap();
+        // 1005.00 - 2.9925 = 1002.0075
+        assert_eq!((d.coefficient, d.scaling), (10020075, 4));
+    }
+
+    #[test]
+    fn dot_exact_intermediate() {
+        // The intermediate sum overflows, but the final result doesn't
+        let lhs = [Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(MAX_COEFFICIENT, 0)];
+        let rhs = [Decimal::new(2, 0), Decimal::new(-2, 0)];
+        let d = Decimal::dot(&lhs, &rhs).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (0, 0));
+    }
+
+    #[test]
+    fn dot_overflow_err() {
+        let (max, one, two) = (Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(1, 0), Decimal::new(2, 0));
+        let res = Decimal::dot(&[max, one], &[one, one]);
+        let expected = DecimalError::OperationOverflow { operation: Operation::Addition, lhs: max, rhs: one };
+        assert_eq!(res.err().unwrap(), expected);
+
+        let res = Decimal::dot(&[one, max], &[one, two]);
+        let expected = DecimalError::OperationOverflow { operation: Operation::Multiplication, lhs: max, rhs: two };
+        assert_eq!(res.err().unwrap(), expected);
+    }
+
+    #[test]
+    fn dot_empty() {
+        let d = Decimal::dot(&[], &[]).unwrap();
+        assert_eq!((d.coefficient, d.scaling), (0, 0));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{Decimal, RoundingMode, MAX_SCALING};
+
+
+impl Decimal {
+
+    /// Compute the arithmetic mean of the decimal numbers of a slice.
+    ///
+    /// Their sum is accumulated exactly (in a wider integer), so that only the quotient is rounded
+    /// according to the given rounding mode (as in [`Decimal::rounding_div`]). It returns `None`
+    /// if the slice is empty, or if the mean cannot be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let prices = [Decimal::new(10050, 2), Decimal::new(9975, 2), Decimal::new(101, 0)];
+    /// assert_eq!(Decimal::rounding_mean(&prices, &rm).unwrap().to_string(), "100.4166667");
+    /// ```
+    pub fn rounding_mean(values: &[Decimal], rounding_mode: &RoundingMode) -> Option<Decimal> {
+        if values.is_empty() {
+            return None;
+        }
+        let (sum, _, scaling) = wide_power_sums(values)?;
+        rounding_quotient(sum, scaling, values.len() as i128, rounding_mode)
+    }
+
+
+    /// Compute the variance of the decimal numbers of a slice, that is the sum of their squared
+    /// deviations from the mean, divided by their count minus the given "delta degrees of
+    /// freedom" (`0` for the population variance, `1` for the sample one).
+    ///
+    /// Differently from floats (that require numerically stable algorithms, such as Welford's),
+    /// the sums of the decimal numbers and of their squares are accumulated exactly (in a wider
+    /// integer), so that the variance is affected by a single rounding (according to the given
+    /// rounding mode). It returns `None` if the count doesn't exceed the delta degrees of
+    /// freedom, or if the variance cannot be represented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = [Decimal::new(1, 2), Decimal::new(-2, 2), Decimal::new(4, 2)];
+    /// assert_eq!(Decimal::rounding_variance(&returns, 0, &rm).unwrap().to_string(), "0.00060000");
+    /// assert_eq!(Decimal::rounding_variance(&returns, 1, &rm).unwrap().to_string(), "0.00090000");
+    /// assert!(Decimal::rounding_variance(&returns[..1], 1, &rm).is_none());
+    /// ```
+    pub fn rounding_variance(values: &[Decimal], ddof: usize, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let (numerator, scaling, denominator) = wide_variance(values, ddof)?;
+        rounding_quotient(numerator, scaling, denominator, rounding_mode)
+    }
+
+
+    /// Compute the standard deviation of the decimal numbers of a slice, that is the square root
+    /// of their variance (see [`Decimal::rounding_variance`]).
+    ///
+    /// The variance is not rounded, so that the standard deviation is affected by a single
+    /// rounding (according to the given rounding mode) as well.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use beaumont_numbers::{Decimal, RoundingMode};
+    /// let rm = RoundingMode::HalfUp;
+    /// let returns = [Decimal::new(1, 2), Decimal::new(-2, 2), Decimal::new(4, 2)];
+    /// assert_eq!(Decimal::rounding_std_dev(&returns, 1, &rm).unwrap().to_string(), "0.03000000");
+    /// ```
+    pub fn rounding_std_dev(values: &[Decimal], ddof: usize, rounding_mode: &RoundingMode) -> Option<Decimal> {
+        let (numerator, scaling, denominator) = wide_variance(values, ddof)?;
+
+        // The variance is truncated with twice as many digits as the root requires
+        let (numerator, scaling) = rescaled(numerator, scaling, 2 * (MAX_SCALING as u32 + 1));
+        Self::rounding_sqrt_from_wide(numerator / denominator, scaling, rounding_mode)
+    }
+}
+
+
+
+// Accumulate the decimal numbers of a slice and their squares exactly, as wide coefficients aligned
+// to the greatest of their scaling factors (which is returned too, as the one of the sum), unless
+// the accumulated sum of squares overflows 128 bits.
+fn wide_power_sums(values: &[Decimal]) -> Option<(i128, i128, u32)> {
+    let scaling = values.iter().map(|value| value.scaling as u32).max().unwrap_or(0);
+    values.iter()
+        .map(|value| value.coefficient as i128 * 10i128.pow(scaling - value.scaling as u32))
+        .try_fold((0i128, 0i128), |(sum, squares), c| Some((sum + c, squares.checked_add(c * c)?)))
+        .map(|(sum, squares)| (sum, squares, scaling))
+}
+
+// Compute the variance of the decimal numbers of a slice exactly, as the fraction of a wide
+// numerator (along with its scaling factor) and a denominator, given that
+//   Σ(x - x̄)² / (n - ddof) = (n Σx² - (Σx)²) / (n (n - ddof))
+fn wide_variance(values: &[Decimal], ddof: usize) -> Option<(i128, u32, i128)> {
+    if values.len() <= ddof {
+        return None;
+    }
+    let (sum, squares, scaling) = wide_power_sums(values)?;
+    let n = values.len() as i128;
+    let numerator = n.checked_mul(squares)?.checked_sub(sum.checked_mul(sum)?)?;
+    Some((numerator, 2 * scaling, n * (n - ddof as i128)))
+}
+
+// Divide a wide coefficient (with its scaling factor) by a positive denominator, while rounding
+// the quotient according to the given rounding mode (as for the quotients of decimal numbers).
+fn rounding_quotient(numerator: i128, scaling: u32, denominator: i128, rounding_mode: &RoundingMode) -> Option<Decimal> {
+    let (numerator, scaling) = rescaled(numerator, scaling, MAX_SCALING as u32 + 1);
+    Decimal::rounding_from_wide(numerator / denominator, scaling, rounding_mode).ok()
+}
+
+// Rescale a wide coefficient to the target scaling factor (truncating it if downscaled), or to the
+// greatest scaling factor below the target that doesn't make it overflow.
+fn rescaled(coefficient: i128, scaling: u32, target: u32) -> (i128, u32) {
+    let mut target = target;
+    loop {
+        let rescaled =
+            if target >= scaling {
+                coefficient.checked_mul(10i128.pow(target - scaling))
+            }
+            else {
+                Some(coefficient / 10i128.pow(scaling - target))
+            };
+        if let Some(rescaled) = rescaled {
+            return (rescaled, target);
+        }
+        target -= 1;
+    }
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MAX_COEFFICIENT;
+
+    #[test]
+    fn rounding_mean() {
+        let rm = RoundingMode::HalfUp;
+        let m = Decimal::rounding_mean(&[Decimal::new(1, 0), Decimal::new(2, 0), Decimal::new(4, 0)], &rm).unwrap();
+        assert_eq!((m.coefficient, m.scaling), (233333333, 8));
+
+        // Misaligned decimal numbers
+        let m = Decimal::rounding_mean(&[Decimal::new(15, 1), Decimal::new(-25, 2)], &rm).unwrap();
+        assert_eq!(m, Decimal::new(625, 3));
+
+        assert!(Decimal::rounding_mean(&[], &rm).is_none());
+    }
+
+    #[test]
+    fn rounding_mean_exact_intermediate() {
+        // The sum overflows, but not the mean
+        let rm = RoundingMode::HalfUp;
+        let m = Decimal::rounding_mean(&[Decimal::new(MAX_COEFFICIENT, 0), Decimal::new(MAX_COEFFICIENT - 2, 0)], &rm).unwrap();
+        assert_eq!(m, Decimal::new(MAX_COEFFICIENT - 1, 0));
+    }
+
+    #[test]
+    fn rounding_variance() {
+        let rm = RoundingMode::HalfUp;
+        let values = [2, 4, 4, 4, 5, 5, 7, 9].map(|c| Decimal::new(c, 0));
+        assert_eq!(Decimal::rounding_variance(&values, 0, &rm), Some(Decimal::new(4, 0)));
+
+        // 32 / 7 = 4.571428571...
+        let v = Decimal::rounding_variance(&values, 1, &rm).unwrap();
+        assert_eq!((v.coefficient, v.scaling), (457142857, 8));
+    }
+
+    #[test]
+    fn rounding_variance_no_cancellation() {
+        // A large offset doesn't affect the variance, as it's computed exactly
+        let rm = RoundingMode::HalfUp;
+        let values = [100000001, 100000002, 100000003].map(|c| Decimal::new(c, 0));
+        let v = Decimal::rounding_variance(&values, 0, &rm).unwrap();
+        assert_eq!((v.coefficient, v.scaling), (66666667, 8));
+    }
+
+    #[test]
+    fn rounding_variance_too_few() {
+        let rm = RoundingMode::HalfUp;
+        assert!(Decimal::rounding_variance(&[], 0, &rm).is_none());
+        assert!(Decimal::rounding_variance(&[Decimal::new(1, 0)], 1, &rm).is_none());
+        assert_eq!(Decimal::rounding_variance(&[Decimal::new(1, 0)], 0, &rm), Some(Decimal::new(0, 0)));
+    }
+
+    #[test]
+    fn rounding_std_dev() {
+        let rm = RoundingMode::HalfUp;
+        let values = [2, 4, 4, 4, 5, 5, 7, 9].map(|c| Decimal::new(c, 0));
+        assert_eq!(Decimal::rounding_std_dev(&values, 0, &rm), Some(Decimal::new(2, 0)));
+
+        // √(32 / 7) = 2.138089935...
+        let s = Decimal::rounding_std_dev(&values, 1, &rm).unwrap();
+        assert_eq!((s.coefficient, s.scaling), (213808994, 8));
+    }
+}